@@ -1,8 +1,18 @@
+use std::rc::Rc;
+
 use dioxus::document;
 use dioxus::prelude::*;
+use shared_models::api::{CreateUserRequest, ListResponse, PageRequest};
+use shared_models::{ThemePreference, User};
 use shared_ui::{
-    Button, ButtonSize, ButtonVariant, Card, CardContent, CardHeader, Header, Input, InputType,
-    Label, Layout, Main,
+    Accordion, AccordionItem, AppShell, Button, ButtonSize, ButtonType, ButtonVariant, Card,
+    CardContent, CardHeader, CardVariant, Collapsible, Combobox, ComboboxLoader, ComboboxOption,
+    ConfirmDialog, ContextMenu, DataTable, DropdownMenu, Field, Footer, FormOptions, Header,
+    IconButton, Input, InputType, Main, MenuItem, NavGroup, NavItem, Placement, RowHeight,
+    ScrollAlign, SelectOption, Sidebar, SortDirection, SortState, Tab, TabList, TabPanel,
+    TableQuery, Tabs, Theme, ThemeProvider, Toast, ToastProvider, Tooltip, TooltipGroup,
+    VirtualList, filter_options, sleep, use_color_scheme, use_form, use_toast, use_virtualizer,
+    user_columns,
 };
 
 fn main() {
@@ -12,166 +22,772 @@ fn main() {
 #[component]
 fn App() -> Element {
     let mut count = use_signal(|| 0);
-    let mut input_value = use_signal(String::new);
-    let mut dark_mode = use_signal(|| false);
 
     rsx! {
         document::Stylesheet {
             href: asset!("/assets/generated.css")
         }
-        div {
-            class: if *dark_mode.read() { "dark" } else { "" },
-            Layout {
-            Header {
-                title: "App Template DX",
-                Button {
-                    text: "Dark Mode",
-                    variant: ButtonVariant::Outline,
-                    size: ButtonSize::Small,
-                    onclick: move |_| {
-                        let current = *dark_mode.read();
-                        dark_mode.set(!current);
-                    }
-                }
-            }
-            Main {
-                div {
-                    class: "space-y-8",
-
-                    // Welcome section
-                    Card {
-                        CardHeader {
-                            h2 {
-                                class: "text-xl font-semibold text-gray-900 dark:text-white",
-                                "Welcome to Dioxus with Tailwind CSS"
+        ThemeProvider {
+            theme: Theme::DEFAULT,
+            ToastProvider {
+                AppShell {
+                    header: rsx! {
+                        Header {
+                            title: "App Template DX",
+                            TooltipGroup {
+                                div {
+                                    class: "flex items-center gap-2",
+                                    ColorSchemeToggle {}
+                                    Tooltip {
+                                        content: "Notifications are coming soon",
+                                        IconButton {
+                                            aria_label: "Notifications",
+                                            variant: ButtonVariant::Outline,
+                                            size: ButtonSize::Small,
+                                            disabled: true,
+                                            HeaderIcon { path: "M14.857 17.082a23.848 23.848 0 0 0 5.454-1.31A8.967 8.967 0 0 1 18 9.75V9A6 6 0 0 0 6 9v.75a8.967 8.967 0 0 1-2.312 6.022c1.733.64 3.56 1.085 5.455 1.31m5.714 0a24.255 24.255 0 0 1-5.714 0m5.714 0a3 3 0 1 1-5.714 0" }
+                                        }
+                                    }
+                                    AccountMenu {}
+                                }
                             }
                         }
-                        CardContent {
-                            p {
-                                class: "text-gray-600 dark:text-gray-300 mb-4",
-                                "This is a modern Rust web application built with Dioxus and styled with Tailwind CSS.
-                                 The components are fully reusable and follow a consistent design system."
-                            }
-                            div {
-                                class: "flex flex-wrap gap-2",
-                                Button {
-                                    text: "Primary Button",
-                                    variant: ButtonVariant::Primary,
-                                    onclick: move |_| count += 1
+                    },
+                    sidebar: rsx! { AppSidebar {} },
+                    footer: rsx! {
+                        Footer {
+                            p { "Built with Dioxus and Tailwind CSS." }
+                        }
+                    },
+                    Main {
+                        div {
+                            class: "space-y-8",
+
+                            // Welcome and counter
+                            section {
+                                id: "overview",
+                                class: "space-y-8 scroll-mt-8",
+                                Card {
+                                    CardHeader {
+                                        h2 {
+                                            class: "text-xl font-semibold text-gray-900 dark:text-white",
+                                            "Welcome to Dioxus with Tailwind CSS"
+                                        }
+                                    }
+                                    CardContent {
+                                        p {
+                                            class: "text-gray-600 dark:text-gray-300 mb-4",
+                                            "This is a modern Rust web application built with Dioxus and styled with Tailwind CSS.
+                                             The components are fully reusable and follow a consistent design system."
+                                        }
+                                        div {
+                                            class: "flex flex-wrap gap-2",
+                                            Button {
+                                                text: "Primary Button",
+                                                variant: ButtonVariant::Primary,
+                                                onclick: move |_| count += 1
+                                            }
+                                            Button {
+                                                text: "Secondary Button",
+                                                variant: ButtonVariant::Secondary
+                                            }
+                                            Button {
+                                                text: "Outline Button",
+                                                variant: ButtonVariant::Outline
+                                            }
+                                            Button {
+                                                text: "Destructive Button",
+                                                variant: ButtonVariant::Destructive
+                                            }
+                                        }
+                                    }
                                 }
-                                Button {
-                                    text: "Secondary Button",
-                                    variant: ButtonVariant::Secondary
+
+                                // Counter demo
+                                Card {
+                                    CardHeader {
+                                        h3 {
+                                            class: "text-lg font-semibold text-gray-900 dark:text-white",
+                                            "Interactive Counter Demo"
+                                        }
+                                    }
+                                    CardContent {
+                                        div {
+                                            class: "text-center",
+                                            p {
+                                                class: "text-2xl font-bold text-blue-600 dark:text-blue-400 mb-4",
+                                                "Count: {count}"
+                                            }
+                                            div {
+                                                class: "flex gap-2 justify-center",
+                                                Button {
+                                                    text: "Increment",
+                                                    variant: ButtonVariant::Primary,
+                                                    onclick: move |_| count += 1
+                                                }
+                                                Button {
+                                                    text: "Decrement",
+                                                    variant: ButtonVariant::Secondary,
+                                                    onclick: move |_| count -= 1
+                                                }
+                                                Button {
+                                                    text: "Reset",
+                                                    variant: ButtonVariant::Outline,
+                                                    onclick: move |_| count.set(0)
+                                                }
+                                            }
+                                        }
+                                    }
                                 }
-                                Button {
-                                    text: "Outline Button",
-                                    variant: ButtonVariant::Outline
+                            }
+
+                            // Form demo
+                            section {
+                                id: "forms",
+                                class: "scroll-mt-8",
+                                CreateUserForm {}
+                            }
+
+                            // Combobox demo
+                            section {
+                                id: "combobox",
+                                class: "scroll-mt-8",
+                                ComboboxDemo {}
+                            }
+
+                            // Data table demo
+                            section {
+                                id: "data-table",
+                                class: "scroll-mt-8",
+                                DataTableDemo {}
+                            }
+
+                            // Virtual list demo
+                            section {
+                                id: "virtual-list",
+                                class: "scroll-mt-8",
+                                VirtualListDemo {}
+                            }
+
+                            // Tabs, accordion and collapsible demo
+                            section {
+                                id: "settings",
+                                class: "scroll-mt-8",
+                                SettingsDemo {}
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn AppSidebar() -> Element {
+    // Heroicons outline paths.
+    rsx! {
+        Sidebar {
+            NavItem {
+                href: "#overview",
+                label: "Overview",
+                icon: rsx! { HeaderIcon { path: "m2.25 12 8.954-8.955c.44-.439 1.152-.439 1.591 0L21.75 12M4.5 9.75v10.125c0 .621.504 1.125 1.125 1.125H9.75v-4.875c0-.621.504-1.125 1.125-1.125h2.25c.621 0 1.125.504 1.125 1.125V21h4.125c.621 0 1.125-.504 1.125-1.125V9.75M8.25 21h8.25" } },
+            }
+            NavGroup {
+                label: "Components",
+                icon: rsx! { HeaderIcon { path: "M6 6.878V6a2.25 2.25 0 0 1 2.25-2.25h7.5A2.25 2.25 0 0 1 18 6v.878m-12 0c.235-.083.487-.128.75-.128h10.5c.263 0 .515.045.75.128m-12 0A2.25 2.25 0 0 0 4.5 9v.878m13.5-3A2.25 2.25 0 0 1 19.5 9v.878m0 0a2.246 2.246 0 0 0-.75-.128H5.25c-.263 0-.515.045-.75.128m15 0A2.25 2.25 0 0 1 21 12v6a2.25 2.25 0 0 1-2.25 2.25H5.25A2.25 2.25 0 0 1 3 18v-6c0-.98.626-1.813 1.5-2.122" } },
+                default_open: true,
+                NavItem { href: "#forms", label: "Forms" }
+                NavItem { href: "#combobox", label: "Combobox" }
+                NavGroup {
+                    label: "Data",
+                    NavItem { href: "#data-table", label: "Data table" }
+                    NavItem { href: "#virtual-list", label: "Virtual list" }
+                }
+            }
+            NavItem {
+                href: "#settings",
+                label: "Settings",
+                icon: rsx! { HeaderIcon { path: "M10.5 6h9.75M10.5 6a1.5 1.5 0 1 1-3 0m3 0a1.5 1.5 0 1 0-3 0M3.75 6H7.5m3 12h9.75m-9.75 0a1.5 1.5 0 0 1-3 0m3 0a1.5 1.5 0 0 0-3 0m-3.75 0H7.5m9-6h3.75m-3.75 0a1.5 1.5 0 0 1-3 0m3 0a1.5 1.5 0 0 0-3 0m-9.75 0h9.75" } },
+            }
+        }
+    }
+}
+
+#[component]
+fn ColorSchemeToggle() -> Element {
+    let mut scheme = use_color_scheme();
+    let label = if scheme.is_dark() {
+        "Switch to light mode"
+    } else {
+        "Switch to dark mode"
+    };
+    // Heroicons sun and moon.
+    let icon = if scheme.is_dark() {
+        "M12 3v2.25m6.364.386-1.591 1.591M21 12h-2.25m-.386 6.364-1.591-1.591M12 18.75V21m-4.773-4.227-1.591 1.591M5.25 12H3m4.227-4.773L5.636 5.636M15.75 12a3.75 3.75 0 1 1-7.5 0 3.75 3.75 0 0 1 7.5 0Z"
+    } else {
+        "M21.752 15.002A9.72 9.72 0 0 1 18 15.75c-5.385 0-9.75-4.365-9.75-9.75 0-1.33.266-2.597.748-3.752A9.753 9.753 0 0 0 3 11.25C3 16.635 7.365 21 12.75 21a9.753 9.753 0 0 0 9.002-5.998Z"
+    };
+
+    rsx! {
+        Tooltip {
+            content: label,
+            IconButton {
+                aria_label: label,
+                variant: ButtonVariant::Outline,
+                size: ButtonSize::Small,
+                onclick: move |_| scheme.toggle(),
+                HeaderIcon { path: icon }
+            }
+        }
+    }
+}
+
+#[component]
+fn HeaderIcon(path: &'static str) -> Element {
+    rsx! {
+        svg {
+            class: "h-4 w-4",
+            xmlns: "http://www.w3.org/2000/svg",
+            fill: "none",
+            view_box: "0 0 24 24",
+            stroke: "currentColor",
+            stroke_width: "1.5",
+            path { stroke_linecap: "round", stroke_linejoin: "round", d: path }
+        }
+    }
+}
+
+#[component]
+fn AccountMenu() -> Element {
+    let mut scheme = use_color_scheme();
+    let mut toaster = use_toast();
+    let preference = scheme.preference();
+
+    let items = vec![
+        MenuItem::new("profile", "Profile"),
+        MenuItem::submenu(
+            "Theme",
+            vec![
+                MenuItem::checkbox("light", "Light", preference == ThemePreference::Light),
+                MenuItem::checkbox("dark", "Dark", preference == ThemePreference::Dark),
+                MenuItem::checkbox("system", "System", preference == ThemePreference::System),
+            ],
+        ),
+        MenuItem::new("settings", "Settings").disabled(),
+        MenuItem::separator(),
+        MenuItem::new("sign-out", "Sign out").destructive(),
+    ];
+
+    rsx! {
+        DropdownMenu {
+            items,
+            variant: ButtonVariant::Outline,
+            size: ButtonSize::Small,
+            placement: Placement::BOTTOM_END,
+            onselect: move |id: String| match id.as_str() {
+                "light" => scheme.set(ThemePreference::Light),
+                "dark" => scheme.set(ThemePreference::Dark),
+                "system" => scheme.set(ThemePreference::System),
+                "sign-out" => {
+                    toaster.info("Signed out");
+                }
+                _ => {}
+            },
+            "Account"
+        }
+    }
+}
+
+#[component]
+fn CreateUserForm() -> Element {
+    let mut users = use_signal(Vec::<User>::new);
+    let mut pending_delete = use_signal(|| None::<u64>);
+    let mut toaster = use_toast();
+    let mut form = use_form(move || {
+        FormOptions::new([("name", ""), ("email", "")])
+            .validator(|values| {
+                User::new(0, values.get("name").into(), values.get("email").into()).map(|_| ())
+            })
+            .async_validator("email", move |email| async move {
+                // Stand-in for a server round trip.
+                sleep(300).await;
+                if users
+                    .peek()
+                    .iter()
+                    .any(|u| u.email.eq_ignore_ascii_case(&email))
+                {
+                    Err("Email is already registered".to_string())
+                } else {
+                    Ok(())
+                }
+            })
+    });
+
+    let submit = move |evt: FormEvent| {
+        evt.prevent_default();
+        form.submit(move |values| async move {
+            sleep(500).await;
+            let request = CreateUserRequest {
+                name: values.get("name").to_string(),
+                email: values.get("email").to_string(),
+            };
+            let next_id = users.peek().iter().map(|u| u.id).max().unwrap_or(0) + 1;
+            let user = request.into_user(next_id)?;
+            toaster.success(format!("Created {}", user.name));
+            users.write().push(user);
+            form.reset();
+            Ok(())
+        });
+    };
+
+    rsx! {
+        Card {
+            CardHeader {
+                h3 {
+                    class: "text-lg font-semibold text-gray-900 dark:text-white",
+                    "Form Components Demo"
+                }
+            }
+            CardContent {
+                form {
+                    class: "max-w-md space-y-4",
+                    novalidate: true,
+                    onsubmit: submit,
+                    Field {
+                        label: "Your Name",
+                        required: true,
+                        error: form.error("name"),
+                        Input {
+                            input_type: InputType::Text,
+                            placeholder: "Enter your name",
+                            value: form.value("name"),
+                            oninput: move |evt: FormEvent| form.set_value("name", evt.value()),
+                            onblur: move |_| form.touch("name")
+                        }
+                    }
+                    Field {
+                        label: "Email Address",
+                        required: true,
+                        hint: if form.is_validating("email") { "Checking availability..." } else { "We will never share your email" },
+                        error: form.error("email"),
+                        Input {
+                            input_type: InputType::Email,
+                            placeholder: "Enter your email",
+                            value: form.value("email"),
+                            oninput: move |evt: FormEvent| form.set_value("email", evt.value()),
+                            onblur: move |_| form.touch("email")
+                        }
+                    }
+                    if let Some(error) = form.form_error() {
+                        p {
+                            class: "text-sm text-red-600 dark:text-red-400",
+                            role: "alert",
+                            "{error}"
+                        }
+                    }
+                    div {
+                        class: "flex gap-2",
+                        Button {
+                            text: "Create User",
+                            button_type: ButtonType::Submit,
+                            loading: form.is_submitting()
+                        }
+                        Button {
+                            text: "Reset",
+                            variant: ButtonVariant::Outline,
+                            disabled: !form.is_dirty() || form.is_submitting(),
+                            onclick: move |_| form.reset()
+                        }
+                    }
+                }
+                if !users.read().is_empty() {
+                    ul {
+                        class: "mt-6 space-y-2",
+                        for user in users.read().iter() {
+                            li {
+                                key: "{user.id}",
+                                class: "flex items-center justify-between gap-4 p-3 bg-blue-50 dark:bg-blue-900 rounded-lg text-blue-700 dark:text-blue-300",
+                                ContextMenu {
+                                    items: vec![
+                                        MenuItem::new("copy-email", "Copy email"),
+                                        MenuItem::separator(),
+                                        MenuItem::new("delete", "Delete").destructive(),
+                                    ],
+                                    aria_label: "User actions",
+                                    onselect: {
+                                        let id = user.id;
+                                        let email = user.email.clone();
+                                        move |action: String| match action.as_str() {
+                                            "copy-email" => {
+                                                document::eval(&format!("navigator.clipboard.writeText({email:?});"));
+                                                toaster.success("Email copied");
+                                            }
+                                            "delete" => pending_delete.set(Some(id)),
+                                            _ => {}
+                                        }
+                                    },
+                                    "Hello, {user.name}! Created user #{user.id} ({user.email})."
                                 }
                                 Button {
-                                    text: "Destructive Button",
-                                    variant: ButtonVariant::Destructive
+                                    text: "Delete",
+                                    variant: ButtonVariant::Destructive,
+                                    size: ButtonSize::Small,
+                                    onclick: {
+                                        let id = user.id;
+                                        move |_| pending_delete.set(Some(id))
+                                    }
                                 }
                             }
                         }
                     }
+                }
+            }
+        }
+        ConfirmDialog {
+            open: pending_delete().is_some(),
+            title: "Delete user?",
+            message: "This removes the user from the list. It cannot be undone.",
+            confirm_text: "Delete",
+            onconfirm: move |_| {
+                let Some(id) = pending_delete.take() else { return };
+                let Some(index) = users.peek().iter().position(|u| u.id == id) else { return };
+                let user = users.write().remove(index);
+                toaster.show(Toast::info(format!("Deleted {}", user.name)).action("Undo", move || {
+                    let mut users = users;
+                    let index = index.min(users.peek().len());
+                    users.write().insert(index, user.clone());
+                }));
+            },
+            oncancel: move |_| pending_delete.set(None)
+        }
+    }
+}
 
-                    // Counter demo
-                    Card {
-                        CardHeader {
-                            h3 {
-                                class: "text-lg font-semibold text-gray-900 dark:text-white",
-                                "Interactive Counter Demo"
-                            }
+/// A stand-in directory large enough that searching it should happen on the server.
+fn directory() -> Vec<User> {
+    const FIRST: [&str; 8] = [
+        "Ada", "Alan", "Grace", "Edsger", "Barbara", "Donald", "Frances", "Ken",
+    ];
+    const LAST: [&str; 10] = [
+        "Lovelace", "Turing", "Hopper", "Dijkstra", "Liskov", "Knuth", "Allen", "Thompson",
+        "Ritchie", "Hamilton",
+    ];
+    (1..=2000)
+        .map(|id| {
+            let first = FIRST[id as usize % FIRST.len()];
+            let last = LAST[id as usize / FIRST.len() % LAST.len()];
+            let name = format!("{first} {last} {id}");
+            let email = format!(
+                "{}.{}{id}@example.com",
+                first.to_lowercase(),
+                last.to_lowercase()
+            );
+            User::new(id, name, email).expect("generated users are valid")
+        })
+        .collect()
+}
+
+#[component]
+fn ComboboxDemo() -> Element {
+    let mut assignees = use_signal(Vec::<User>::new);
+    let mut tags = use_signal(|| {
+        ["Bug", "Feature", "Docs", "Performance"]
+            .map(|tag| SelectOption::new(tag.to_lowercase(), tag))
+            .to_vec()
+    });
+    let mut selected_tags = use_signal(Vec::<SelectOption>::new);
+    let loader = use_hook(|| {
+        let directory = Rc::new(directory());
+        ComboboxLoader::new(move |query: String| {
+            let directory = directory.clone();
+            async move {
+                // Stand-in for a search request.
+                sleep(400).await;
+                Ok(filter_options(&directory, &query, 50))
+            }
+        })
+    });
+
+    rsx! {
+        Card {
+            CardHeader {
+                h3 {
+                    class: "text-lg font-semibold text-gray-900 dark:text-white",
+                    "Combobox Demo"
+                }
+            }
+            CardContent {
+                div {
+                    class: "max-w-md space-y-4",
+                    Field {
+                        label: "Assignees",
+                        hint: "Searches 2,000 people as you type",
+                        Combobox::<User> {
+                            loader: loader.clone(),
+                            multiple: true,
+                            selected: assignees(),
+                            onchange: move |users| assignees.set(users),
+                            placeholder: "Search people..."
+                        }
+                    }
+                    Field {
+                        label: "Tag",
+                        hint: "Pick a tag or type a new one",
+                        Combobox::<SelectOption> {
+                            options: tags(),
+                            selected: selected_tags(),
+                            onchange: move |selected| selected_tags.set(selected),
+                            oncreate: move |label: String| {
+                                let tag = SelectOption::new(label.to_lowercase(), label);
+                                tags.write().push(tag.clone());
+                                selected_tags.set(vec![tag]);
+                            },
+                            placeholder: "Choose a tag"
+                        }
+                    }
+                    if !assignees.read().is_empty() {
+                        p {
+                            class: "text-sm text-gray-600 dark:text-gray-300",
+                            "Assigned to "
+                            {assignees.read().iter().map(ComboboxOption::label).collect::<Vec<_>>().join(", ")}
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Stand-in for `GET /users?sort=..&page=..`: sorts and pages the directory after a delay.
+async fn fetch_users(directory: Rc<Vec<User>>, query: TableQuery) -> ListResponse<User> {
+    sleep(300).await;
+    let mut users = directory.as_ref().clone();
+    if let Some(sort) = &query.sort {
+        match sort.column.as_str() {
+            "name" => users.sort_by_key(|u| u.name.to_lowercase()),
+            "email" => users.sort_by_key(|u| u.email.to_lowercase()),
+            _ => users.sort_by_key(|u| u.id),
+        }
+        if sort.direction == SortDirection::Descending {
+            users.reverse();
+        }
+    }
+    ListResponse::paginate(users, query.page)
+}
+
+#[component]
+fn DataTableDemo() -> Element {
+    let directory = use_hook(|| CopyValue::new(Rc::new(directory())));
+    let columns = use_hook(user_columns);
+    let mut page = use_signal(|| None::<ListResponse<User>>);
+    let mut loading = use_signal(|| false);
+    let mut selected = use_signal(Vec::<String>::new);
+    let mut request = use_signal(|| None::<Task>);
+
+    let mut load = move |query: TableQuery| {
+        if let Some(task) = request.take() {
+            task.cancel();
+        }
+        loading.set(true);
+        let directory = directory.read().clone();
+        request.set(Some(spawn(async move {
+            page.set(Some(fetch_users(directory, query).await));
+            loading.set(false);
+        })));
+    };
+    let default_sort = SortState::ascending("name");
+    use_hook({
+        let default_sort = default_sort.clone();
+        move || {
+            load(TableQuery {
+                sort: Some(default_sort),
+                page: PageRequest::default(),
+            })
+        }
+    });
+
+    let rows = page
+        .read()
+        .as_ref()
+        .map(|p| p.items.clone())
+        .unwrap_or_default();
+    let total = page.read().as_ref().map(|p| p.pagination.total);
+
+    rsx! {
+        Card {
+            CardHeader {
+                h3 {
+                    class: "text-lg font-semibold text-gray-900 dark:text-white",
+                    "Data Table Demo"
+                }
+            }
+            CardContent {
+                class: "space-y-2",
+                p {
+                    class: "text-sm text-gray-600 dark:text-gray-300",
+                    "{selected.read().len()} selected. Shift-click a checkbox to select a range."
+                }
+                DataTable::<User> {
+                    rows,
+                    columns: columns.clone(),
+                    server_side: true,
+                    total,
+                    default_sort,
+                    onquerychange: load,
+                    selectable: true,
+                    selected: selected(),
+                    onselectionchange: move |keys| selected.set(keys),
+                    loading: loading(),
+                    max_height: "24rem",
+                    caption: "User directory"
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn VirtualListDemo() -> Element {
+    const BATCH: usize = 10_000;
+    const LIMIT: usize = 100_000;
+
+    let mut count = use_signal(|| 5 * BATCH);
+    let mut loading = use_signal(|| false);
+    let mut jump_to = use_signal(|| "25000".to_string());
+    let list = use_virtualizer(count(), RowHeight::Estimated(40.0), 8);
+
+    let load_more = move |_| {
+        loading.set(true);
+        spawn(async move {
+            // Stand-in for fetching the next page.
+            sleep(600).await;
+            count.set((count() + BATCH).min(LIMIT));
+            loading.set(false);
+        });
+    };
+    let jump = move |_| {
+        if let Ok(row) = jump_to.read().trim().parse::<usize>() {
+            list.scroll_to_index(row.saturating_sub(1), ScrollAlign::Start);
+        }
+    };
+
+    rsx! {
+        Card {
+            CardHeader {
+                h3 {
+                    class: "text-lg font-semibold text-gray-900 dark:text-white",
+                    "Virtual List Demo"
+                }
+            }
+            CardContent {
+                class: "space-y-3",
+                div {
+                    class: "flex items-end gap-2",
+                    Field {
+                        label: "Row",
+                        Input {
+                            input_type: InputType::Number,
+                            value: jump_to(),
+                            oninput: move |evt: FormEvent| jump_to.set(evt.value())
                         }
-                        CardContent {
-                            div {
-                                class: "text-center",
+                    }
+                    Button { text: "Jump", variant: ButtonVariant::Outline, onclick: jump }
+                }
+                VirtualList {
+                    virtualizer: list,
+                    height: "20rem",
+                    aria_label: "Activity log",
+                    has_more: count() < LIMIT,
+                    loading: loading(),
+                    onloadmore: load_more,
+                    class: "rounded-lg border border-gray-200 dark:border-gray-700",
+                    render_item: move |index: usize| rsx! {
+                        div {
+                            class: "border-b border-gray-100 dark:border-gray-700 px-4 py-2 text-sm text-gray-700 dark:text-gray-200",
+                            span { class: "font-medium", "Row {index + 1}" }
+                            // Every seventh row wraps onto more lines, to exercise measuring.
+                            if index % 7 == 0 {
                                 p {
-                                    class: "text-2xl font-bold text-blue-600 dark:text-blue-400 mb-4",
-                                    "Count: {count}"
-                                }
-                                div {
-                                    class: "flex gap-2 justify-center",
-                                    Button {
-                                        text: "Increment",
-                                        variant: ButtonVariant::Primary,
-                                        onclick: move |_| count += 1
-                                    }
-                                    Button {
-                                        text: "Decrement",
-                                        variant: ButtonVariant::Secondary,
-                                        onclick: move |_| count -= 1
-                                    }
-                                    Button {
-                                        text: "Reset",
-                                        variant: ButtonVariant::Outline,
-                                        onclick: move |_| count.set(0)
-                                    }
+                                    class: "text-gray-500 dark:text-gray-400",
+                                    "A longer entry whose height is only known once it has been rendered and measured."
                                 }
                             }
                         }
                     }
+                }
+                p {
+                    class: "text-sm text-gray-600 dark:text-gray-300",
+                    "{count} rows loaded"
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn SettingsDemo() -> Element {
+    let mut tab = use_signal(|| "general".to_string());
 
-                    // Form demo
-                    Card {
-                        CardHeader {
-                            h3 {
-                                class: "text-lg font-semibold text-gray-900 dark:text-white",
-                                "Form Components Demo"
+    rsx! {
+        Card {
+            CardHeader {
+                h3 {
+                    class: "text-lg font-semibold text-gray-900 dark:text-white",
+                    "Settings Demo"
+                }
+            }
+            CardContent {
+                Tabs {
+                    value: tab(),
+                    onchange: move |value| tab.set(value),
+                    lazy: true,
+                    TabList {
+                        aria_label: "Settings sections",
+                        Tab { value: "general", "General" }
+                        Tab { value: "notifications", "Notifications" }
+                        Tab { value: "billing", disabled: true, "Billing" }
+                        Tab { value: "advanced", "Advanced" }
+                    }
+                    TabPanel {
+                        value: "general",
+                        Accordion {
+                            default_value: vec!["profile".to_string()],
+                            AccordionItem {
+                                value: "profile",
+                                title: "Profile",
+                                "Name, email and avatar shown to other users."
+                            }
+                            AccordionItem {
+                                value: "language",
+                                title: "Language and region",
+                                "Locale and timezone used for dates and numbers."
                             }
                         }
-                        CardContent {
-                            div {
-                                class: "max-w-md space-y-4",
-                                div {
-                                    Label {
-                                        for_input: "demo-input",
-                                        required: true,
-                                        "Your Name"
-                                    }
-                                    Input {
-                                        id: "demo-input",
-                                        input_type: InputType::Text,
-                                        placeholder: "Enter your name",
-                                        value: input_value.read().clone(),
-                                        oninput: move |evt: FormEvent| input_value.set(evt.value())
-                                    }
-                                }
-                                div {
-                                    Label {
-                                        for_input: "demo-email",
-                                        "Email Address"
-                                    }
-                                    Input {
-                                        id: "demo-email",
-                                        input_type: InputType::Email,
-                                        placeholder: "Enter your email"
-                                    }
-                                }
-                                div {
-                                    Button {
-                                        text: "Submit Form",
-                                        variant: ButtonVariant::Primary,
-                                        onclick: move |_| {
-                                            // Form submission logic
-                                        }
-                                    }
-                                }
-                                if !input_value.read().is_empty() {
-                                    div {
-                                        class: "mt-4 p-3 bg-blue-50 dark:bg-blue-900 rounded-lg",
-                                        p {
-                                            class: "text-blue-700 dark:text-blue-300",
-                                            "Hello, {input_value.read()}! Nice to meet you."
-                                        }
-                                    }
-                                }
+                    }
+                    TabPanel {
+                        value: "notifications",
+                        Accordion {
+                            multiple: true,
+                            AccordionItem { value: "email", title: "Email", "Weekly digest and security alerts." }
+                            AccordionItem { value: "push", title: "Push", "Mentions and direct messages." }
+                        }
+                    }
+                    TabPanel { value: "billing", "Billing is managed by your organization." }
+                    TabPanel {
+                        value: "advanced",
+                        Collapsible {
+                            title: "Danger zone",
+                            variant: CardVariant::Outlined,
+                            p {
+                                class: "mb-4",
+                                "Deleting your account removes all of your data."
                             }
+                            Button { text: "Delete account", variant: ButtonVariant::Destructive }
                         }
                     }
                 }
             }
-            }
         }
     }
 }
@@ -0,0 +1,361 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{User, UserPreferences, UserProfile, UserUpdate, ValidationError};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateUserRequest {
+    pub name: String,
+    pub email: String,
+}
+
+impl CreateUserRequest {
+    /// Validate the payload and build the `User` it describes
+    pub fn into_user(self, id: u64) -> Result<User, ApiError> {
+        User::new(id, self.name, self.email).map_err(ApiError::from_validation)
+    }
+}
+
+/// Partial update payload; absent fields are left alone and `null` clears nullable ones.
+pub type UpdateUserRequest = UserUpdate;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserResponse {
+    pub id: u64,
+    pub name: String,
+    pub email: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avatar_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preferences: Option<UserPreferences>,
+}
+
+impl From<User> for UserResponse {
+    fn from(user: User) -> Self {
+        Self {
+            id: user.id,
+            name: user.name,
+            email: user.email,
+            avatar_url: None,
+            preferences: None,
+        }
+    }
+}
+
+impl From<UserProfile> for UserResponse {
+    fn from(profile: UserProfile) -> Self {
+        Self {
+            avatar_url: profile.avatar_url,
+            preferences: Some(profile.preferences),
+            ..Self::from(profile.user)
+        }
+    }
+}
+
+/// Query parameters for paginated list endpoints. Pages are 1-based.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PageRequest {
+    #[serde(default = "PageRequest::default_page")]
+    pub page: u32,
+    #[serde(default = "PageRequest::default_per_page")]
+    pub per_page: u32,
+}
+
+impl PageRequest {
+    pub const MAX_PER_PAGE: u32 = 100;
+
+    fn default_page() -> u32 {
+        1
+    }
+
+    fn default_per_page() -> u32 {
+        20
+    }
+
+    /// Clamp to a valid page (>= 1) and page size (1..=MAX_PER_PAGE)
+    pub fn normalized(self) -> Self {
+        Self {
+            page: self.page.max(1),
+            per_page: self.per_page.clamp(1, Self::MAX_PER_PAGE),
+        }
+    }
+
+    /// Index of the first item on this page. Computed in `u64` so a huge `page` cannot
+    /// overflow a 32-bit `usize`; offsets past `usize::MAX` saturate, giving an empty page.
+    pub fn offset(&self) -> usize {
+        let normalized = self.normalized();
+        let offset = u64::from(normalized.page - 1).saturating_mul(u64::from(normalized.per_page));
+        usize::try_from(offset).unwrap_or(usize::MAX)
+    }
+}
+
+impl Default for PageRequest {
+    fn default() -> Self {
+        Self {
+            page: Self::default_page(),
+            per_page: Self::default_per_page(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Pagination {
+    pub page: u32,
+    pub per_page: u32,
+    pub total: u64,
+    pub total_pages: u32,
+}
+
+impl Pagination {
+    pub fn new(request: PageRequest, total: u64) -> Self {
+        let request = request.normalized();
+        let total_pages = total.div_ceil(u64::from(request.per_page));
+        Self {
+            page: request.page,
+            per_page: request.per_page,
+            total,
+            total_pages: u32::try_from(total_pages).unwrap_or(u32::MAX),
+        }
+    }
+
+    pub fn has_next(&self) -> bool {
+        self.page < self.total_pages
+    }
+
+    pub fn has_previous(&self) -> bool {
+        self.page > 1
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ListResponse<T> {
+    pub items: Vec<T>,
+    pub pagination: Pagination,
+}
+
+impl<T> ListResponse<T> {
+    /// Slice one page out of a complete, already ordered collection.
+    pub fn paginate(all: impl IntoIterator<Item = T>, request: PageRequest) -> Self {
+        let request = request.normalized();
+        let offset = request.offset();
+        let mut total = 0u64;
+        let mut items = Vec::new();
+        for (i, item) in all.into_iter().enumerate() {
+            total += 1;
+            if i >= offset && items.len() < request.per_page as usize {
+                items.push(item);
+            }
+        }
+        Self {
+            items,
+            pagination: Pagination::new(request, total),
+        }
+    }
+
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> ListResponse<U> {
+        ListResponse {
+            items: self.items.into_iter().map(f).collect(),
+            pagination: self.pagination,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCode {
+    ValidationFailed,
+    BadRequest,
+    Unauthorized,
+    Forbidden,
+    NotFound,
+    Conflict,
+    RateLimited,
+    Internal,
+}
+
+impl ErrorCode {
+    pub fn http_status(&self) -> u16 {
+        match self {
+            ErrorCode::ValidationFailed => 422,
+            ErrorCode::BadRequest => 400,
+            ErrorCode::Unauthorized => 401,
+            ErrorCode::Forbidden => 403,
+            ErrorCode::NotFound => 404,
+            ErrorCode::Conflict => 409,
+            ErrorCode::RateLimited => 429,
+            ErrorCode::Internal => 500,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldError {
+    pub field: String,
+    pub message: String,
+}
+
+/// Standard error envelope returned by every endpoint.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApiError {
+    pub code: ErrorCode,
+    pub message: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub field_errors: Vec<FieldError>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trace_id: Option<String>,
+}
+
+impl ApiError {
+    pub fn new(code: ErrorCode, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            field_errors: Vec::new(),
+            trace_id: None,
+        }
+    }
+
+    pub fn not_found(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::NotFound, message)
+    }
+
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::Internal, message)
+    }
+
+    /// Wrap a validation error from a model constructor such as `User::new`,
+    /// attaching it to the field it names.
+    pub fn from_validation(error: impl Into<ValidationError>) -> Self {
+        let ValidationError { field, message } = error.into();
+        let error = Self::new(ErrorCode::ValidationFailed, "Validation failed");
+        match field {
+            Some(field) => error.with_field_error(field, message),
+            None => Self { message, ..error },
+        }
+    }
+
+    pub fn with_field_error(
+        mut self,
+        field: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        self.field_errors.push(FieldError {
+            field: field.into(),
+            message: message.into(),
+        });
+        self
+    }
+
+    pub fn with_trace_id(mut self, trace_id: impl Into<String>) -> Self {
+        self.trace_id = Some(trace_id.into());
+        self
+    }
+
+    pub fn http_status(&self) -> u16 {
+        self.code.http_status()
+    }
+
+    pub fn field_error(&self, field: &str) -> Option<&str> {
+        self.field_errors
+            .iter()
+            .find(|e| e.field == field)
+            .map(|e| e.message.as_str())
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)?;
+        for error in &self.field_errors {
+            write!(f, "; {}: {}", error.field, error.message)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_request_validation() {
+        let request = CreateUserRequest {
+            name: "John Doe".to_string(),
+            email: "john@example.com".to_string(),
+        };
+        assert_eq!(request.into_user(7).unwrap().id, 7);
+
+        let request = CreateUserRequest {
+            name: "John Doe".to_string(),
+            email: "invalid".to_string(),
+        };
+        let error = request.into_user(7).unwrap_err();
+        assert_eq!(error.code, ErrorCode::ValidationFailed);
+        assert_eq!(error.http_status(), 422);
+        assert_eq!(error.field_error("email"), Some("Invalid email format"));
+    }
+
+    #[test]
+    fn test_validation_mapping() {
+        let error = ApiError::from_validation(ValidationError::new("name", "Name cannot be empty"));
+        assert_eq!(error.field_error("name"), Some("Name cannot be empty"));
+
+        // The field comes from the error, not from words in its message.
+        let error = ApiError::from_validation(ValidationError::new(
+            "username",
+            "Name must not equal email",
+        ));
+        assert_eq!(
+            error.field_error("username"),
+            Some("Name must not equal email")
+        );
+        assert_eq!(error.field_errors.len(), 1);
+
+        let error = ApiError::from_validation("Something else went wrong");
+        assert!(error.field_errors.is_empty());
+        assert_eq!(error.message, "Something else went wrong");
+    }
+
+    #[test]
+    fn test_pagination() {
+        let request = PageRequest {
+            page: 2,
+            per_page: 10,
+        };
+        let list = ListResponse::paginate(1..=25, request);
+        assert_eq!(list.items, (11..=20).collect::<Vec<_>>());
+        assert_eq!(list.pagination.total, 25);
+        assert_eq!(list.pagination.total_pages, 3);
+        assert!(list.pagination.has_next());
+        assert!(list.pagination.has_previous());
+
+        let request = PageRequest {
+            page: 0,
+            per_page: 1000,
+        };
+        assert_eq!(request.normalized().page, 1);
+        assert_eq!(request.normalized().per_page, PageRequest::MAX_PER_PAGE);
+        assert_eq!(request.offset(), 0);
+
+        // Would overflow a 32-bit usize without widening first.
+        let far = PageRequest {
+            page: u32::MAX,
+            per_page: PageRequest::MAX_PER_PAGE,
+        };
+        let expected = (u64::from(u32::MAX) - 1) * u64::from(PageRequest::MAX_PER_PAGE);
+        assert_eq!(
+            far.offset(),
+            usize::try_from(expected).unwrap_or(usize::MAX)
+        );
+        let beyond = ListResponse::paginate(1..=5u32, far);
+        assert!(beyond.items.is_empty());
+        assert_eq!(beyond.pagination.total, 5);
+
+        let empty = ListResponse::<u32>::paginate(Vec::new(), PageRequest::default());
+        assert_eq!(empty.pagination.total_pages, 0);
+        assert!(!empty.pagination.has_next());
+    }
+}
@@ -0,0 +1,208 @@
+//! Compact binary encoding for CRDT updates: LEB128 varints and length-prefixed strings.
+
+use super::OpId;
+
+#[derive(Debug, Default)]
+pub struct Encoder {
+    buf: Vec<u8>,
+}
+
+impl Encoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn finish(self) -> Vec<u8> {
+        self.buf
+    }
+
+    pub fn u8(&mut self, value: u8) {
+        self.buf.push(value);
+    }
+
+    pub fn varint(&mut self, mut value: u64) {
+        loop {
+            let byte = (value & 0x7f) as u8;
+            value >>= 7;
+            if value == 0 {
+                self.buf.push(byte);
+                return;
+            }
+            self.buf.push(byte | 0x80);
+        }
+    }
+
+    pub fn bytes(&mut self, value: &[u8]) {
+        self.varint(value.len() as u64);
+        self.buf.extend_from_slice(value);
+    }
+
+    pub fn str(&mut self, value: &str) {
+        self.bytes(value.as_bytes());
+    }
+
+    pub fn op_id(&mut self, id: OpId) {
+        self.varint(id.replica);
+        self.varint(id.counter);
+    }
+}
+
+#[derive(Debug)]
+pub struct Decoder<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> Decoder<'a> {
+    pub fn new(buf: &'a [u8]) -> Self {
+        Self { buf }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.buf.is_empty()
+    }
+
+    pub fn u8(&mut self) -> Result<u8, String> {
+        let (&first, rest) = self
+            .buf
+            .split_first()
+            .ok_or_else(|| "Unexpected end of input".to_string())?;
+        self.buf = rest;
+        Ok(first)
+    }
+
+    pub fn varint(&mut self) -> Result<u64, String> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.u8()?;
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err("Varint too long".to_string())
+    }
+
+    pub fn bytes(&mut self) -> Result<&'a [u8], String> {
+        let len = usize::try_from(self.varint()?).map_err(|_| "Length overflow".to_string())?;
+        if len > self.buf.len() {
+            return Err("Unexpected end of input".to_string());
+        }
+        let (value, rest) = self.buf.split_at(len);
+        self.buf = rest;
+        Ok(value)
+    }
+
+    pub fn str(&mut self) -> Result<&'a str, String> {
+        std::str::from_utf8(self.bytes()?).map_err(|_| "Invalid UTF-8".to_string())
+    }
+
+    pub fn op_id(&mut self) -> Result<OpId, String> {
+        let replica = self.varint()?;
+        let counter = self.varint()?;
+        OpId { counter, replica }.checked()
+    }
+}
+
+/// Values that can be stored in CRDT containers and written to the binary format.
+pub trait Codec: Sized {
+    fn encode(&self, encoder: &mut Encoder);
+    fn decode(decoder: &mut Decoder<'_>) -> Result<Self, String>;
+}
+
+impl Codec for String {
+    fn encode(&self, encoder: &mut Encoder) {
+        encoder.str(self);
+    }
+
+    fn decode(decoder: &mut Decoder<'_>) -> Result<Self, String> {
+        decoder.str().map(str::to_string)
+    }
+}
+
+impl Codec for Vec<u8> {
+    fn encode(&self, encoder: &mut Encoder) {
+        encoder.bytes(self);
+    }
+
+    fn decode(decoder: &mut Decoder<'_>) -> Result<Self, String> {
+        decoder.bytes().map(<[u8]>::to_vec)
+    }
+}
+
+impl Codec for u64 {
+    fn encode(&self, encoder: &mut Encoder) {
+        encoder.varint(*self);
+    }
+
+    fn decode(decoder: &mut Decoder<'_>) -> Result<Self, String> {
+        decoder.varint()
+    }
+}
+
+impl Codec for i64 {
+    fn encode(&self, encoder: &mut Encoder) {
+        // Zigzag so small negative numbers stay short.
+        encoder.varint(((*self << 1) ^ (*self >> 63)) as u64);
+    }
+
+    fn decode(decoder: &mut Decoder<'_>) -> Result<Self, String> {
+        let raw = decoder.varint()?;
+        Ok((raw >> 1) as i64 ^ -((raw & 1) as i64))
+    }
+}
+
+impl Codec for bool {
+    fn encode(&self, encoder: &mut Encoder) {
+        encoder.u8(u8::from(*self));
+    }
+
+    fn decode(decoder: &mut Decoder<'_>) -> Result<Self, String> {
+        match decoder.u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            other => Err(format!("Invalid bool: {}", other)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roundtrip<T: Codec + PartialEq + std::fmt::Debug>(value: T) {
+        let mut encoder = Encoder::new();
+        value.encode(&mut encoder);
+        let bytes = encoder.finish();
+        let mut decoder = Decoder::new(&bytes);
+        assert_eq!(T::decode(&mut decoder).unwrap(), value);
+        assert!(decoder.is_empty());
+    }
+
+    #[test]
+    fn test_codec_roundtrip() {
+        roundtrip(0u64);
+        roundtrip(300u64);
+        roundtrip(u64::MAX);
+        roundtrip(-1i64);
+        roundtrip(i64::MIN);
+        roundtrip(true);
+        roundtrip("héllo".to_string());
+        roundtrip(vec![0u8, 255, 7]);
+    }
+
+    #[test]
+    fn test_varint_is_compact() {
+        let mut encoder = Encoder::new();
+        encoder.varint(127);
+        encoder.varint(128);
+        assert_eq!(encoder.finish(), vec![0x7f, 0x80, 0x01]);
+    }
+
+    #[test]
+    fn test_truncated_input() {
+        let mut decoder = Decoder::new(&[0x80]);
+        assert!(decoder.varint().is_err());
+        let mut decoder = Decoder::new(&[5, b'a']);
+        assert!(decoder.str().is_err());
+    }
+}
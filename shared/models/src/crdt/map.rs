@@ -0,0 +1,252 @@
+use std::collections::BTreeMap;
+
+use super::encoding::{Codec, Decoder, Encoder};
+use super::{OpId, ReplicaId};
+
+/// Write to a single key; `value: None` removes it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MapOp<V> {
+    pub key: String,
+    pub value: Option<V>,
+    pub stamp: OpId,
+}
+
+/// Last-writer-wins map. Removals are kept as tombstones so a stale write cannot
+/// resurrect a key that was deleted later.
+#[derive(Debug, Clone)]
+pub struct LwwMap<V> {
+    replica: ReplicaId,
+    clock: u64,
+    entries: BTreeMap<String, (Option<V>, OpId)>,
+}
+
+impl<V: Clone> LwwMap<V> {
+    pub fn new(replica: ReplicaId) -> Self {
+        Self {
+            replica,
+            clock: 0,
+            entries: BTreeMap::new(),
+        }
+    }
+
+    pub fn get(&self, key: &str) -> Option<&V> {
+        self.entries.get(key).and_then(|(value, _)| value.as_ref())
+    }
+
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Live entries in key order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &V)> {
+        self.entries
+            .iter()
+            .filter_map(|(key, (value, _))| value.as_ref().map(|v| (key.as_str(), v)))
+    }
+
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn set(&mut self, key: impl Into<String>, value: V) -> Result<MapOp<V>, String> {
+        self.write(key.into(), Some(value))
+    }
+
+    pub fn remove(&mut self, key: impl Into<String>) -> Result<MapOp<V>, String> {
+        self.write(key.into(), None)
+    }
+
+    /// Apply a remote write; returns whether it changed the map. Writes with a counter
+    /// above [`OpId::MAX_COUNTER`] are rejected.
+    pub fn apply(&mut self, op: MapOp<V>) -> Result<bool, String> {
+        op.stamp.checked()?;
+        self.clock = self.clock.max(op.stamp.counter);
+        if let Some((_, current)) = self.entries.get(&op.key) {
+            if *current >= op.stamp {
+                return Ok(false);
+            }
+        }
+        self.entries.insert(op.key, (op.value, op.stamp));
+        Ok(true)
+    }
+
+    /// Apply remote writes, stopping at the first one rejected.
+    pub fn apply_all(&mut self, ops: impl IntoIterator<Item = MapOp<V>>) -> Result<(), String> {
+        ops.into_iter()
+            .try_for_each(|op| self.apply(op).map(|_| ()))
+    }
+
+    /// Every write (including tombstones) needed to rebuild this map elsewhere.
+    pub fn snapshot(&self) -> Vec<MapOp<V>> {
+        self.entries
+            .iter()
+            .map(|(key, (value, stamp))| MapOp {
+                key: key.clone(),
+                value: value.clone(),
+                stamp: *stamp,
+            })
+            .collect()
+    }
+
+    fn write(&mut self, key: String, value: Option<V>) -> Result<MapOp<V>, String> {
+        self.clock =
+            OpId::next_counter(self.clock).ok_or("Operation counter exhausted".to_string())?;
+        let op = MapOp {
+            key,
+            value,
+            stamp: OpId {
+                counter: self.clock,
+                replica: self.replica,
+            },
+        };
+        self.entries
+            .insert(op.key.clone(), (op.value.clone(), op.stamp));
+        Ok(op)
+    }
+}
+
+pub fn encode_map_ops<V: Codec>(ops: &[MapOp<V>]) -> Vec<u8> {
+    let mut encoder = Encoder::new();
+    for op in ops {
+        encoder.op_id(op.stamp);
+        encoder.str(&op.key);
+        match &op.value {
+            Some(value) => {
+                encoder.u8(1);
+                value.encode(&mut encoder);
+            }
+            None => encoder.u8(0),
+        }
+    }
+    encoder.finish()
+}
+
+pub fn decode_map_ops<V: Codec>(bytes: &[u8]) -> Result<Vec<MapOp<V>>, String> {
+    let mut decoder = Decoder::new(bytes);
+    let mut ops = Vec::new();
+    while !decoder.is_empty() {
+        let stamp = decoder.op_id()?;
+        let key = decoder.str()?.to_string();
+        let value = match decoder.u8()? {
+            0 => None,
+            1 => Some(V::decode(&mut decoder)?),
+            other => return Err(format!("Invalid value flag: {}", other)),
+        };
+        ops.push(MapOp { key, value, stamp });
+    }
+    Ok(ops)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crdt::tests::Rng;
+
+    #[test]
+    fn test_set_get_remove() {
+        let mut map = LwwMap::new(1);
+        map.set("title", "Notes".to_string()).unwrap();
+        map.set("color", "yellow".to_string()).unwrap();
+        map.remove("color").unwrap();
+
+        assert_eq!(map.get("title").map(String::as_str), Some("Notes"));
+        assert!(!map.contains_key("color"));
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    fn test_stale_write_does_not_resurrect() {
+        let mut a = LwwMap::new(1);
+        let mut b = LwwMap::new(2);
+        let set = a.set("pinned", true).unwrap();
+        b.apply(set.clone()).unwrap();
+        let removed = b.remove("pinned").unwrap();
+
+        a.apply(removed).unwrap();
+        assert!(!a.apply(set).unwrap());
+        assert!(!a.contains_key("pinned"));
+    }
+
+    #[test]
+    fn test_encoding_roundtrip() {
+        let mut map = LwwMap::new(3);
+        let ops = vec![
+            map.set("count", 42u64).unwrap(),
+            map.remove("old").unwrap(),
+            map.set("é", 0).unwrap(),
+        ];
+        let bytes = encode_map_ops(&ops);
+        assert_eq!(decode_map_ops::<u64>(&bytes).unwrap(), ops);
+        assert!(decode_map_ops::<u64>(&bytes[..bytes.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn test_counter_overflow_is_rejected() {
+        let mut map = LwwMap::new(1);
+        let hostile = MapOp {
+            key: "title".to_string(),
+            value: Some(1u64),
+            stamp: OpId {
+                counter: u64::MAX,
+                replica: 2,
+            },
+        };
+        assert!(map.apply(hostile.clone()).is_err());
+        assert!(decode_map_ops::<u64>(&encode_map_ops(&[hostile])).is_err());
+
+        let last = MapOp {
+            key: "title".to_string(),
+            value: Some(2u64),
+            stamp: OpId {
+                counter: OpId::MAX_COUNTER,
+                replica: 2,
+            },
+        };
+        assert!(map.apply(last).unwrap());
+        // The clock is exhausted: local writes fail instead of overflowing.
+        assert!(map.set("title", 3).is_err());
+        assert_eq!(map.get("title"), Some(&2));
+    }
+
+    #[test]
+    fn test_convergence_under_shuffled_delivery() {
+        let mut rng = Rng::new(42);
+        let keys = ["a", "b", "c", "d"];
+        for _ in 0..20 {
+            let mut replicas: Vec<LwwMap<i64>> = (1..=3).map(LwwMap::new).collect();
+            let mut log = Vec::new();
+            for step in 0..40 {
+                let r = rng.below(replicas.len());
+                let key = keys[rng.below(keys.len())];
+                let op = if rng.below(4) == 0 {
+                    replicas[r].remove(key)
+                } else {
+                    replicas[r].set(key, step as i64 - 20)
+                }
+                .unwrap();
+                log.push(op);
+            }
+
+            for replica in replicas.iter_mut() {
+                let mut incoming = log.clone();
+                rng.shuffle(&mut incoming);
+                let bytes = encode_map_ops(&incoming);
+                replica.apply_all(decode_map_ops(&bytes).unwrap()).unwrap();
+            }
+
+            let expected: Vec<(String, i64)> = replicas[0]
+                .iter()
+                .map(|(k, v)| (k.to_string(), *v))
+                .collect();
+            for replica in &replicas {
+                let actual: Vec<(String, i64)> =
+                    replica.iter().map(|(k, v)| (k.to_string(), *v)).collect();
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+}
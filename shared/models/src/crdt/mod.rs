@@ -0,0 +1,79 @@
+//! Conflict-free replicated data types for documents edited concurrently, such as
+//! shared notes attached to a user. Replicas exchange operations in any order and
+//! converge once they have seen the same set.
+
+pub mod encoding;
+pub mod map;
+pub mod text;
+
+pub use encoding::Codec;
+pub use map::*;
+pub use text::*;
+
+use std::fmt;
+
+pub type ReplicaId = u64;
+
+/// Lamport timestamp identifying an operation. Ordered by counter, then replica.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct OpId {
+    pub counter: u64,
+    pub replica: ReplicaId,
+}
+
+impl OpId {
+    /// Highest counter a replica issues. Operations carrying `u64::MAX` are rejected, so
+    /// a clock advanced by a remote operation can never overflow on its next tick.
+    pub const MAX_COUNTER: u64 = u64::MAX - 1;
+
+    /// The counter after `counter`, or `None` once the clock is exhausted.
+    pub(crate) fn next_counter(counter: u64) -> Option<u64> {
+        counter
+            .checked_add(1)
+            .filter(|&next| next <= Self::MAX_COUNTER)
+    }
+
+    /// `self`, or an error if its counter is above [`OpId::MAX_COUNTER`].
+    pub(crate) fn checked(self) -> Result<Self, String> {
+        if self.counter > Self::MAX_COUNTER {
+            return Err(format!("Operation counter out of range: {}", self));
+        }
+        Ok(self)
+    }
+}
+
+impl fmt::Display for OpId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}@{}", self.counter, self.replica)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    /// Small deterministic xorshift generator for shuffling operation delivery.
+    pub struct Rng(u64);
+
+    impl Rng {
+        pub fn new(seed: u64) -> Self {
+            Self(seed.max(1))
+        }
+
+        pub fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        pub fn below(&mut self, n: usize) -> usize {
+            (self.next() % n as u64) as usize
+        }
+
+        pub fn shuffle<T>(&mut self, items: &mut [T]) {
+            for i in (1..items.len()).rev() {
+                let j = self.below(i + 1);
+                items.swap(i, j);
+            }
+        }
+    }
+}
@@ -0,0 +1,485 @@
+use super::encoding::{Decoder, Encoder};
+use super::{OpId, ReplicaId};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TextOp {
+    /// Insert `ch` immediately after `origin` (or at the start when `None`).
+    Insert {
+        id: OpId,
+        origin: Option<OpId>,
+        ch: char,
+    },
+    Delete {
+        target: OpId,
+    },
+}
+
+#[derive(Debug, Clone)]
+struct Element {
+    id: OpId,
+    ch: char,
+    deleted: bool,
+}
+
+/// Replicated text sequence (RGA). Deleted characters stay as tombstones so that
+/// concurrent inserts next to them can still be placed.
+#[derive(Debug, Clone)]
+pub struct TextDoc {
+    replica: ReplicaId,
+    clock: u64,
+    elements: Vec<Element>,
+    pending: Vec<TextOp>,
+}
+
+impl TextDoc {
+    pub fn new(replica: ReplicaId) -> Self {
+        Self {
+            replica,
+            clock: 0,
+            elements: Vec::new(),
+            pending: Vec::new(),
+        }
+    }
+
+    pub fn text(&self) -> String {
+        self.visible().map(|e| e.ch).collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.visible().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Operations received before their dependencies and not applied yet.
+    pub fn pending_len(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Insert `text` before the character at `pos` (in chars) and return the operations to broadcast.
+    pub fn insert(&mut self, pos: usize, text: &str) -> Result<Vec<TextOp>, String> {
+        let len = self.len();
+        if pos > len {
+            return Err(format!("Position {} out of bounds (len {})", pos, len));
+        }
+
+        let count = text.chars().count() as u64;
+        if self
+            .clock
+            .checked_add(count)
+            .map_or(true, |last| last > OpId::MAX_COUNTER)
+        {
+            return Err("Operation counter exhausted".to_string());
+        }
+
+        let mut origin = pos.checked_sub(1).map(|p| self.visible_id(p));
+        let mut ops = Vec::with_capacity(text.len());
+        for ch in text.chars() {
+            // Checked against `MAX_COUNTER` for the whole text above.
+            self.clock += 1;
+            let id = OpId {
+                counter: self.clock,
+                replica: self.replica,
+            };
+            self.integrate_insert(id, origin, ch);
+            ops.push(TextOp::Insert { id, origin, ch });
+            origin = Some(id);
+        }
+        Ok(ops)
+    }
+
+    /// Delete `count` characters starting at `pos` and return the operations to broadcast.
+    pub fn delete(&mut self, pos: usize, count: usize) -> Result<Vec<TextOp>, String> {
+        let len = self.len();
+        // `count` may come from a remote peer, so the end must not overflow.
+        if pos.checked_add(count).map_or(true, |end| end > len) {
+            return Err(format!(
+                "Range {}..{} out of bounds (len {})",
+                pos,
+                pos.saturating_add(count),
+                len
+            ));
+        }
+
+        let targets: Vec<OpId> = self.visible().skip(pos).take(count).map(|e| e.id).collect();
+        for target in &targets {
+            if let Some(index) = self.index_of(*target) {
+                self.elements[index].deleted = true;
+            }
+        }
+        Ok(targets
+            .into_iter()
+            .map(|target| TextOp::Delete { target })
+            .collect())
+    }
+
+    /// Apply a remote operation. Duplicates are ignored and operations whose
+    /// dependencies have not arrived yet are buffered until they do. Inserts with a
+    /// counter above [`OpId::MAX_COUNTER`] are rejected.
+    pub fn apply(&mut self, op: TextOp) -> Result<(), String> {
+        if let TextOp::Insert { id, .. } = op {
+            id.checked()?;
+        }
+        if !self.try_apply(&op) {
+            self.pending.push(op);
+            return Ok(());
+        }
+
+        let mut progressed = true;
+        while progressed {
+            progressed = false;
+            let mut i = 0;
+            while i < self.pending.len() {
+                let op = self.pending[i].clone();
+                if self.try_apply(&op) {
+                    self.pending.swap_remove(i);
+                    progressed = true;
+                } else {
+                    i += 1;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Apply remote operations, stopping at the first one rejected.
+    pub fn apply_all(&mut self, ops: impl IntoIterator<Item = TextOp>) -> Result<(), String> {
+        ops.into_iter().try_for_each(|op| self.apply(op))
+    }
+
+    /// Every operation needed to rebuild this document on an empty replica.
+    pub fn snapshot(&self) -> Vec<TextOp> {
+        let mut ops = Vec::with_capacity(self.elements.len());
+        let mut origin = None;
+        for element in &self.elements {
+            ops.push(TextOp::Insert {
+                id: element.id,
+                origin,
+                ch: element.ch,
+            });
+            origin = Some(element.id);
+        }
+        ops.extend(
+            self.elements
+                .iter()
+                .filter(|e| e.deleted)
+                .map(|e| TextOp::Delete { target: e.id }),
+        );
+        ops
+    }
+
+    fn try_apply(&mut self, op: &TextOp) -> bool {
+        match *op {
+            TextOp::Insert { id, origin, ch } => {
+                if self.index_of(id).is_some() {
+                    return true;
+                }
+                if origin.is_some_and(|o| self.index_of(o).is_none()) {
+                    return false;
+                }
+                self.clock = self.clock.max(id.counter);
+                self.integrate_insert(id, origin, ch);
+                true
+            }
+            TextOp::Delete { target } => match self.index_of(target) {
+                Some(index) => {
+                    self.elements[index].deleted = true;
+                    true
+                }
+                None => false,
+            },
+        }
+    }
+
+    /// RGA placement: right after the origin, skipping newer concurrent siblings
+    /// (and their descendants, which are always newer still).
+    fn integrate_insert(&mut self, id: OpId, origin: Option<OpId>, ch: char) {
+        let mut index = match origin {
+            Some(origin) => self.index_of(origin).map_or(0, |i| i + 1),
+            None => 0,
+        };
+        while index < self.elements.len() && self.elements[index].id > id {
+            index += 1;
+        }
+        self.elements.insert(
+            index,
+            Element {
+                id,
+                ch,
+                deleted: false,
+            },
+        );
+    }
+
+    fn index_of(&self, id: OpId) -> Option<usize> {
+        self.elements.iter().position(|e| e.id == id)
+    }
+
+    fn visible(&self) -> impl Iterator<Item = &Element> {
+        self.elements.iter().filter(|e| !e.deleted)
+    }
+
+    fn visible_id(&self, pos: usize) -> OpId {
+        self.visible()
+            .nth(pos)
+            .map(|e| e.id)
+            .expect("position checked by caller")
+    }
+}
+
+const TAG_INSERT_RUN: u8 = 0;
+const TAG_DELETE: u8 = 1;
+
+/// Encode text operations. Consecutive typing by one replica (each insert following
+/// the previous one) is stored as a single run with its characters as UTF-8.
+pub fn encode_text_ops(ops: &[TextOp]) -> Vec<u8> {
+    let mut encoder = Encoder::new();
+    let mut i = 0;
+    while i < ops.len() {
+        match ops[i] {
+            TextOp::Insert { id, origin, ch } => {
+                let mut run = String::from(ch);
+                let mut last = id;
+                while let Some(TextOp::Insert {
+                    id: next,
+                    origin: Some(next_origin),
+                    ch,
+                }) = ops.get(i + 1)
+                {
+                    if *next_origin != last
+                        || next.replica != id.replica
+                        || last.counter.checked_add(1) != Some(next.counter)
+                    {
+                        break;
+                    }
+                    run.push(*ch);
+                    last = *next;
+                    i += 1;
+                }
+
+                encoder.u8(TAG_INSERT_RUN);
+                encoder.op_id(id);
+                match origin {
+                    Some(origin) => {
+                        encoder.u8(1);
+                        encoder.op_id(origin);
+                    }
+                    None => encoder.u8(0),
+                }
+                encoder.str(&run);
+            }
+            TextOp::Delete { target } => {
+                encoder.u8(TAG_DELETE);
+                encoder.op_id(target);
+            }
+        }
+        i += 1;
+    }
+    encoder.finish()
+}
+
+pub fn decode_text_ops(bytes: &[u8]) -> Result<Vec<TextOp>, String> {
+    let mut decoder = Decoder::new(bytes);
+    let mut ops = Vec::new();
+    while !decoder.is_empty() {
+        match decoder.u8()? {
+            TAG_INSERT_RUN => {
+                let start = decoder.op_id()?;
+                let mut origin = match decoder.u8()? {
+                    0 => None,
+                    1 => Some(decoder.op_id()?),
+                    other => return Err(format!("Invalid origin flag: {}", other)),
+                };
+                let mut counter = Some(start.counter);
+                for ch in decoder.str()?.chars() {
+                    let id = OpId {
+                        counter: counter.ok_or_else(|| {
+                            format!("Operation counter out of range in run from {}", start)
+                        })?,
+                        replica: start.replica,
+                    };
+                    ops.push(TextOp::Insert { id, origin, ch });
+                    origin = Some(id);
+                    counter = OpId::next_counter(id.counter);
+                }
+            }
+            TAG_DELETE => ops.push(TextOp::Delete {
+                target: decoder.op_id()?,
+            }),
+            other => return Err(format!("Unknown text op tag: {}", other)),
+        }
+    }
+    Ok(ops)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crdt::tests::Rng;
+
+    #[test]
+    fn test_local_editing() {
+        let mut doc = TextDoc::new(1);
+        doc.insert(0, "helo").unwrap();
+        doc.insert(3, "l").unwrap();
+        doc.insert(5, " world").unwrap();
+        doc.delete(0, 1).unwrap();
+        doc.insert(0, "H").unwrap();
+        assert_eq!(doc.text(), "Hello world");
+        assert!(doc.insert(99, "x").is_err());
+        assert!(doc.delete(10, 5).is_err());
+        assert!(doc.delete(1, usize::MAX).is_err());
+        assert_eq!(doc.text(), "Hello world");
+    }
+
+    #[test]
+    fn test_concurrent_inserts_at_same_position() {
+        let mut a = TextDoc::new(1);
+        let base = a.insert(0, "ac").unwrap();
+        let mut b = TextDoc::new(2);
+        b.apply_all(base).unwrap();
+
+        let from_a = a.insert(1, "X").unwrap();
+        let from_b = b.insert(1, "Y").unwrap();
+        a.apply_all(from_b).unwrap();
+        b.apply_all(from_a).unwrap();
+
+        assert_eq!(a.text(), b.text());
+        assert_eq!(a.text(), "aYXc");
+    }
+
+    #[test]
+    fn test_out_of_order_delivery_is_buffered() {
+        let mut a = TextDoc::new(1);
+        let mut ops = a.insert(0, "abc").unwrap();
+        ops.extend(a.delete(1, 1).unwrap());
+
+        let mut b = TextDoc::new(2);
+        for op in ops.into_iter().rev() {
+            b.apply(op).unwrap();
+        }
+        assert_eq!(b.text(), "ac");
+        assert_eq!(b.pending_len(), 0);
+    }
+
+    #[test]
+    fn test_encoding_roundtrip_and_runs() {
+        let mut doc = TextDoc::new(7);
+        let mut ops = doc.insert(0, "hello, wörld").unwrap();
+        ops.extend(doc.delete(5, 2).unwrap());
+        ops.extend(doc.insert(0, ">").unwrap());
+
+        let bytes = encode_text_ops(&ops);
+        assert_eq!(decode_text_ops(&bytes).unwrap(), ops);
+        // One run header plus the UTF-8 text, not one record per character.
+        assert!(bytes.len() < 40, "encoded {} bytes", bytes.len());
+
+        assert!(decode_text_ops(&[9]).is_err());
+    }
+
+    #[test]
+    fn test_counter_overflow_is_rejected() {
+        let at = |counter| OpId {
+            counter,
+            replica: 5,
+        };
+        // A run of three starting two below the limit would need counter `u64::MAX`.
+        let run = [
+            TextOp::Insert {
+                id: at(OpId::MAX_COUNTER - 1),
+                origin: None,
+                ch: 'a',
+            },
+            TextOp::Insert {
+                id: at(OpId::MAX_COUNTER),
+                origin: Some(at(OpId::MAX_COUNTER - 1)),
+                ch: 'b',
+            },
+        ];
+        let mut encoder = Encoder::new();
+        encoder.u8(TAG_INSERT_RUN);
+        encoder.op_id(at(OpId::MAX_COUNTER - 1));
+        encoder.u8(0);
+        encoder.str("abc");
+        assert!(decode_text_ops(&encoder.finish()).is_err());
+        let mut encoder = Encoder::new();
+        encoder.u8(TAG_INSERT_RUN);
+        encoder.op_id(at(u64::MAX));
+        encoder.u8(0);
+        encoder.str("a");
+        assert!(decode_text_ops(&encoder.finish()).is_err());
+        assert_eq!(decode_text_ops(&encode_text_ops(&run)).unwrap(), run);
+
+        let mut doc = TextDoc::new(1);
+        assert!(
+            doc.apply(TextOp::Insert {
+                id: at(u64::MAX),
+                origin: None,
+                ch: 'x',
+            })
+            .is_err()
+        );
+        doc.apply_all(run).unwrap();
+        assert_eq!(doc.text(), "ab");
+        // The clock is exhausted: local edits fail instead of overflowing.
+        assert!(doc.insert(0, "x").is_err());
+        assert_eq!(doc.text(), "ab");
+    }
+
+    #[test]
+    fn test_convergence_under_shuffled_delivery() {
+        let mut rng = Rng::new(0x5eed);
+        for round in 0..20 {
+            let mut replicas: Vec<TextDoc> = (1..=3).map(TextDoc::new).collect();
+            let mut log: Vec<(usize, TextOp)> = Vec::new();
+
+            for step in 0..30 {
+                let r = rng.below(replicas.len());
+                let doc = &mut replicas[r];
+                let ops = if doc.is_empty() || rng.below(3) > 0 {
+                    let pos = rng.below(doc.len() + 1);
+                    let ch = char::from(b'a' + (step % 26) as u8);
+                    doc.insert(pos, &ch.to_string()).unwrap()
+                } else {
+                    let pos = rng.below(doc.len());
+                    doc.delete(pos, 1).unwrap()
+                };
+                log.extend(ops.into_iter().map(|op| (r, op)));
+
+                // Occasionally deliver part of the log so later edits build on remote ones.
+                if rng.below(4) == 0 {
+                    let target = rng.below(replicas.len());
+                    for (_, op) in log.iter().filter(|(from, _)| *from != target) {
+                        replicas[target].apply(op.clone()).unwrap();
+                    }
+                }
+            }
+
+            for (target, doc) in replicas.iter_mut().enumerate() {
+                let mut incoming: Vec<TextOp> = log
+                    .iter()
+                    .filter(|(from, _)| *from != target)
+                    .map(|(_, op)| op.clone())
+                    .collect();
+                incoming.extend(incoming.clone());
+                rng.shuffle(&mut incoming);
+                let bytes = encode_text_ops(&incoming);
+                doc.apply_all(decode_text_ops(&bytes).unwrap()).unwrap();
+                assert_eq!(doc.pending_len(), 0);
+            }
+
+            let text = replicas[0].text();
+            assert!(
+                replicas.iter().all(|d| d.text() == text),
+                "round {} diverged",
+                round
+            );
+
+            let mut fresh = TextDoc::new(9);
+            fresh.apply_all(replicas[1].snapshot()).unwrap();
+            assert_eq!(fresh.text(), text);
+        }
+    }
+}
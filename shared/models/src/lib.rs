@@ -1,3 +1,13 @@
+pub mod api;
+pub mod crdt;
+pub mod money;
+pub mod profile;
+pub mod sync;
 pub mod user;
+pub mod validation;
 
+pub use money::*;
+pub use profile::*;
+pub use sync::*;
 pub use user::*;
+pub use validation::*;
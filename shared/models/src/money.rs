@@ -0,0 +1,524 @@
+use std::fmt;
+
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// An ISO 4217 currency. Obtain one with [`Currency::from_code`] or the associated constants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Currency {
+    code: &'static str,
+    numeric: u16,
+    minor_units: u8,
+    symbol: &'static str,
+}
+
+macro_rules! currencies {
+    ($($name:ident => ($code:literal, $numeric:literal, $minor:literal, $symbol:literal)),* $(,)?) => {
+        impl Currency {
+            $(
+                pub const $name: Currency = Currency {
+                    code: $code,
+                    numeric: $numeric,
+                    minor_units: $minor,
+                    symbol: $symbol,
+                };
+            )*
+
+            /// Every currency known to this crate, in code order.
+            pub const ALL: &'static [Currency] = &[$(Currency::$name),*];
+        }
+    };
+}
+
+currencies! {
+    AED => ("AED", 784, 2, "د.إ"),
+    ARS => ("ARS", 32, 2, "$"),
+    AUD => ("AUD", 36, 2, "A$"),
+    BHD => ("BHD", 48, 3, "BD"),
+    BRL => ("BRL", 986, 2, "R$"),
+    CAD => ("CAD", 124, 2, "CA$"),
+    CHF => ("CHF", 756, 2, "CHF"),
+    CLP => ("CLP", 152, 0, "$"),
+    CNY => ("CNY", 156, 2, "¥"),
+    CZK => ("CZK", 203, 2, "Kč"),
+    DKK => ("DKK", 208, 2, "kr"),
+    EUR => ("EUR", 978, 2, "€"),
+    GBP => ("GBP", 826, 2, "£"),
+    HKD => ("HKD", 344, 2, "HK$"),
+    HUF => ("HUF", 348, 2, "Ft"),
+    IDR => ("IDR", 360, 2, "Rp"),
+    ILS => ("ILS", 376, 2, "₪"),
+    INR => ("INR", 356, 2, "₹"),
+    ISK => ("ISK", 352, 0, "kr"),
+    JOD => ("JOD", 400, 3, "JD"),
+    JPY => ("JPY", 392, 0, "¥"),
+    KRW => ("KRW", 410, 0, "₩"),
+    KWD => ("KWD", 414, 3, "KD"),
+    KZT => ("KZT", 398, 2, "₸"),
+    MXN => ("MXN", 484, 2, "MX$"),
+    NOK => ("NOK", 578, 2, "kr"),
+    NZD => ("NZD", 554, 2, "NZ$"),
+    OMR => ("OMR", 512, 3, "OMR"),
+    PLN => ("PLN", 985, 2, "zł"),
+    RUB => ("RUB", 643, 2, "₽"),
+    SAR => ("SAR", 682, 2, "SAR"),
+    SEK => ("SEK", 752, 2, "kr"),
+    SGD => ("SGD", 702, 2, "S$"),
+    THB => ("THB", 764, 2, "฿"),
+    TRY => ("TRY", 949, 2, "₺"),
+    TWD => ("TWD", 901, 2, "NT$"),
+    UAH => ("UAH", 980, 2, "₴"),
+    USD => ("USD", 840, 2, "$"),
+    VND => ("VND", 704, 0, "₫"),
+    ZAR => ("ZAR", 710, 2, "R"),
+}
+
+impl Currency {
+    pub fn from_code(code: &str) -> Result<Self, String> {
+        Self::ALL
+            .iter()
+            .find(|c| c.code.eq_ignore_ascii_case(code))
+            .copied()
+            .ok_or_else(|| format!("Unknown currency: {}", code))
+    }
+
+    /// ISO 4217 alphabetic code, e.g. `"USD"`.
+    pub fn code(&self) -> &'static str {
+        self.code
+    }
+
+    /// ISO 4217 numeric code, e.g. `840` for USD.
+    pub fn numeric(&self) -> u16 {
+        self.numeric
+    }
+
+    /// Number of decimal places (2 for USD, 0 for JPY, 3 for KWD).
+    pub fn minor_units(&self) -> u8 {
+        self.minor_units
+    }
+
+    pub fn symbol(&self) -> &'static str {
+        self.symbol
+    }
+
+    /// Number of minor units in one major unit (100 for USD, 1 for JPY, 1000 for KWD).
+    pub fn minor_per_major(&self) -> i64 {
+        10_i64.pow(u32::from(self.minor_units))
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code)
+    }
+}
+
+impl Serialize for Currency {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.code)
+    }
+}
+
+impl<'de> Deserialize<'de> for Currency {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let code = String::deserialize(deserializer)?;
+        Currency::from_code(&code).map_err(D::Error::custom)
+    }
+}
+
+/// Separators and symbol placement used when rendering numbers for a locale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberFormat {
+    pub decimal: char,
+    pub group: Option<char>,
+    pub symbol_first: bool,
+    pub symbol_space: bool,
+}
+
+impl NumberFormat {
+    /// Look up the format for a BCP 47 locale by its language (and region, where it matters).
+    /// Unknown locales fall back to `en-US` conventions.
+    pub fn for_locale(locale: &str) -> Self {
+        let mut parts = locale.split(['-', '_']);
+        let language = parts.next().unwrap_or("").to_ascii_lowercase();
+        let region = parts.find(|p| p.len() == 2).map(|p| p.to_ascii_uppercase());
+
+        match (language.as_str(), region.as_deref()) {
+            ("de", Some("CH")) => Self::new('.', Some('\''), true, true),
+            ("de" | "es" | "it" | "nl" | "pt" | "tr" | "id" | "da", _) => {
+                Self::new(',', Some('.'), false, true)
+            }
+            ("fr" | "ru" | "uk" | "pl" | "cs" | "sv" | "nb" | "fi" | "kk", _) => {
+                Self::new(',', Some('\u{202f}'), false, true)
+            }
+            _ => Self::new('.', Some(','), true, false),
+        }
+    }
+
+    const fn new(
+        decimal: char,
+        group: Option<char>,
+        symbol_first: bool,
+        symbol_space: bool,
+    ) -> Self {
+        Self {
+            decimal,
+            group,
+            symbol_first,
+            symbol_space,
+        }
+    }
+
+    /// Format an integer amount scaled by `10^scale`, e.g. `(123456, 2)` as `1,234.56`.
+    pub fn format_scaled(&self, value: i128, scale: u8) -> String {
+        let digits = value.unsigned_abs().to_string();
+        let scale = usize::from(scale);
+        let padded = format!("{:0>width$}", digits, width = scale + 1);
+        let (int_part, frac_part) = padded.split_at(padded.len() - scale);
+
+        let mut out = String::new();
+        if value < 0 {
+            out.push('-');
+        }
+        out.push_str(&self.group_digits(int_part));
+        if scale > 0 {
+            out.push(self.decimal);
+            out.push_str(frac_part);
+        }
+        out
+    }
+
+    /// Insert group separators into a run of ASCII digits.
+    pub fn group_digits(&self, digits: &str) -> String {
+        let Some(group) = self.group else {
+            return digits.to_string();
+        };
+
+        let mut out = String::with_capacity(digits.len() + digits.len() / 3);
+        for (i, c) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i) % 3 == 0 {
+                out.push(group);
+            }
+            out.push(c);
+        }
+        out
+    }
+}
+
+/// An amount of money stored as an integer count of the currency's minor units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Money {
+    minor: i64,
+    currency: Currency,
+}
+
+impl Money {
+    pub fn from_minor(minor: i64, currency: Currency) -> Self {
+        Self { minor, currency }
+    }
+
+    pub fn zero(currency: Currency) -> Self {
+        Self::from_minor(0, currency)
+    }
+
+    /// Create from whole major units, e.g. `Money::from_major(5, Currency::USD)` is $5.00
+    pub fn from_major(major: i64, currency: Currency) -> Result<Self, String> {
+        major
+            .checked_mul(currency.minor_per_major())
+            .map(|minor| Self::from_minor(minor, currency))
+            .ok_or_else(|| "Amount overflow".to_string())
+    }
+
+    /// Parse a plain decimal string such as `"-12.5"` without going through floating point.
+    pub fn parse(amount: &str, currency: Currency) -> Result<Self, String> {
+        let amount = amount.trim();
+        let (negative, unsigned) = match amount.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, amount.strip_prefix('+').unwrap_or(amount)),
+        };
+        let (int_part, frac_part) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+
+        let is_digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+        if (int_part.is_empty() && frac_part.is_empty())
+            || !is_digits(int_part)
+            || !is_digits(frac_part)
+        {
+            return Err(format!("Invalid amount: {}", amount));
+        }
+        if frac_part.len() > usize::from(currency.minor_units) {
+            return Err(format!(
+                "{} supports at most {} decimal places",
+                currency.code, currency.minor_units
+            ));
+        }
+
+        // Work in i128 so the magnitude of `i64::MIN` can be parsed before negating.
+        let overflow = || "Amount overflow".to_string();
+        let major: i128 = if int_part.is_empty() {
+            0
+        } else {
+            int_part.parse().map_err(|_| overflow())?
+        };
+        let padded = format!(
+            "{:0<width$}",
+            frac_part,
+            width = usize::from(currency.minor_units)
+        );
+        let fraction: i128 = if padded.is_empty() {
+            0
+        } else {
+            padded.parse().map_err(|_| overflow())?
+        };
+
+        let minor = major
+            .checked_mul(i128::from(currency.minor_per_major()))
+            .and_then(|m| m.checked_add(fraction))
+            .map(|m| if negative { -m } else { m })
+            .and_then(|m| i64::try_from(m).ok())
+            .ok_or_else(overflow)?;
+
+        Ok(Self::from_minor(minor, currency))
+    }
+
+    pub fn minor(&self) -> i64 {
+        self.minor
+    }
+
+    pub fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.minor == 0
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.minor < 0
+    }
+
+    pub fn checked_add(self, other: Money) -> Result<Self, String> {
+        self.ensure_same_currency(&other)?;
+        self.minor
+            .checked_add(other.minor)
+            .map(|minor| Self::from_minor(minor, self.currency))
+            .ok_or_else(|| "Amount overflow".to_string())
+    }
+
+    pub fn checked_sub(self, other: Money) -> Result<Self, String> {
+        self.ensure_same_currency(&other)?;
+        self.minor
+            .checked_sub(other.minor)
+            .map(|minor| Self::from_minor(minor, self.currency))
+            .ok_or_else(|| "Amount overflow".to_string())
+    }
+
+    pub fn checked_mul(self, factor: i64) -> Result<Self, String> {
+        self.minor
+            .checked_mul(factor)
+            .map(|minor| Self::from_minor(minor, self.currency))
+            .ok_or_else(|| "Amount overflow".to_string())
+    }
+
+    pub fn checked_neg(self) -> Result<Self, String> {
+        self.checked_mul(-1)
+    }
+
+    /// Split proportionally to `ratios`, handing leftover minor units to the earliest parts
+    /// so the results always sum to the original amount.
+    pub fn allocate(self, ratios: &[u32]) -> Result<Vec<Self>, String> {
+        let total: u64 = ratios.iter().map(|&r| u64::from(r)).sum();
+        if total == 0 {
+            return Err("Allocation ratios must not all be zero".to_string());
+        }
+
+        let amount = i128::from(self.minor);
+        let mut parts: Vec<i128> = ratios
+            .iter()
+            .map(|&r| amount * i128::from(r) / i128::from(total))
+            .collect();
+
+        let mut remainder = amount - parts.iter().sum::<i128>();
+        let step = remainder.signum();
+        for i in (0..parts.len()).cycle() {
+            if remainder == 0 {
+                break;
+            }
+            if ratios[i] > 0 {
+                parts[i] += step;
+                remainder -= step;
+            }
+        }
+
+        Ok(parts
+            .into_iter()
+            .map(|minor| Self::from_minor(minor as i64, self.currency))
+            .collect())
+    }
+
+    /// Split into `n` parts that differ by at most one minor unit.
+    pub fn split(self, n: usize) -> Result<Vec<Self>, String> {
+        if n == 0 {
+            return Err("Cannot split into zero parts".to_string());
+        }
+        self.allocate(&vec![1; n])
+    }
+
+    /// Plain decimal representation without symbol or grouping, e.g. `-1234.50`.
+    pub fn amount_string(&self) -> String {
+        NumberFormat::new('.', None, true, false)
+            .format_scaled(i128::from(self.minor), self.currency.minor_units)
+    }
+
+    /// Format with the currency symbol and separators of `locale`, e.g. `$1,234.50` for
+    /// `en-US` or `1.234,50 €` for `de-DE`.
+    pub fn format(&self, locale: &str) -> String {
+        let format = NumberFormat::for_locale(locale);
+        let number = format.format_scaled(i128::from(self.minor).abs(), self.currency.minor_units);
+        let sign = if self.is_negative() { "-" } else { "" };
+        let space = if format.symbol_space { "\u{a0}" } else { "" };
+        let symbol = self.currency.symbol;
+
+        if format.symbol_first {
+            format!("{}{}{}{}", sign, symbol, space, number)
+        } else {
+            format!("{}{}{}{}", sign, number, space, symbol)
+        }
+    }
+
+    fn ensure_same_currency(&self, other: &Money) -> Result<(), String> {
+        if self.currency != other.currency {
+            return Err(format!(
+                "Currency mismatch: {} and {}",
+                self.currency.code, other.currency.code
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.amount_string(), self.currency.code)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct MoneyRepr {
+    amount: String,
+    currency: Currency,
+}
+
+impl Serialize for Money {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        MoneyRepr {
+            amount: self.amount_string(),
+            currency: self.currency,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Money {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = MoneyRepr::deserialize(deserializer)?;
+        Money::parse(&repr.amount, repr.currency).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn usd(minor: i64) -> Money {
+        Money::from_minor(minor, Currency::USD)
+    }
+
+    #[test]
+    fn test_currency_lookup() {
+        assert_eq!(Currency::from_code("usd").unwrap(), Currency::USD);
+        assert_eq!(Currency::JPY.minor_per_major(), 1);
+        assert_eq!(Currency::KWD.minor_per_major(), 1000);
+        assert_eq!(
+            Currency::from_code("XXX").unwrap_err(),
+            "Unknown currency: XXX"
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Money::parse("12.34", Currency::USD).unwrap(), usd(1234));
+        assert_eq!(Money::parse("-0.5", Currency::USD).unwrap(), usd(-50));
+        assert_eq!(Money::parse("7", Currency::USD).unwrap(), usd(700));
+        assert_eq!(
+            Money::parse("1.234", Currency::KWD).unwrap(),
+            Money::from_minor(1234, Currency::KWD)
+        );
+        assert!(Money::parse("1.234", Currency::USD).is_err());
+        assert!(Money::parse("1.5", Currency::JPY).is_err());
+        assert!(Money::parse("abc", Currency::USD).is_err());
+        assert!(Money::parse("", Currency::USD).is_err());
+        assert!(Money::parse("99999999999999999999", Currency::USD).is_err());
+        assert!(Money::parse("92233720368547758.08", Currency::USD).is_err());
+    }
+
+    #[test]
+    fn test_extremes_roundtrip() {
+        for minor in [i64::MIN, i64::MAX, -1, 0] {
+            for currency in [Currency::USD, Currency::JPY, Currency::KWD] {
+                let money = Money::from_minor(minor, currency);
+                let json = serde_json::to_string(&money).unwrap();
+                assert_eq!(serde_json::from_str::<Money>(&json).unwrap(), money);
+            }
+        }
+    }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        assert_eq!(usd(150).checked_add(usd(250)).unwrap(), usd(400));
+        assert_eq!(usd(150).checked_sub(usd(250)).unwrap(), usd(-100));
+        assert_eq!(usd(150).checked_mul(3).unwrap(), usd(450));
+        assert_eq!(
+            usd(i64::MAX).checked_add(usd(1)).unwrap_err(),
+            "Amount overflow"
+        );
+        assert_eq!(
+            usd(1)
+                .checked_add(Money::from_minor(1, Currency::EUR))
+                .unwrap_err(),
+            "Currency mismatch: USD and EUR"
+        );
+    }
+
+    #[test]
+    fn test_allocate_keeps_every_cent() {
+        let parts = usd(100).allocate(&[1, 1, 1]).unwrap();
+        assert_eq!(parts, vec![usd(34), usd(33), usd(33)]);
+
+        let parts = usd(5).allocate(&[3, 7]).unwrap();
+        assert_eq!(parts, vec![usd(2), usd(3)]);
+
+        let parts = usd(-100).split(3).unwrap();
+        assert_eq!(parts, vec![usd(-34), usd(-33), usd(-33)]);
+
+        let parts = usd(10).allocate(&[0, 1, 1]).unwrap();
+        assert_eq!(parts, vec![usd(0), usd(5), usd(5)]);
+
+        assert!(usd(10).allocate(&[0, 0]).is_err());
+        assert!(usd(10).split(0).is_err());
+    }
+
+    #[test]
+    fn test_formatting() {
+        let amount = usd(-123_456_789);
+        assert_eq!(amount.to_string(), "-1234567.89 USD");
+        assert_eq!(amount.format("en-US"), "-$1,234,567.89");
+
+        let euros = Money::from_minor(123_450, Currency::EUR);
+        assert_eq!(euros.format("de-DE"), "1.234,50\u{a0}€");
+        assert_eq!(euros.format("fr-FR"), "1\u{202f}234,50\u{a0}€");
+
+        let yen = Money::from_minor(5000, Currency::JPY);
+        assert_eq!(yen.format("ja-JP"), "¥5,000");
+        assert_eq!(usd(5).amount_string(), "0.05");
+    }
+}
@@ -0,0 +1,414 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{User, ValidationError};
+
+/// A field in a partial update: left alone, explicitly cleared, or set to a new value.
+///
+/// Serialized as an absent field, `null` and a plain value respectively, so
+/// structs using it need `#[serde(default, skip_serializing_if = "Patch::is_unset")]`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum Patch<T> {
+    #[default]
+    Unset,
+    Null,
+    Set(T),
+}
+
+impl<T> Patch<T> {
+    pub fn is_unset(&self) -> bool {
+        matches!(self, Patch::Unset)
+    }
+
+    /// Apply to a nullable field.
+    pub fn apply_to(self, current: Option<T>) -> Option<T> {
+        match self {
+            Patch::Unset => current,
+            Patch::Null => None,
+            Patch::Set(value) => Some(value),
+        }
+    }
+
+    /// Apply to the required field `field` (as spelled in the API), rejecting `null`.
+    pub fn apply_required(self, current: T, field: &str) -> Result<T, ValidationError> {
+        match self {
+            Patch::Unset => Ok(current),
+            Patch::Null => {
+                let mut chars = field.chars();
+                let label: String = chars
+                    .next()
+                    .map(|first| first.to_uppercase().chain(chars).collect())
+                    .unwrap_or_default();
+                Err(ValidationError::new(
+                    field,
+                    format!("{} cannot be null", label.replace('_', " ")),
+                ))
+            }
+            Patch::Set(value) => Ok(value),
+        }
+    }
+}
+
+impl<T: Serialize> Serialize for Patch<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Patch::Unset | Patch::Null => serializer.serialize_none(),
+            Patch::Set(value) => serializer.serialize_some(value),
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Patch<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(match Option::<T>::deserialize(deserializer)? {
+            Some(value) => Patch::Set(value),
+            None => Patch::Null,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ThemePreference {
+    Light,
+    Dark,
+    #[default]
+    System,
+}
+
+impl ThemePreference {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ThemePreference::Light => "light",
+            ThemePreference::Dark => "dark",
+            ThemePreference::System => "system",
+        }
+    }
+}
+
+impl FromStr for ThemePreference {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "light" => Ok(ThemePreference::Light),
+            "dark" => Ok(ThemePreference::Dark),
+            "system" => Ok(ThemePreference::System),
+            other => Err(format!("Invalid theme preference: {}", other)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NotificationSettings {
+    pub email: bool,
+    pub push: bool,
+    pub marketing: bool,
+}
+
+impl Default for NotificationSettings {
+    fn default() -> Self {
+        Self {
+            email: true,
+            push: false,
+            marketing: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserPreferences {
+    pub locale: String,
+    pub timezone: String,
+    #[serde(default)]
+    pub theme: ThemePreference,
+    #[serde(default)]
+    pub notifications: NotificationSettings,
+}
+
+impl Default for UserPreferences {
+    fn default() -> Self {
+        Self {
+            locale: "en-US".to_string(),
+            timezone: "UTC".to_string(),
+            theme: ThemePreference::default(),
+            notifications: NotificationSettings::default(),
+        }
+    }
+}
+
+impl UserPreferences {
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        if !is_valid_locale(&self.locale) {
+            return Err(ValidationError::new("locale", "Invalid locale"));
+        }
+
+        if !is_valid_timezone(&self.timezone) {
+            return Err(ValidationError::new("timezone", "Invalid timezone"));
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserProfile {
+    #[serde(flatten)]
+    pub user: User,
+    #[serde(default)]
+    pub avatar_url: Option<String>,
+    #[serde(default)]
+    pub preferences: UserPreferences,
+}
+
+/// A partial update of a [`UserProfile`]; every field defaults to [`Patch::Unset`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UserUpdate {
+    #[serde(default, skip_serializing_if = "Patch::is_unset")]
+    pub name: Patch<String>,
+    #[serde(default, skip_serializing_if = "Patch::is_unset")]
+    pub email: Patch<String>,
+    #[serde(default, skip_serializing_if = "Patch::is_unset")]
+    pub avatar_url: Patch<String>,
+    #[serde(default, skip_serializing_if = "Patch::is_unset")]
+    pub locale: Patch<String>,
+    #[serde(default, skip_serializing_if = "Patch::is_unset")]
+    pub timezone: Patch<String>,
+    #[serde(default, skip_serializing_if = "Patch::is_unset")]
+    pub theme: Patch<ThemePreference>,
+    #[serde(default, skip_serializing_if = "Patch::is_unset")]
+    pub notifications: Patch<NotificationSettings>,
+}
+
+impl UserUpdate {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl UserProfile {
+    /// Create a profile for an existing user with default preferences
+    pub fn new(user: User) -> Self {
+        Self {
+            user,
+            avatar_url: None,
+            preferences: UserPreferences::default(),
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        User::new(
+            self.user.id,
+            self.user.name.clone(),
+            self.user.email.clone(),
+        )?;
+
+        if let Some(url) = &self.avatar_url {
+            if !is_valid_avatar_url(url) {
+                return Err(ValidationError::new("avatar_url", "Invalid avatar URL"));
+            }
+        }
+
+        self.preferences.validate()
+    }
+
+    /// Merge an update into a copy of this profile and validate the result.
+    ///
+    /// Clearing a required field (`name`, `email`, `locale`, ...) is an error;
+    /// clearing a preference with a default resets it to that default.
+    pub fn merge(&self, update: UserUpdate) -> Result<Self, ValidationError> {
+        let defaults = UserPreferences::default();
+        let current = self.clone();
+
+        let merged = Self {
+            user: User {
+                id: current.user.id,
+                name: update.name.apply_required(current.user.name, "name")?,
+                email: update.email.apply_required(current.user.email, "email")?,
+            },
+            avatar_url: update.avatar_url.apply_to(current.avatar_url),
+            preferences: UserPreferences {
+                locale: update
+                    .locale
+                    .apply_required(current.preferences.locale, "locale")?,
+                timezone: update
+                    .timezone
+                    .apply_required(current.preferences.timezone, "timezone")?,
+                theme: update
+                    .theme
+                    .apply_to(Some(current.preferences.theme))
+                    .unwrap_or(defaults.theme),
+                notifications: update
+                    .notifications
+                    .apply_to(Some(current.preferences.notifications))
+                    .unwrap_or(defaults.notifications),
+            },
+        };
+
+        merged.validate()?;
+        Ok(merged)
+    }
+}
+
+fn is_valid_avatar_url(url: &str) -> bool {
+    let rest = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"));
+
+    matches!(rest, Some(host) if !host.is_empty() && !host.contains(char::is_whitespace))
+}
+
+/// Accepts BCP 47-like tags such as `en`, `en-US` or `zh-Hant-TW`.
+fn is_valid_locale(locale: &str) -> bool {
+    let mut parts = locale.split('-');
+    let language_ok = parts
+        .next()
+        .is_some_and(|p| (2..=3).contains(&p.len()) && p.chars().all(|c| c.is_ascii_alphabetic()));
+
+    language_ok
+        && parts.all(|p| (2..=8).contains(&p.len()) && p.chars().all(|c| c.is_ascii_alphanumeric()))
+}
+
+/// Accepts `UTC` or IANA-style names such as `Europe/Berlin`.
+fn is_valid_timezone(timezone: &str) -> bool {
+    if timezone == "UTC" {
+        return true;
+    }
+
+    timezone.contains('/')
+        && timezone.split('/').all(|p| {
+            !p.is_empty()
+                && p.chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '+'))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profile() -> UserProfile {
+        let user = User::new(1, "John Doe".to_string(), "john@example.com".to_string()).unwrap();
+        UserProfile::new(user)
+    }
+
+    #[test]
+    fn test_profile_defaults() {
+        let profile = profile();
+        assert_eq!(profile.avatar_url, None);
+        assert_eq!(profile.preferences.locale, "en-US");
+        assert_eq!(profile.preferences.timezone, "UTC");
+        assert_eq!(profile.preferences.theme, ThemePreference::System);
+        assert!(profile.preferences.notifications.email);
+        assert!(profile.validate().is_ok());
+    }
+
+    #[test]
+    fn test_theme_preference_strings() {
+        for theme in [
+            ThemePreference::Light,
+            ThemePreference::Dark,
+            ThemePreference::System,
+        ] {
+            assert_eq!(theme.as_str().parse::<ThemePreference>(), Ok(theme));
+        }
+        assert!("auto".parse::<ThemePreference>().is_err());
+    }
+
+    #[test]
+    fn test_merge_empty_update_is_noop() {
+        let profile = profile();
+        assert!(UserUpdate::default().is_empty());
+        assert_eq!(profile.merge(UserUpdate::default()).unwrap(), profile);
+    }
+
+    #[test]
+    fn test_merge_sets_fields() {
+        let update = UserUpdate {
+            name: Patch::Set("Jane Doe".to_string()),
+            avatar_url: Patch::Set("https://cdn.example.com/jane.png".to_string()),
+            timezone: Patch::Set("Europe/Berlin".to_string()),
+            theme: Patch::Set(ThemePreference::Dark),
+            ..Default::default()
+        };
+
+        let merged = profile().merge(update).unwrap();
+        assert_eq!(merged.user.name, "Jane Doe");
+        assert_eq!(merged.user.email, "john@example.com");
+        assert_eq!(
+            merged.avatar_url.as_deref(),
+            Some("https://cdn.example.com/jane.png")
+        );
+        assert_eq!(merged.preferences.timezone, "Europe/Berlin");
+        assert_eq!(merged.preferences.theme, ThemePreference::Dark);
+    }
+
+    #[test]
+    fn test_merge_null_clears_or_resets() {
+        let mut profile = profile();
+        profile.avatar_url = Some("https://cdn.example.com/john.png".to_string());
+        profile.preferences.theme = ThemePreference::Light;
+
+        let update = UserUpdate {
+            avatar_url: Patch::Null,
+            theme: Patch::Null,
+            ..Default::default()
+        };
+
+        let merged = profile.merge(update).unwrap();
+        assert_eq!(merged.avatar_url, None);
+        assert_eq!(merged.preferences.theme, ThemePreference::System);
+    }
+
+    #[test]
+    fn test_merge_null_required_field() {
+        let update = UserUpdate {
+            email: Patch::Null,
+            ..Default::default()
+        };
+        assert_eq!(
+            profile().merge(update).unwrap_err(),
+            ValidationError::new("email", "Email cannot be null")
+        );
+    }
+
+    #[test]
+    fn test_merge_validates_result() {
+        let update = UserUpdate {
+            name: Patch::Set("   ".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            profile().merge(update).unwrap_err(),
+            ValidationError::new("name", "Name cannot be empty")
+        );
+
+        let update = UserUpdate {
+            avatar_url: Patch::Set("ftp://example.com/a.png".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            profile().merge(update).unwrap_err(),
+            ValidationError::new("avatar_url", "Invalid avatar URL")
+        );
+
+        let update = UserUpdate {
+            locale: Patch::Set("english".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            profile().merge(update).unwrap_err(),
+            ValidationError::new("locale", "Invalid locale")
+        );
+
+        let update = UserUpdate {
+            timezone: Patch::Set("Mars Time".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            profile().merge(update).unwrap_err(),
+            ValidationError::new("timezone", "Invalid timezone")
+        );
+    }
+}
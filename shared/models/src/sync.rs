@@ -0,0 +1,629 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::User;
+
+pub type NodeId = u64;
+
+/// Hybrid logical clock timestamp. The derived ordering (wall time, counter, node)
+/// is total, so every replica picks the same winner for concurrent writes.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+pub struct Timestamp {
+    pub wall_ms: u64,
+    pub counter: u32,
+    pub node: NodeId,
+}
+
+/// Clock that issues monotonically increasing [`Timestamp`]s. Wall time is passed in by the
+/// caller so the clock works on every target and can be driven by tests.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HybridClock {
+    last: Timestamp,
+}
+
+impl HybridClock {
+    pub fn new(node: NodeId) -> Self {
+        Self {
+            last: Timestamp {
+                node,
+                ..Default::default()
+            },
+        }
+    }
+
+    pub fn node(&self) -> NodeId {
+        self.last.node
+    }
+
+    /// Issue a timestamp for a local event.
+    pub fn tick(&mut self, now_ms: u64) -> Timestamp {
+        if now_ms > self.last.wall_ms {
+            self.last.wall_ms = now_ms;
+            self.last.counter = 0;
+        } else {
+            self.step(self.last.wall_ms, self.last.counter);
+        }
+        self.last
+    }
+
+    /// Advance past a timestamp received from another node.
+    pub fn observe(&mut self, remote: Timestamp, now_ms: u64) {
+        let wall_ms = now_ms.max(self.last.wall_ms).max(remote.wall_ms);
+        match (wall_ms == self.last.wall_ms, wall_ms == remote.wall_ms) {
+            (true, true) => self.step(wall_ms, self.last.counter.max(remote.counter)),
+            (true, false) => self.step(wall_ms, self.last.counter),
+            (false, true) => self.step(wall_ms, remote.counter),
+            (false, false) => {
+                self.last.wall_ms = wall_ms;
+                self.last.counter = 0;
+            }
+        }
+    }
+
+    /// Move to the timestamp after `counter` at `wall_ms`. When the counter is exhausted
+    /// the clock moves on to the next millisecond instead of wrapping, and once that is
+    /// exhausted too it stays pinned at the maximum rather than going back.
+    fn step(&mut self, wall_ms: u64, counter: u32) {
+        let (wall_ms, counter) = match (counter.checked_add(1), wall_ms.checked_add(1)) {
+            (Some(counter), _) => (wall_ms, counter),
+            (None, Some(next_ms)) => (next_ms, 0),
+            (None, None) => (u64::MAX, u32::MAX),
+        };
+        self.last.wall_ms = wall_ms;
+        self.last.counter = counter;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UserField {
+    Name,
+    Email,
+    Deleted,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "field", content = "value", rename_all = "lowercase")]
+pub enum FieldValue {
+    Name(String),
+    Email(String),
+    Deleted(bool),
+}
+
+impl FieldValue {
+    pub fn field(&self) -> UserField {
+        match self {
+            FieldValue::Name(_) => UserField::Name,
+            FieldValue::Email(_) => UserField::Email,
+            FieldValue::Deleted(_) => UserField::Deleted,
+        }
+    }
+}
+
+/// A single field write on a single record.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Change {
+    pub id: u64,
+    pub value: FieldValue,
+    pub stamp: Timestamp,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ChangeSet {
+    pub node: NodeId,
+    pub changes: Vec<Change>,
+}
+
+impl ChangeSet {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+/// A remote write that raced with an unsynced local write to the same field.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Conflict {
+    pub id: u64,
+    pub field: UserField,
+    pub local: Change,
+    pub remote: Change,
+    pub remote_won: bool,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MergeReport {
+    pub applied: usize,
+    pub ignored: usize,
+    pub conflicts: Vec<Conflict>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Register<T> {
+    value: T,
+    stamp: Timestamp,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Record {
+    name: Register<String>,
+    email: Register<String>,
+    deleted: Register<bool>,
+}
+
+impl Record {
+    fn empty() -> Self {
+        Self {
+            name: Register {
+                value: String::new(),
+                stamp: Timestamp::default(),
+            },
+            email: Register {
+                value: String::new(),
+                stamp: Timestamp::default(),
+            },
+            deleted: Register {
+                value: false,
+                stamp: Timestamp::default(),
+            },
+        }
+    }
+
+    /// Whether every field a `User` needs has been written. Records created by a
+    /// partial remote change set stay hidden until the rest arrives.
+    fn is_complete(&self) -> bool {
+        self.name.stamp != Timestamp::default() && self.email.stamp != Timestamp::default()
+    }
+
+    fn stamp(&self, field: UserField) -> Timestamp {
+        match field {
+            UserField::Name => self.name.stamp,
+            UserField::Email => self.email.stamp,
+            UserField::Deleted => self.deleted.stamp,
+        }
+    }
+
+    /// Last-writer-wins write; returns whether the value was taken.
+    fn apply(&mut self, value: FieldValue, stamp: Timestamp) -> bool {
+        if stamp <= self.stamp(value.field()) {
+            return false;
+        }
+        match value {
+            FieldValue::Name(name) => self.name = Register { value: name, stamp },
+            FieldValue::Email(email) => {
+                self.email = Register {
+                    value: email,
+                    stamp,
+                }
+            }
+            FieldValue::Deleted(deleted) => {
+                self.deleted = Register {
+                    value: deleted,
+                    stamp,
+                }
+            }
+        }
+        true
+    }
+}
+
+/// Offline-first replica of a `User` collection.
+///
+/// Local edits are recorded as per-field changes and queued until [`take_changes`]
+/// hands them to the transport. Remote change sets are merged field by field with
+/// last-writer-wins on [`Timestamp`], so replicas converge regardless of delivery order.
+///
+/// [`take_changes`]: SyncEngine::take_changes
+#[derive(Debug, Clone)]
+pub struct SyncEngine {
+    clock: HybridClock,
+    records: BTreeMap<u64, Record>,
+    pending: Vec<Change>,
+}
+
+impl SyncEngine {
+    pub fn new(node: NodeId) -> Self {
+        Self {
+            clock: HybridClock::new(node),
+            records: BTreeMap::new(),
+            pending: Vec::new(),
+        }
+    }
+
+    pub fn node(&self) -> NodeId {
+        self.clock.node()
+    }
+
+    /// Insert or update a user, recording a change for every field that differs.
+    pub fn upsert(&mut self, user: User, now_ms: u64) -> Result<(), String> {
+        let user = User::new(user.id, user.name, user.email)?;
+        let existing = self.records.get(&user.id);
+
+        let mut values = Vec::new();
+        if existing.map_or(true, |r| r.deleted.value) {
+            values.push(FieldValue::Deleted(false));
+        }
+        if existing.map_or(true, |r| r.name.value != user.name) {
+            values.push(FieldValue::Name(user.name));
+        }
+        if existing.map_or(true, |r| r.email.value != user.email) {
+            values.push(FieldValue::Email(user.email));
+        }
+
+        for value in values {
+            self.record_local(user.id, value, now_ms);
+        }
+        Ok(())
+    }
+
+    pub fn delete(&mut self, id: u64, now_ms: u64) -> Result<(), String> {
+        match self.records.get(&id) {
+            Some(record) if record.is_complete() && !record.deleted.value => {
+                self.record_local(id, FieldValue::Deleted(true), now_ms);
+                Ok(())
+            }
+            _ => Err(format!("User {} not found", id)),
+        }
+    }
+
+    pub fn get(&self, id: u64) -> Option<User> {
+        self.records
+            .get(&id)
+            .filter(|r| r.is_complete() && !r.deleted.value)
+            .map(|r| User {
+                id,
+                name: r.name.value.clone(),
+                email: r.email.value.clone(),
+            })
+    }
+
+    /// Live users ordered by id.
+    pub fn users(&self) -> Vec<User> {
+        self.records.keys().filter_map(|&id| self.get(id)).collect()
+    }
+
+    pub fn has_pending_changes(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    /// Local changes that have not been sent yet.
+    pub fn pending_changes(&self) -> ChangeSet {
+        ChangeSet {
+            node: self.node(),
+            changes: self.pending.clone(),
+        }
+    }
+
+    /// Drain local changes that have not been sent yet.
+    pub fn take_changes(&mut self) -> ChangeSet {
+        ChangeSet {
+            node: self.node(),
+            changes: std::mem::take(&mut self.pending),
+        }
+    }
+
+    /// Snapshot of the whole collection, for bootstrapping a new peer.
+    pub fn snapshot(&self) -> ChangeSet {
+        let changes = self
+            .records
+            .iter()
+            .flat_map(|(&id, r)| {
+                [
+                    (FieldValue::Deleted(r.deleted.value), r.deleted.stamp),
+                    (FieldValue::Name(r.name.value.clone()), r.name.stamp),
+                    (FieldValue::Email(r.email.value.clone()), r.email.stamp),
+                ]
+                .into_iter()
+                .filter(|(_, stamp)| *stamp != Timestamp::default())
+                .map(move |(value, stamp)| Change { id, value, stamp })
+            })
+            .collect();
+
+        ChangeSet {
+            node: self.node(),
+            changes,
+        }
+    }
+
+    /// Merge a change set from another replica.
+    ///
+    /// Re-delivered or stale changes are ignored. When a remote change touches a field
+    /// that still has an unsent local change with a different value, a [`Conflict`] is
+    /// reported; the winner is decided by timestamp and, if the remote wins, the local
+    /// change is dropped from the outgoing queue.
+    pub fn merge(&mut self, remote: &ChangeSet, now_ms: u64) -> MergeReport {
+        let mut report = MergeReport::default();
+
+        for change in &remote.changes {
+            self.clock.observe(change.stamp, now_ms);
+            let field = change.value.field();
+
+            if let Some(pos) = self
+                .pending
+                .iter()
+                .position(|c| c.id == change.id && c.value.field() == field)
+            {
+                let local = self.pending[pos].clone();
+                let remote_won = change.stamp > local.stamp;
+                if local.value != change.value {
+                    report.conflicts.push(Conflict {
+                        id: change.id,
+                        field,
+                        local,
+                        remote: change.clone(),
+                        remote_won,
+                    });
+                }
+                if remote_won {
+                    self.pending.remove(pos);
+                }
+            }
+
+            let record = self.records.entry(change.id).or_insert_with(Record::empty);
+            if record.apply(change.value.clone(), change.stamp) {
+                report.applied += 1;
+            } else {
+                report.ignored += 1;
+            }
+        }
+
+        report
+    }
+
+    fn record_local(&mut self, id: u64, value: FieldValue, now_ms: u64) {
+        let stamp = self.clock.tick(now_ms);
+        self.records
+            .entry(id)
+            .or_insert_with(Record::empty)
+            .apply(value.clone(), stamp);
+
+        self.pending
+            .retain(|c| !(c.id == id && c.value.field() == value.field()));
+        self.pending.push(Change { id, value, stamp });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user(id: u64, name: &str, email: &str) -> User {
+        User::new(id, name.to_string(), email.to_string()).unwrap()
+    }
+
+    /// Deliver every peer's pending changes to every other peer, merging incoming
+    /// changes before each peer's own queue is drained, as a pull-then-push client would.
+    fn sync_all(peers: &mut [SyncEngine], now_ms: u64) -> Vec<Conflict> {
+        let sets: Vec<ChangeSet> = peers.iter().map(|p| p.pending_changes()).collect();
+        let mut conflicts = Vec::new();
+        for peer in peers.iter_mut() {
+            let node = peer.node();
+            for set in sets.iter().filter(|s| s.node != node) {
+                conflicts.extend(peer.merge(set, now_ms).conflicts);
+            }
+            peer.take_changes();
+        }
+        conflicts
+    }
+
+    #[test]
+    fn test_clock_is_monotonic() {
+        let mut clock = HybridClock::new(1);
+        let a = clock.tick(100);
+        let b = clock.tick(100);
+        let c = clock.tick(50);
+        assert!(a < b && b < c);
+
+        clock.observe(
+            Timestamp {
+                wall_ms: 500,
+                counter: 3,
+                node: 2,
+            },
+            200,
+        );
+        let d = clock.tick(200);
+        assert_eq!((d.wall_ms, d.counter), (500, 5));
+
+        // An exhausted counter moves on to the next millisecond instead of wrapping.
+        clock.observe(
+            Timestamp {
+                wall_ms: 500,
+                counter: u32::MAX,
+                node: 2,
+            },
+            200,
+        );
+        let e = clock.tick(200);
+        assert!(d < e);
+        assert_eq!((e.wall_ms, e.counter), (501, 1));
+
+        // With both exhausted the clock stays at the maximum instead of going back.
+        clock.observe(
+            Timestamp {
+                wall_ms: u64::MAX,
+                counter: u32::MAX,
+                node: 2,
+            },
+            200,
+        );
+        let f = clock.tick(200);
+        assert!(e < f);
+        assert_eq!((f.wall_ms, f.counter), (u64::MAX, u32::MAX));
+        assert_eq!(clock.tick(u64::MAX), f);
+    }
+
+    #[test]
+    fn test_partial_records_stay_hidden() {
+        let mut source = SyncEngine::new(1);
+        source
+            .upsert(user(1, "Alice", "alice@example.com"), 10)
+            .unwrap();
+        let set = source.take_changes();
+        let (name, rest): (Vec<Change>, Vec<Change>) = set
+            .changes
+            .into_iter()
+            .partition(|c| c.value.field() == UserField::Name);
+
+        let mut replica = SyncEngine::new(2);
+        replica.merge(
+            &ChangeSet {
+                node: 1,
+                changes: name,
+            },
+            20,
+        );
+        assert_eq!(replica.get(1), None);
+        assert!(replica.users().is_empty());
+        assert!(replica.delete(1, 30).is_err());
+
+        replica.merge(
+            &ChangeSet {
+                node: 1,
+                changes: rest,
+            },
+            40,
+        );
+        assert_eq!(replica.get(1), Some(user(1, "Alice", "alice@example.com")));
+    }
+
+    #[test]
+    fn test_local_changes_are_queued() {
+        let mut engine = SyncEngine::new(1);
+        engine
+            .upsert(user(1, "Alice", "alice@example.com"), 10)
+            .unwrap();
+        engine
+            .upsert(user(1, "Alice B", "alice@example.com"), 20)
+            .unwrap();
+
+        let set = engine.take_changes();
+        assert_eq!(set.changes.len(), 3);
+        assert!(
+            set.changes
+                .iter()
+                .any(|c| c.value == FieldValue::Name("Alice B".into()))
+        );
+        assert!(!engine.has_pending_changes());
+
+        assert!(engine.upsert(user(2, "Bob", "bob@example.com"), 30).is_ok());
+        assert!(
+            engine
+                .upsert(
+                    User {
+                        id: 3,
+                        name: " ".into(),
+                        email: "x@y".into()
+                    },
+                    30
+                )
+                .is_err()
+        );
+        assert!(engine.delete(99, 40).is_err());
+    }
+
+    #[test]
+    fn test_peers_converge_with_per_field_lww() {
+        let mut peers = vec![SyncEngine::new(1), SyncEngine::new(2)];
+        peers[0]
+            .upsert(user(1, "Alice", "alice@example.com"), 10)
+            .unwrap();
+        sync_all(&mut peers, 20);
+        assert_eq!(peers[1].get(1), Some(user(1, "Alice", "alice@example.com")));
+
+        // Offline edits to different fields both survive.
+        peers[0]
+            .upsert(user(1, "Alicia", "alice@example.com"), 30)
+            .unwrap();
+        peers[1]
+            .upsert(user(1, "Alice", "alicia@example.com"), 31)
+            .unwrap();
+        let conflicts = sync_all(&mut peers, 40);
+
+        assert!(conflicts.is_empty());
+        let expected = user(1, "Alicia", "alicia@example.com");
+        assert_eq!(peers[0].get(1), Some(expected.clone()));
+        assert_eq!(peers[1].get(1), Some(expected));
+    }
+
+    #[test]
+    fn test_concurrent_writes_report_conflicts() {
+        let mut peers = vec![SyncEngine::new(1), SyncEngine::new(2)];
+        peers[0]
+            .upsert(user(1, "Alice", "alice@example.com"), 10)
+            .unwrap();
+        sync_all(&mut peers, 20);
+
+        peers[0]
+            .upsert(user(1, "Alice A", "alice@example.com"), 50)
+            .unwrap();
+        peers[1]
+            .upsert(user(1, "Alice B", "alice@example.com"), 60)
+            .unwrap();
+        let conflicts = sync_all(&mut peers, 70);
+
+        assert_eq!(conflicts.len(), 2);
+        assert!(conflicts.iter().all(|c| c.field == UserField::Name));
+        let on_first = conflicts.iter().find(|c| c.local.stamp.node == 1).unwrap();
+        assert!(on_first.remote_won);
+        let on_second = conflicts.iter().find(|c| c.local.stamp.node == 2).unwrap();
+        assert!(!on_second.remote_won);
+
+        assert_eq!(peers[0].users(), peers[1].users());
+        assert_eq!(peers[0].get(1).unwrap().name, "Alice B");
+    }
+
+    #[test]
+    fn test_delete_and_resurrect() {
+        let mut peers = vec![SyncEngine::new(1), SyncEngine::new(2)];
+        peers[0]
+            .upsert(user(1, "Alice", "alice@example.com"), 10)
+            .unwrap();
+        sync_all(&mut peers, 20);
+
+        peers[1].delete(1, 30).unwrap();
+        sync_all(&mut peers, 40);
+        assert!(peers[0].users().is_empty());
+
+        peers[0]
+            .upsert(user(1, "Alice", "alice@example.com"), 50)
+            .unwrap();
+        sync_all(&mut peers, 60);
+        assert_eq!(
+            peers[1].users(),
+            vec![user(1, "Alice", "alice@example.com")]
+        );
+    }
+
+    #[test]
+    fn test_merge_is_idempotent_and_order_independent() {
+        let mut a = SyncEngine::new(1);
+        let mut b = SyncEngine::new(2);
+        let mut c = SyncEngine::new(3);
+        a.upsert(user(1, "A", "a@example.com"), 10).unwrap();
+        b.upsert(user(1, "B", "b@example.com"), 10).unwrap();
+        c.upsert(user(2, "C", "c@example.com"), 5).unwrap();
+        c.upsert(user(1, "C", "a@example.com"), 9).unwrap();
+
+        let sets = [a.take_changes(), b.take_changes(), c.take_changes()];
+        let mut forward = SyncEngine::new(4);
+        let mut backward = SyncEngine::new(5);
+        for set in &sets {
+            forward.merge(set, 100);
+        }
+        for set in sets.iter().rev() {
+            backward.merge(set, 100);
+            backward.merge(set, 100);
+        }
+
+        assert_eq!(forward.users(), backward.users());
+        assert_eq!(forward.get(1).unwrap().name, "B");
+
+        let mut fresh = SyncEngine::new(6);
+        let report = fresh.merge(&forward.snapshot(), 200);
+        assert_eq!(report.ignored, 0);
+        assert_eq!(fresh.users(), forward.users());
+    }
+}
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::ValidationError;
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct User {
     pub id: u64,
@@ -9,13 +11,13 @@ pub struct User {
 
 impl User {
     /// Create a new user with validation
-    pub fn new(id: u64, name: String, email: String) -> Result<Self, String> {
+    pub fn new(id: u64, name: String, email: String) -> Result<Self, ValidationError> {
         if name.trim().is_empty() {
-            return Err("Name cannot be empty".to_string());
+            return Err(ValidationError::new("name", "Name cannot be empty"));
         }
 
         if !email.contains('@') {
-            return Err("Invalid email format".to_string());
+            return Err(ValidationError::new("email", "Invalid email format"));
         }
 
         Ok(Self { id, name, email })
@@ -41,13 +43,19 @@ mod tests {
     fn test_user_creation_empty_name() {
         let result = User::new(1, "".to_string(), "john@example.com".to_string());
         assert!(result.is_err());
-        assert_eq!(result.unwrap_err(), "Name cannot be empty");
+        assert_eq!(
+            result.unwrap_err(),
+            ValidationError::new("name", "Name cannot be empty")
+        );
     }
 
     #[test]
     fn test_user_creation_invalid_email() {
         let result = User::new(1, "John Doe".to_string(), "invalid-email".to_string());
         assert!(result.is_err());
-        assert_eq!(result.unwrap_err(), "Invalid email format");
+        assert_eq!(
+            result.unwrap_err(),
+            ValidationError::new("email", "Invalid email format")
+        );
     }
 }
@@ -30,3 +30,68 @@ fn test_user_creation() {
     assert_eq!(user.name, "Alice");
     assert_eq!(user.email, "alice@test.com");
 }
+
+#[test]
+fn test_user_update_distinguishes_unset_and_null() {
+    let update: UserUpdate =
+        serde_json::from_str(r#"{"name":"Jane","avatar_url":null}"#).expect("valid update");
+
+    assert_eq!(update.name, Patch::Set("Jane".to_string()));
+    assert_eq!(update.avatar_url, Patch::Null);
+    assert_eq!(update.email, Patch::Unset);
+
+    let json = serde_json::to_string(&update).expect("Failed to serialize update");
+    assert_eq!(json, r#"{"name":"Jane","avatar_url":null}"#);
+}
+
+#[test]
+fn test_user_profile_serialization() {
+    let user = User::new(1, "John Doe".to_string(), "john@example.com".to_string()).unwrap();
+    let profile = UserProfile::new(user);
+
+    let json = serde_json::to_string(&profile).expect("Failed to serialize profile");
+    assert!(json.contains(r#""name":"John Doe""#));
+    assert!(json.contains(r#""theme":"system""#));
+
+    let deserialized: UserProfile =
+        serde_json::from_str(&json).expect("Failed to deserialize profile");
+    assert_eq!(profile, deserialized);
+}
+
+#[test]
+fn test_money_serializes_amount_as_string() {
+    let money = Money::parse("1234.50", Currency::EUR).expect("valid amount");
+
+    let json = serde_json::to_string(&money).expect("Failed to serialize money");
+    assert_eq!(json, r#"{"amount":"1234.50","currency":"EUR"}"#);
+
+    let deserialized: Money = serde_json::from_str(&json).expect("Failed to deserialize money");
+    assert_eq!(money, deserialized);
+
+    assert!(serde_json::from_str::<Money>(r#"{"amount":1234.5,"currency":"EUR"}"#).is_err());
+    assert!(serde_json::from_str::<Money>(r#"{"amount":"1.001","currency":"EUR"}"#).is_err());
+}
+
+#[test]
+fn test_api_envelopes_serialization() {
+    use shared_models::api::*;
+
+    let user = User::new(1, "John Doe".to_string(), "john@example.com".to_string()).unwrap();
+    let list = ListResponse::paginate(vec![UserResponse::from(user)], PageRequest::default());
+    let json = serde_json::to_string(&list).expect("Failed to serialize list");
+    assert_eq!(
+        json,
+        r#"{"items":[{"id":1,"name":"John Doe","email":"john@example.com"}],"pagination":{"page":1,"per_page":20,"total":1,"total_pages":1}}"#
+    );
+
+    let error = ApiError::from_validation(ValidationError::new("email", "Invalid email format"))
+        .with_trace_id("abc123");
+    let json = serde_json::to_string(&error).expect("Failed to serialize error");
+    assert_eq!(
+        json,
+        r#"{"code":"validation_failed","message":"Validation failed","field_errors":[{"field":"email","message":"Invalid email format"}],"trace_id":"abc123"}"#
+    );
+
+    let page: PageRequest = serde_json::from_str("{}").expect("Failed to deserialize page");
+    assert_eq!(page, PageRequest::default());
+}
@@ -0,0 +1,31 @@
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use shared_ui::{Theme, merge_classes};
+
+fn button_merge_benchmark(c: &mut Criterion) {
+    let theme = Theme::DEFAULT.button;
+
+    c.bench_function("merge button classes", |b| {
+        b.iter(|| {
+            merge_classes(black_box(&[
+                theme.base,
+                theme.primary,
+                "px-4 py-2 text-base",
+                "",
+            ]))
+        })
+    });
+
+    c.bench_function("merge button classes with overrides", |b| {
+        b.iter(|| {
+            merge_classes(black_box(&[
+                theme.base,
+                theme.primary,
+                "px-4 py-2 text-base",
+                "bg-emerald-600 hover:bg-emerald-700 px-8 rounded-full",
+            ]))
+        })
+    });
+}
+
+criterion_group!(benches, button_merge_benchmark);
+criterion_main!(benches);
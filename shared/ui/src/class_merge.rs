@@ -0,0 +1,854 @@
+//! Tailwind-aware merging of class lists, so a `class` override such as `px-8`
+//! replaces the component's `px-4` instead of both ending up in the DOM.
+
+/// Merge class lists left to right. When two utilities set the same CSS property under
+/// the same variants (`dark:`, `hover:`, `md:`, ...), only the later one is kept.
+/// Unknown classes are kept as-is, minus exact duplicates.
+///
+/// ```
+/// use shared_ui::merge_classes;
+///
+/// assert_eq!(merge_classes(&["px-4 py-2 bg-blue-600", "px-8"]), "py-2 bg-blue-600 px-8");
+/// assert_eq!(merge_classes(&["p-4 dark:bg-gray-800", "dark:bg-black px-2"]), "p-4 dark:bg-black px-2");
+/// ```
+pub fn merge_classes(parts: &[&str]) -> String {
+    let classes: Vec<&str> = parts.iter().flat_map(|p| p.split_whitespace()).collect();
+
+    let mut claimed: Vec<Claim<'_>> = Vec::with_capacity(classes.len());
+    let mut keep = vec![false; classes.len()];
+
+    for (index, class) in classes.iter().enumerate().rev() {
+        let parsed = parse(class);
+        let taken = claimed.iter().any(|c| {
+            c.variants == parsed.variants
+                && c.important == parsed.important
+                && c.group == parsed.group
+        });
+        if taken {
+            continue;
+        }
+
+        keep[index] = true;
+        if let Group::Known(group) = parsed.group {
+            for &overridden in overrides(group) {
+                claimed.push(Claim {
+                    group: Group::Known(overridden),
+                    ..parsed.clone()
+                });
+            }
+        }
+        claimed.push(parsed);
+    }
+
+    let mut out = String::with_capacity(classes.iter().map(|c| c.len() + 1).sum());
+    for (class, _) in classes.iter().zip(&keep).filter(|(_, keep)| **keep) {
+        if !out.is_empty() {
+            out.push(' ');
+        }
+        out.push_str(class);
+    }
+    out
+}
+
+#[derive(Clone, PartialEq)]
+enum Group<'a> {
+    Known(&'static str),
+    Exact(&'a str),
+}
+
+#[derive(Clone)]
+struct Claim<'a> {
+    variants: Variants<'a>,
+    important: bool,
+    group: Group<'a>,
+}
+
+/// Variant prefix of a class, compared regardless of order (`dark:hover:` == `hover:dark:`).
+/// A single variant is kept as a slice to avoid allocating in the common case.
+#[derive(Clone)]
+enum Variants<'a> {
+    Raw(&'a str),
+    Sorted(Vec<&'a str>),
+}
+
+impl PartialEq for Variants<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        // `Raw` holds zero or one variant and `Sorted` two or more, so they never match.
+        match (self, other) {
+            (Variants::Raw(a), Variants::Raw(b)) => a == b,
+            (Variants::Sorted(a), Variants::Sorted(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+fn parse(class: &str) -> Claim<'_> {
+    // Split off variants at the last `:` that is not inside an arbitrary value.
+    let mut depth = 0i32;
+    let mut split = None;
+    for (i, c) in class.char_indices() {
+        match c {
+            '[' | '(' => depth += 1,
+            ']' | ')' => depth -= 1,
+            ':' if depth == 0 => split = Some(i),
+            _ => {}
+        }
+    }
+    let (variant_str, utility) = match split {
+        Some(i) => (&class[..i], &class[i + 1..]),
+        None => ("", class),
+    };
+
+    let variants = if variant_str.contains(':') {
+        let mut parts: Vec<&str> = variant_str.split(':').collect();
+        parts.sort_unstable();
+        Variants::Sorted(parts)
+    } else {
+        Variants::Raw(variant_str)
+    };
+
+    let (important, utility) = match utility.strip_prefix('!') {
+        Some(rest) => (true, rest),
+        None => match utility.strip_suffix('!') {
+            Some(rest) => (true, rest),
+            None => (false, utility),
+        },
+    };
+    let utility = utility.strip_prefix('-').unwrap_or(utility);
+
+    let group = match arbitrary_property(utility) {
+        Some(property) => arbitrary_property_group(property),
+        None => classify(utility).map_or(Group::Exact(class), Group::Known),
+    };
+    Claim {
+        variants,
+        important,
+        group,
+    }
+}
+
+/// Utilities that are a single keyword.
+const KEYWORDS: &[(&str, &[&str])] = &[
+    (
+        "display",
+        &[
+            "block",
+            "inline-block",
+            "inline",
+            "flex",
+            "inline-flex",
+            "grid",
+            "inline-grid",
+            "table",
+            "inline-table",
+            "table-row",
+            "table-cell",
+            "contents",
+            "flow-root",
+            "list-item",
+            "hidden",
+        ],
+    ),
+    (
+        "position",
+        &["static", "fixed", "absolute", "relative", "sticky"],
+    ),
+    ("visibility", &["visible", "invisible", "collapse"]),
+    ("sr", &["sr-only", "not-sr-only"]),
+    (
+        "flex-direction",
+        &[
+            "flex-row",
+            "flex-row-reverse",
+            "flex-col",
+            "flex-col-reverse",
+        ],
+    ),
+    (
+        "flex-wrap",
+        &["flex-wrap", "flex-wrap-reverse", "flex-nowrap"],
+    ),
+    (
+        "text-transform",
+        &["uppercase", "lowercase", "capitalize", "normal-case"],
+    ),
+    (
+        "text-decoration",
+        &["underline", "overline", "line-through", "no-underline"],
+    ),
+    ("font-style", &["italic", "not-italic"]),
+    ("text-overflow", &["truncate", "text-ellipsis", "text-clip"]),
+    (
+        "text-wrap",
+        &["text-wrap", "text-nowrap", "text-balance", "text-pretty"],
+    ),
+    ("border-collapse", &["border-collapse", "border-separate"]),
+    (
+        "text-align",
+        &[
+            "text-left",
+            "text-center",
+            "text-right",
+            "text-justify",
+            "text-start",
+            "text-end",
+        ],
+    ),
+    (
+        "border-style",
+        &[
+            "border-solid",
+            "border-dashed",
+            "border-dotted",
+            "border-double",
+            "border-hidden",
+            "border-none",
+        ],
+    ),
+    (
+        "outline-style",
+        &[
+            "outline-none",
+            "outline",
+            "outline-dashed",
+            "outline-dotted",
+            "outline-double",
+        ],
+    ),
+    ("isolation", &["isolate", "isolation-auto"]),
+    ("box-sizing", &["box-border", "box-content"]),
+    ("antialiasing", &["antialiased", "subpixel-antialiased"]),
+    (
+        "transition",
+        &[
+            "transition",
+            "transition-all",
+            "transition-colors",
+            "transition-opacity",
+            "transition-shadow",
+            "transition-transform",
+            "transition-none",
+        ],
+    ),
+    ("shadow", &["shadow", "shadow-inner", "shadow-none"]),
+    ("ring-width", &["ring"]),
+    ("ring-inset", &["ring-inset"]),
+    ("border-w", &["border"]),
+    ("rounded", &["rounded"]),
+    ("grow", &["grow"]),
+    ("shrink", &["shrink"]),
+    ("bg-attachment", &["bg-fixed", "bg-local", "bg-scroll"]),
+    ("bg-size", &["bg-auto", "bg-cover", "bg-contain"]),
+    (
+        "bg-repeat",
+        &[
+            "bg-repeat",
+            "bg-no-repeat",
+            "bg-repeat-x",
+            "bg-repeat-y",
+            "bg-repeat-round",
+            "bg-repeat-space",
+        ],
+    ),
+    (
+        "bg-position",
+        &[
+            "bg-bottom",
+            "bg-center",
+            "bg-left",
+            "bg-left-bottom",
+            "bg-left-top",
+            "bg-right",
+            "bg-right-bottom",
+            "bg-right-top",
+            "bg-top",
+        ],
+    ),
+    ("bg-image", &["bg-none"]),
+    ("font-family", &["font-sans", "font-serif", "font-mono"]),
+    (
+        "font-weight",
+        &[
+            "font-thin",
+            "font-extralight",
+            "font-light",
+            "font-normal",
+            "font-medium",
+            "font-semibold",
+            "font-bold",
+            "font-extrabold",
+            "font-black",
+        ],
+    ),
+];
+
+/// `prefix-<value>` utilities whose group does not depend on the value. Longest prefix wins.
+const PREFIXES: &[(&str, &str)] = &[
+    ("p", "p"),
+    ("px", "px"),
+    ("py", "py"),
+    ("ps", "ps"),
+    ("pe", "pe"),
+    ("pt", "pt"),
+    ("pr", "pr"),
+    ("pb", "pb"),
+    ("pl", "pl"),
+    ("m", "m"),
+    ("mx", "mx"),
+    ("my", "my"),
+    ("ms", "ms"),
+    ("me", "me"),
+    ("mt", "mt"),
+    ("mr", "mr"),
+    ("mb", "mb"),
+    ("ml", "ml"),
+    ("space-x", "space-x"),
+    ("space-y", "space-y"),
+    ("gap", "gap"),
+    ("gap-x", "gap-x"),
+    ("gap-y", "gap-y"),
+    ("w", "w"),
+    ("min-w", "min-w"),
+    ("max-w", "max-w"),
+    ("h", "h"),
+    ("min-h", "min-h"),
+    ("max-h", "max-h"),
+    ("size", "size"),
+    ("inset", "inset"),
+    ("inset-x", "inset-x"),
+    ("inset-y", "inset-y"),
+    ("top", "top"),
+    ("right", "right"),
+    ("bottom", "bottom"),
+    ("left", "left"),
+    ("start", "start"),
+    ("end", "end"),
+    ("z", "z"),
+    ("order", "order"),
+    ("opacity", "opacity"),
+    ("flex", "flex"),
+    ("grow", "grow"),
+    ("shrink", "shrink"),
+    ("basis", "basis"),
+    ("grid-cols", "grid-cols"),
+    ("grid-rows", "grid-rows"),
+    ("col", "col"),
+    ("col-span", "col"),
+    ("row", "row"),
+    ("row-span", "row"),
+    ("items", "items"),
+    ("justify", "justify"),
+    ("justify-items", "justify-items"),
+    ("justify-self", "justify-self"),
+    ("content", "content"),
+    ("self", "self"),
+    ("place-content", "place-content"),
+    ("place-items", "place-items"),
+    ("place-self", "place-self"),
+    ("overflow", "overflow"),
+    ("overflow-x", "overflow-x"),
+    ("overflow-y", "overflow-y"),
+    ("overscroll", "overscroll"),
+    ("cursor", "cursor"),
+    ("pointer-events", "pointer-events"),
+    ("select", "select"),
+    ("duration", "duration"),
+    ("ease", "ease"),
+    ("delay", "delay"),
+    ("animate", "animate"),
+    ("leading", "leading"),
+    ("tracking", "tracking"),
+    ("whitespace", "whitespace"),
+    ("break", "break"),
+    ("align", "align"),
+    ("list", "list"),
+    ("rounded", "rounded"),
+    ("rounded-t", "rounded-t"),
+    ("rounded-r", "rounded-r"),
+    ("rounded-b", "rounded-b"),
+    ("rounded-l", "rounded-l"),
+    ("rounded-s", "rounded-s"),
+    ("rounded-e", "rounded-e"),
+    ("rounded-tl", "rounded-tl"),
+    ("rounded-tr", "rounded-tr"),
+    ("rounded-br", "rounded-br"),
+    ("rounded-bl", "rounded-bl"),
+    ("scale", "scale"),
+    ("scale-x", "scale-x"),
+    ("scale-y", "scale-y"),
+    ("rotate", "rotate"),
+    ("translate-x", "translate-x"),
+    ("translate-y", "translate-y"),
+    ("skew-x", "skew-x"),
+    ("skew-y", "skew-y"),
+    ("origin", "origin"),
+    ("bg-opacity", "bg-opacity"),
+    ("text-opacity", "text-opacity"),
+    ("border-opacity", "border-opacity"),
+    ("ring-opacity", "ring-opacity"),
+    ("bg-gradient-to", "bg-image"),
+    ("from", "from"),
+    ("via", "via"),
+    ("to", "to"),
+    ("blur", "blur"),
+    ("backdrop-blur", "backdrop-blur"),
+    ("aspect", "aspect"),
+    ("columns", "columns"),
+    ("object", "object"),
+    ("fill", "fill"),
+    ("stroke-w", "stroke-w"),
+    ("line-clamp", "line-clamp"),
+    ("indent", "indent"),
+    ("will-change", "will-change"),
+    ("touch", "touch"),
+    ("scroll-m", "scroll-m"),
+    ("scroll-p", "scroll-p"),
+    ("snap", "snap"),
+    ("accent", "accent"),
+    ("caret", "caret"),
+    ("resize", "resize"),
+    ("appearance", "appearance"),
+];
+
+/// Groups that a later class also overrides (`p-4` after `px-2` wins for padding-x too).
+fn overrides(group: &str) -> &'static [&'static str] {
+    match group {
+        "p" => &["px", "py", "ps", "pe", "pt", "pr", "pb", "pl"],
+        "px" => &["pr", "pl", "ps", "pe"],
+        "py" => &["pt", "pb"],
+        "m" => &["mx", "my", "ms", "me", "mt", "mr", "mb", "ml"],
+        "mx" => &["mr", "ml", "ms", "me"],
+        "my" => &["mt", "mb"],
+        "gap" => &["gap-x", "gap-y"],
+        "size" => &["w", "h"],
+        "inset" => &[
+            "inset-x", "inset-y", "top", "right", "bottom", "left", "start", "end",
+        ],
+        "inset-x" => &["right", "left"],
+        "inset-y" => &["top", "bottom"],
+        "overflow" => &["overflow-x", "overflow-y"],
+        "scale" => &["scale-x", "scale-y"],
+        "rounded" => &[
+            "rounded-t",
+            "rounded-r",
+            "rounded-b",
+            "rounded-l",
+            "rounded-s",
+            "rounded-e",
+            "rounded-tl",
+            "rounded-tr",
+            "rounded-br",
+            "rounded-bl",
+        ],
+        "rounded-t" => &["rounded-tl", "rounded-tr"],
+        "rounded-r" => &["rounded-tr", "rounded-br"],
+        "rounded-b" => &["rounded-br", "rounded-bl"],
+        "rounded-l" => &["rounded-tl", "rounded-bl"],
+        "border-w" => &[
+            "border-w-x",
+            "border-w-y",
+            "border-w-t",
+            "border-w-r",
+            "border-w-b",
+            "border-w-l",
+        ],
+        "border-w-x" => &["border-w-r", "border-w-l"],
+        "border-w-y" => &["border-w-t", "border-w-b"],
+        "border-color" => &[
+            "border-color-x",
+            "border-color-y",
+            "border-color-t",
+            "border-color-r",
+            "border-color-b",
+            "border-color-l",
+        ],
+        "border-color-x" => &["border-color-r", "border-color-l"],
+        "border-spacing" => &["border-spacing-x", "border-spacing-y"],
+        "border-color-y" => &["border-color-t", "border-color-b"],
+        _ => &[],
+    }
+}
+
+/// The CSS property of an arbitrary property utility such as `[mask-type:luminance]`.
+fn arbitrary_property(utility: &str) -> Option<&str> {
+    utility
+        .strip_prefix('[')
+        .and_then(|u| u.strip_suffix(']'))
+        .and_then(|u| u.split_once(':'))
+        .map(|(property, _)| property)
+}
+
+fn classify(utility: &str) -> Option<&'static str> {
+    if let Some((group, _)) = KEYWORDS.iter().find(|(_, words)| words.contains(&utility)) {
+        return Some(group);
+    }
+
+    if let Some(group) = classify_by_value(utility) {
+        return Some(group);
+    }
+
+    let mut end = utility.len();
+    while let Some(dash) = utility[..end].rfind('-') {
+        let prefix = &utility[..dash];
+        if let Some((_, group)) = PREFIXES.iter().find(|(p, _)| *p == prefix) {
+            return Some(group);
+        }
+        end = dash;
+    }
+    None
+}
+
+/// Utilities sharing a prefix across several properties, told apart by their value.
+fn classify_by_value(utility: &str) -> Option<&'static str> {
+    for (prefix, group) in [
+        ("bg-opacity-", "bg-opacity"),
+        ("text-opacity-", "text-opacity"),
+        ("border-opacity-", "border-opacity"),
+        ("ring-opacity-", "ring-opacity"),
+        ("bg-gradient-to-", "bg-image"),
+        ("bg-clip-", "bg-clip"),
+        ("bg-origin-", "bg-origin"),
+        ("bg-blend-", "bg-blend"),
+        ("border-spacing-x-", "border-spacing-x"),
+        ("border-spacing-y-", "border-spacing-y"),
+        ("border-spacing-", "border-spacing"),
+    ] {
+        if utility.starts_with(prefix) {
+            return Some(group);
+        }
+    }
+
+    if let Some(value) = utility.strip_prefix("text-") {
+        // `text-sm/6` sets the line height along with the size.
+        let size = value.split_once('/').map_or(value, |(size, _)| size);
+        return Some(if is_font_size(size) {
+            "font-size"
+        } else {
+            "text-color"
+        });
+    }
+    if let Some(value) = utility.strip_prefix("bg-") {
+        return Some(if is_arbitrary(value) && !is_color(value) {
+            "bg-image"
+        } else {
+            "bg-color"
+        });
+    }
+    if let Some(value) = utility.strip_prefix("ring-offset-") {
+        return Some(if is_width(value) {
+            "ring-offset-w"
+        } else {
+            "ring-offset-color"
+        });
+    }
+    if let Some(value) = utility.strip_prefix("ring-") {
+        return Some(if is_width(value) {
+            "ring-width"
+        } else {
+            "ring-color"
+        });
+    }
+    if let Some(value) = utility.strip_prefix("outline-") {
+        return Some(if utility.starts_with("outline-offset-") {
+            "outline-offset"
+        } else if is_width(value) {
+            "outline-w"
+        } else {
+            "outline-color"
+        });
+    }
+    if let Some(value) = utility.strip_prefix("shadow-") {
+        return Some(
+            if matches!(value, "sm" | "md" | "lg" | "xl" | "2xl") || value.starts_with("[0") {
+                "shadow"
+            } else {
+                "shadow-color"
+            },
+        );
+    }
+    if let Some(value) = utility.strip_prefix("font-") {
+        return Some(
+            if is_arbitrary(value) && value[1..].starts_with(|c: char| c.is_ascii_digit()) {
+                "font-weight"
+            } else {
+                "font-family"
+            },
+        );
+    }
+    if let Some(rest) = utility.strip_prefix("border-") {
+        for (side, width_group, color_group) in [
+            ("x-", "border-w-x", "border-color-x"),
+            ("y-", "border-w-y", "border-color-y"),
+            ("t-", "border-w-t", "border-color-t"),
+            ("r-", "border-w-r", "border-color-r"),
+            ("b-", "border-w-b", "border-color-b"),
+            ("l-", "border-w-l", "border-color-l"),
+        ] {
+            if let Some(value) = rest.strip_prefix(side) {
+                return Some(if is_width(value) {
+                    width_group
+                } else {
+                    color_group
+                });
+            }
+        }
+        if let Some(side) = ["x", "y", "t", "r", "b", "l"].iter().find(|s| **s == rest) {
+            return Some(match *side {
+                "x" => "border-w-x",
+                "y" => "border-w-y",
+                "t" => "border-w-t",
+                "r" => "border-w-r",
+                "b" => "border-w-b",
+                _ => "border-w-l",
+            });
+        }
+        return Some(if is_width(rest) {
+            "border-w"
+        } else {
+            "border-color"
+        });
+    }
+    if let Some(value) = utility.strip_prefix("divide-") {
+        return Some(match value {
+            v if v.starts_with("x") => "divide-x",
+            v if v.starts_with("y") => "divide-y",
+            _ => "divide-color",
+        });
+    }
+    if let Some(value) = utility.strip_prefix("decoration-") {
+        return Some(if is_width(value) {
+            "decoration-w"
+        } else {
+            "decoration-color"
+        });
+    }
+    if let Some(value) = utility.strip_prefix("stroke-") {
+        return Some(if is_width(value) {
+            "stroke-w"
+        } else {
+            "stroke-color"
+        });
+    }
+    None
+}
+
+fn arbitrary_property_group(property: &str) -> Group<'_> {
+    match property {
+        "padding" => Group::Known("p"),
+        "margin" => Group::Known("m"),
+        "color" => Group::Known("text-color"),
+        "background-color" => Group::Known("bg-color"),
+        "width" => Group::Known("w"),
+        "height" => Group::Known("h"),
+        "display" => Group::Known("display"),
+        other => Group::Exact(other),
+    }
+}
+
+fn is_arbitrary(value: &str) -> bool {
+    value.starts_with('[') && value.ends_with(']')
+}
+
+fn is_number(value: &str) -> bool {
+    !value.is_empty() && value.chars().all(|c| c.is_ascii_digit() || c == '.')
+}
+
+fn is_length(value: &str) -> bool {
+    let value = value.trim_start_matches('[').trim_end_matches(']');
+    let value = value.strip_prefix("length:").unwrap_or(value);
+    ["px", "rem", "em", "%", "vh", "vw", "ch", "pt"]
+        .iter()
+        .any(|unit| value.strip_suffix(unit).is_some_and(is_number))
+        || value == "0"
+        || value.starts_with("calc(")
+}
+
+fn is_color(value: &str) -> bool {
+    let value = value.trim_start_matches('[').trim_end_matches(']');
+    value.starts_with('#')
+        || value.starts_with("rgb")
+        || value.starts_with("hsl")
+        || value.starts_with("color:")
+        || value.starts_with("var(")
+}
+
+fn is_width(value: &str) -> bool {
+    is_number(value) || (is_arbitrary(value) && is_length(value))
+}
+
+fn is_font_size(value: &str) -> bool {
+    matches!(
+        value,
+        "xs" | "sm"
+            | "base"
+            | "lg"
+            | "xl"
+            | "2xl"
+            | "3xl"
+            | "4xl"
+            | "5xl"
+            | "6xl"
+            | "7xl"
+            | "8xl"
+            | "9xl"
+    ) || (is_arbitrary(value) && is_length(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_later_class_wins() {
+        assert_eq!(merge_classes(&["px-4 py-2", "px-8"]), "py-2 px-8");
+        assert_eq!(
+            merge_classes(&["bg-blue-600 text-white", "bg-red-500"]),
+            "text-white bg-red-500"
+        );
+        assert_eq!(
+            merge_classes(&["rounded-lg", "rounded-none"]),
+            "rounded-none"
+        );
+        assert_eq!(merge_classes(&["block", "hidden"]), "hidden");
+        assert_eq!(merge_classes(&["font-medium", "font-bold"]), "font-bold");
+    }
+
+    #[test]
+    fn test_shorthands_override_longhands() {
+        assert_eq!(merge_classes(&["px-4 py-2 pt-1", "p-6"]), "p-6");
+        assert_eq!(merge_classes(&["p-6", "px-2"]), "p-6 px-2");
+        assert_eq!(merge_classes(&["rounded-t-lg", "rounded"]), "rounded");
+        assert_eq!(merge_classes(&["w-4 h-4", "size-8"]), "size-8");
+    }
+
+    #[test]
+    fn test_shared_prefixes_are_told_apart() {
+        assert_eq!(
+            merge_classes(&["text-sm text-gray-900", "text-lg"]),
+            "text-gray-900 text-lg"
+        );
+        assert_eq!(
+            merge_classes(&["text-sm text-gray-900", "text-white"]),
+            "text-sm text-white"
+        );
+        assert_eq!(
+            merge_classes(&["text-left", "text-center text-red-500"]),
+            "text-center text-red-500"
+        );
+        assert_eq!(
+            merge_classes(&["border border-gray-200", "border-2"]),
+            "border-gray-200 border-2"
+        );
+        assert_eq!(
+            merge_classes(&["border-2 border-blue-600", "border-red-500"]),
+            "border-2 border-red-500"
+        );
+        assert_eq!(
+            merge_classes(&["ring-2 ring-blue-500", "ring-red-500"]),
+            "ring-2 ring-red-500"
+        );
+        assert_eq!(
+            merge_classes(&["ring-2 ring-opacity-50", "ring-4"]),
+            "ring-opacity-50 ring-4"
+        );
+        assert_eq!(
+            merge_classes(&["shadow-lg shadow-black", "shadow-sm"]),
+            "shadow-black shadow-sm"
+        );
+        assert_eq!(
+            merge_classes(&["flex flex-col", "flex-row"]),
+            "flex flex-row"
+        );
+    }
+
+    #[test]
+    fn test_lookalike_utilities_keep_their_own_group() {
+        assert_eq!(merge_classes(&["ring-2 ring-inset"]), "ring-2 ring-inset");
+        assert_eq!(
+            merge_classes(&["ring-inset", "ring-4"]),
+            "ring-inset ring-4"
+        );
+        assert_eq!(
+            merge_classes(&["border border-gray-200 border-collapse"]),
+            "border border-gray-200 border-collapse"
+        );
+        assert_eq!(
+            merge_classes(&["border-collapse", "border-separate border-spacing-2"]),
+            "border-separate border-spacing-2"
+        );
+        assert_eq!(
+            merge_classes(&["border-spacing-x-2 border-red-500", "border-spacing-4"]),
+            "border-red-500 border-spacing-4"
+        );
+        assert_eq!(
+            merge_classes(&["text-white text-nowrap", "text-balance"]),
+            "text-white text-balance"
+        );
+        assert_eq!(
+            merge_classes(&["text-white text-base", "text-sm/6"]),
+            "text-white text-sm/6"
+        );
+        assert_eq!(
+            merge_classes(&["text-white", "text-red-500/50"]),
+            "text-red-500/50"
+        );
+        assert_eq!(
+            merge_classes(&["bg-white bg-clip-text bg-origin-border bg-blend-multiply"]),
+            "bg-white bg-clip-text bg-origin-border bg-blend-multiply"
+        );
+        assert_eq!(
+            merge_classes(&["bg-clip-text", "bg-clip-padding"]),
+            "bg-clip-padding"
+        );
+    }
+
+    #[test]
+    fn test_variants_are_scoped() {
+        assert_eq!(
+            merge_classes(&[
+                "bg-white dark:bg-gray-800 hover:bg-gray-100",
+                "dark:bg-black"
+            ]),
+            "bg-white hover:bg-gray-100 dark:bg-black"
+        );
+        assert_eq!(
+            merge_classes(&["dark:hover:bg-red-500", "hover:dark:bg-blue-500"]),
+            "hover:dark:bg-blue-500"
+        );
+        assert_eq!(merge_classes(&["px-4 md:px-6", "md:px-8"]), "px-4 md:px-8");
+        assert_eq!(merge_classes(&["px-4", "!px-8"]), "px-4 !px-8");
+    }
+
+    #[test]
+    fn test_arbitrary_values() {
+        assert_eq!(merge_classes(&["w-4", "w-[37px]"]), "w-[37px]");
+        assert_eq!(merge_classes(&["text-sm", "text-[13px]"]), "text-[13px]");
+        assert_eq!(
+            merge_classes(&["text-gray-900", "text-[#bada55]"]),
+            "text-[#bada55]"
+        );
+        assert_eq!(
+            merge_classes(&["bg-blue-600", "bg-[var(--brand-primary)]"]),
+            "bg-[var(--brand-primary)]"
+        );
+        assert_eq!(
+            merge_classes(&["[mask-type:luminance]", "[mask-type:alpha]"]),
+            "[mask-type:alpha]"
+        );
+        assert_eq!(
+            merge_classes(&["[mask-type:alpha]", "[clip-path:none]"]),
+            "[mask-type:alpha] [clip-path:none]"
+        );
+        assert_eq!(merge_classes(&["[padding:1rem]", "p-2"]), "p-2");
+    }
+
+    #[test]
+    fn test_unknown_and_duplicate_classes() {
+        assert_eq!(
+            merge_classes(&["user-card foo", "foo bar", ""]),
+            "user-card foo bar"
+        );
+        assert_eq!(merge_classes(&["  px-2   ", "-mt-2 mt-4"]), "px-2 mt-4");
+        assert_eq!(merge_classes(&[]), "");
+    }
+}
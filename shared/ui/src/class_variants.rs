@@ -0,0 +1,174 @@
+//! `class_variants!`: declare a component's class recipe (base classes, variant axes,
+//! default variants and compound variants) in one place.
+
+/// Declares a class recipe in the spirit of cva.
+///
+/// Generates one enum per variant axis (with `Default` set to the variant after `=`),
+/// a `Copy` struct holding one value per axis, and a `classes` method that merges the
+/// base, the selected variants, every matching compound variant and a caller `class`
+/// override with [`merge_classes`](crate::merge_classes).
+///
+/// Values are expressions, so a recipe can take arguments such as theme tokens. Compound
+/// conditions are patterns, so `Intent::Danger | Intent::Warning` matches either.
+///
+/// ```
+/// shared_ui::class_variants! {
+///     pub BadgeClasses(accent: &str) {
+///         base: "inline-flex rounded-full",
+///         variants: {
+///             tone: BadgeTone = Neutral {
+///                 Neutral => "bg-gray-100 text-gray-800",
+///                 Accent => accent,
+///             },
+///             size: BadgeSize = Medium {
+///                 Small => "px-2 text-xs",
+///                 Medium => "px-3 text-sm",
+///             },
+///         },
+///         compound: [
+///             { tone: BadgeTone::Accent, size: BadgeSize::Small } => "font-semibold",
+///         ],
+///     }
+/// }
+///
+/// let badge = BadgeClasses { tone: BadgeTone::Accent, size: BadgeSize::Small };
+/// assert_eq!(
+///     badge.classes("bg-blue-600 text-white", "px-4"),
+///     "inline-flex rounded-full bg-blue-600 text-white text-xs font-semibold px-4"
+/// );
+/// assert_eq!(BadgeClasses::default().size, BadgeSize::Medium);
+/// ```
+#[macro_export]
+macro_rules! class_variants {
+    (
+        $(#[$meta:meta])*
+        $vis:vis $name:ident $( ( $($arg:ident: $arg_ty:ty),* $(,)? ) )? {
+            base: $base:expr,
+            variants: {
+                $(
+                    $(#[$axis_meta:meta])*
+                    $axis:ident: $enum:ident = $default:ident {
+                        $( $(#[$variant_meta:meta])* $variant:ident => $classes:expr ),+ $(,)?
+                    }
+                ),* $(,)?
+            }
+            $(, compound: [
+                $( { $( $compound_axis:ident: $compound_value:pat ),+ $(,)? } => $compound_classes:expr ),* $(,)?
+            ])?
+            $(,)?
+        }
+    ) => {
+        $(
+            $(#[$axis_meta])*
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+            $vis enum $enum {
+                $( $(#[$variant_meta])* $variant, )+
+            }
+
+            impl Default for $enum {
+                fn default() -> Self {
+                    $enum::$default
+                }
+            }
+        )*
+
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+        $vis struct $name {
+            $( pub $axis: $enum, )*
+        }
+
+        impl $name {
+            /// Merged classes for this combination of variants, with `class` applied last.
+            #[allow(unused_variables)]
+            pub fn classes(&self, $($($arg: $arg_ty,)*)? class: &str) -> String {
+                $crate::merge_classes(&[
+                    $base,
+                    $( match self.$axis { $( $enum::$variant => $classes, )+ }, )*
+                    $($(
+                        if $( matches!(self.$compound_axis, $compound_value) )&&+ {
+                            $compound_classes
+                        } else {
+                            ""
+                        },
+                    )*)?
+                    class,
+                ])
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    crate::class_variants! {
+        Recipe {
+            base: "rounded",
+            variants: {
+                intent: Intent = Primary {
+                    Primary => "bg-blue-600",
+                    Danger => "bg-red-600",
+                },
+                size: Size = Medium {
+                    Small => "px-2 py-1",
+                    Medium => "px-4 py-2",
+                },
+            },
+            compound: [
+                { intent: Intent::Danger, size: Size::Small } => "font-bold",
+                { intent: Intent::Danger | Intent::Primary, size: Size::Medium } => "shadow",
+            ],
+        }
+    }
+
+    crate::class_variants! {
+        Themed(accent: &'static str, muted: &'static str) {
+            base: "border",
+            variants: {
+                tone: Tone = Muted {
+                    Accent => accent,
+                    Muted => muted,
+                },
+            },
+        }
+    }
+
+    #[test]
+    fn test_defaults() {
+        let recipe = Recipe::default();
+        assert_eq!(recipe.intent, Intent::Primary);
+        assert_eq!(recipe.size, Size::Medium);
+        assert_eq!(recipe.classes(""), "rounded bg-blue-600 px-4 py-2 shadow");
+    }
+
+    #[test]
+    fn test_compound_variants() {
+        let recipe = Recipe {
+            intent: Intent::Danger,
+            size: Size::Small,
+        };
+        assert_eq!(recipe.classes(""), "rounded bg-red-600 px-2 py-1 font-bold");
+    }
+
+    #[test]
+    fn test_class_override_wins() {
+        let recipe = Recipe::default();
+        assert_eq!(
+            recipe.classes("bg-emerald-600 px-8"),
+            "rounded py-2 shadow bg-emerald-600 px-8"
+        );
+    }
+
+    #[test]
+    fn test_arguments() {
+        let themed = Themed { tone: Tone::Accent };
+        assert_eq!(
+            themed.classes("text-blue-600", "text-gray-500", ""),
+            "border text-blue-600"
+        );
+        assert_eq!(
+            Themed::default().classes("text-blue-600", "text-gray-500", ""),
+            "border text-gray-500"
+        );
+    }
+}
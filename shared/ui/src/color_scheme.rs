@@ -0,0 +1,169 @@
+use dioxus::document;
+use dioxus::prelude::*;
+pub use shared_models::ThemePreference;
+
+pub const DEFAULT_COLOR_SCHEME_STORAGE_KEY: &str = "color-scheme";
+
+/// JavaScript that applies the stored color scheme, or `default` when nothing is stored,
+/// to `<html>`. It evaluates to the stored preference, or `null` when there is none.
+///
+/// `ThemeProvider` runs it before its children first render. For a flash-free
+/// first paint, also inline it in a `<script>` in the `<head>` of `index.html`.
+pub fn color_scheme_init_script(storage_key: &str, default: ThemePreference) -> String {
+    format!(
+        r#"(function () {{
+    try {{
+        var stored = localStorage.getItem({key:?});
+        var preference = stored || {default:?};
+        var dark = preference === "dark" ||
+            (preference === "system" && window.matchMedia("(prefers-color-scheme: dark)").matches);
+        document.documentElement.classList.toggle("dark", dark);
+        document.documentElement.style.colorScheme = dark ? "dark" : "light";
+        return stored;
+    }} catch (e) {{
+        return null;
+    }}
+}})()"#,
+        key = storage_key,
+        default = default.as_str()
+    )
+}
+
+/// The preference returned by [`color_scheme_init_script`]; `None` when nothing valid
+/// is stored, so the provider keeps its default.
+fn stored_preference(stored: Option<&str>) -> Option<ThemePreference> {
+    stored.and_then(|s| s.parse().ok())
+}
+
+/// Color scheme state shared through context by `ThemeProvider`.
+#[derive(Clone, Copy, PartialEq)]
+pub struct ColorScheme {
+    preference: Signal<ThemePreference>,
+    system_dark: Signal<Option<bool>>,
+    loaded: Signal<bool>,
+    storage_key: &'static str,
+}
+
+impl ColorScheme {
+    pub fn preference(&self) -> ThemePreference {
+        *self.preference.read()
+    }
+
+    /// Whether dark mode is in effect after resolving `System` against the OS setting.
+    pub fn is_dark(&self) -> bool {
+        match self.preference() {
+            ThemePreference::Light => false,
+            ThemePreference::Dark => true,
+            ThemePreference::System => self.system_dark.read().unwrap_or(false),
+        }
+    }
+
+    /// Change and persist the preference.
+    pub fn set(&mut self, preference: ThemePreference) {
+        self.preference.set(preference);
+        document::eval(&format!(
+            "try {{ localStorage.setItem({:?}, {:?}); }} catch (e) {{}}",
+            self.storage_key,
+            preference.as_str()
+        ));
+    }
+
+    /// Switch to the opposite of what is currently shown.
+    pub fn toggle(&mut self) {
+        let next = if self.is_dark() {
+            ThemePreference::Light
+        } else {
+            ThemePreference::Dark
+        };
+        self.set(next);
+    }
+}
+
+/// Create the color scheme state, load the stored preference, follow OS changes and
+/// keep the `dark` class on `<html>` in sync. Called by `ThemeProvider`.
+pub(crate) fn use_color_scheme_provider(
+    default: ThemePreference,
+    storage_key: &'static str,
+) -> ColorScheme {
+    let mut preference = use_signal(|| default);
+    let mut system_dark = use_signal(|| None);
+    let mut loaded = use_signal(|| false);
+
+    // Runs during the first render so `<html>` has the right class before children paint.
+    use_hook(move || {
+        let init = document::eval(&format!(
+            "return {};",
+            color_scheme_init_script(storage_key, default)
+        ));
+        spawn(async move {
+            let stored = init.await;
+            if let Some(stored) = stored.ok().and_then(|v| stored_preference(v.as_str())) {
+                preference.set(stored);
+            }
+            loaded.set(true);
+        });
+    });
+
+    use_future(move || async move {
+        let mut media = document::eval(
+            r#"
+            const query = window.matchMedia("(prefers-color-scheme: dark)");
+            dioxus.send(query.matches);
+            query.addEventListener("change", (event) => dioxus.send(event.matches));
+            await new Promise(() => {});
+            "#,
+        );
+        while let Ok(dark) = media.recv::<bool>().await {
+            system_dark.set(Some(dark));
+        }
+    });
+
+    let scheme = use_context_provider(|| ColorScheme {
+        preference,
+        system_dark,
+        loaded,
+        storage_key,
+    });
+
+    // The init script already applied the stored scheme; only take over once both the
+    // stored preference and the OS setting are known, so nothing flips back and forth.
+    use_effect(move || {
+        if !loaded() || system_dark().is_none() {
+            return;
+        }
+        let dark = scheme.is_dark();
+        document::eval(&format!(
+            r#"document.documentElement.classList.toggle("dark", {dark});
+            document.documentElement.style.colorScheme = "{}";"#,
+            if dark { "dark" } else { "light" }
+        ));
+    });
+
+    scheme
+}
+
+/// The color scheme of the nearest `ThemeProvider`.
+pub fn use_color_scheme() -> ColorScheme {
+    use_context::<ColorScheme>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nothing_stored_keeps_default() {
+        let script = color_scheme_init_script("scheme", ThemePreference::Dark);
+        assert!(script.contains(r#"localStorage.getItem("scheme")"#));
+        // The first paint falls back to the configured default...
+        assert!(script.contains(r#"stored || "dark""#));
+        // ...and the script reports that nothing was stored rather than the fallback.
+        assert!(script.contains("return stored;"));
+        assert_eq!(stored_preference(None), None);
+        assert_eq!(stored_preference(Some("bogus")), None);
+        assert_eq!(
+            stored_preference(Some("light")),
+            Some(ThemePreference::Light)
+        );
+    }
+}
@@ -0,0 +1,326 @@
+use dioxus::document;
+use dioxus::prelude::*;
+
+use super::tabs::{focus_script, id_part, roving_target};
+use crate::{CardClasses, CardVariant, merge_classes, use_theme, use_unique_id};
+
+/// The open sections after toggling `value`. With `multiple` any number can be open;
+/// otherwise opening one closes the others, and `collapsible` allows closing the last.
+fn toggle_open(open: &[String], value: &str, multiple: bool, collapsible: bool) -> Vec<String> {
+    let is_open = open.iter().any(|v| v == value);
+    match (is_open, multiple) {
+        (true, true) => open.iter().filter(|v| *v != value).cloned().collect(),
+        (true, false) if collapsible => Vec::new(),
+        (true, false) => open.to_vec(),
+        (false, true) => open.iter().cloned().chain([value.to_string()]).collect(),
+        (false, false) => vec![value.to_string()],
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct AccordionEntry {
+    value: String,
+    disabled: bool,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+struct AccordionContext {
+    id: CopyValue<String>,
+    open: Signal<Vec<String>>,
+    /// Sections in the order they were first rendered, for keyboard navigation
+    items: Signal<Vec<AccordionEntry>>,
+    controlled: bool,
+    multiple: bool,
+    collapsible: bool,
+    onchange: Option<EventHandler<Vec<String>>>,
+}
+
+impl AccordionContext {
+    fn toggle(&mut self, value: &str) {
+        let next = toggle_open(&self.open.peek(), value, self.multiple, self.collapsible);
+        if *self.open.peek() == next {
+            return;
+        }
+        if !self.controlled {
+            self.open.set(next.clone());
+        }
+        if let Some(handler) = &self.onchange {
+            handler.call(next);
+        }
+    }
+
+    fn trigger_id(&self, value: &str) -> String {
+        format!("{}-trigger-{}", self.id.read(), id_part(value))
+    }
+
+    fn panel_id(&self, value: &str) -> String {
+        format!("{}-panel-{}", self.id.read(), id_part(value))
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct AccordionProps {
+    /// Open sections, for a controlled component; update it from `onchange`
+    #[props(default)]
+    pub value: Option<Vec<String>>,
+    /// Initially open sections when uncontrolled
+    #[props(default)]
+    pub default_value: Vec<String>,
+    #[props(default)]
+    pub onchange: Option<EventHandler<Vec<String>>>,
+    /// Allow several sections open at once
+    #[props(default = false)]
+    pub multiple: bool,
+    /// With a single open section, allow closing it so none is open
+    #[props(default = true)]
+    pub collapsible: bool,
+    #[props(default)]
+    pub class: Option<String>,
+    children: Element,
+}
+
+/// Stacked sections that expand one at a time, or several with `multiple`, following the
+/// WAI-ARIA accordion pattern: Up/Down, Home and End move between section headers.
+#[component]
+pub fn Accordion(props: AccordionProps) -> Element {
+    let theme = use_theme().accordion;
+    let id = use_unique_id("accordion");
+    let initial = props.value.clone().unwrap_or(props.default_value.clone());
+    let state = AccordionContext {
+        id: use_hook(|| CopyValue::new(id)),
+        open: use_signal(|| initial),
+        items: use_signal(Vec::new),
+        controlled: props.value.is_some(),
+        multiple: props.multiple,
+        collapsible: props.collapsible,
+        onchange: props.onchange,
+    };
+    let mut context = use_context_provider(|| Signal::new(state));
+    use_effect(use_reactive!(|(state)| context.set(state)));
+
+    let mut open = state.open;
+    let value = props.value.clone();
+    use_effect(use_reactive!(|(value)| {
+        if let Some(value) = value {
+            if *open.peek() != value {
+                open.set(value);
+            }
+        }
+    }));
+
+    let class_string = merge_classes(&[theme.root, props.class.as_deref().unwrap_or("")]);
+
+    rsx! {
+        div {
+            class: "{class_string}",
+            {props.children}
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct AccordionItemProps {
+    /// Identifies the section in the accordion's open values
+    pub value: String,
+    pub title: String,
+    #[props(default = false)]
+    pub disabled: bool,
+    #[props(default)]
+    pub class: Option<String>,
+    children: Element,
+}
+
+#[component]
+pub fn AccordionItem(props: AccordionItemProps) -> Element {
+    let theme = use_theme().accordion;
+    let context_signal = use_context::<Signal<AccordionContext>>();
+    let mut context = context_signal();
+
+    let value = props.value.clone();
+    let disabled = props.disabled;
+    let mut items = context.items;
+    use_effect(use_reactive!(|(value, disabled)| {
+        let mut items = items.write();
+        match items.iter_mut().find(|item| item.value == value) {
+            Some(item) => item.disabled = disabled,
+            None => items.push(AccordionEntry { value, disabled }),
+        }
+    }));
+    let drop_value = props.value.clone();
+    use_drop(move || {
+        // The whole `Accordion` may be going away at the same time.
+        if let Ok(mut items) = items.try_write() {
+            items.retain(|item| item.value != drop_value);
+        }
+    });
+
+    let open = context.open.read().contains(&props.value);
+    let click_value = props.value.clone();
+    let key_value = props.value.clone();
+    let onkeydown = move |evt: KeyboardEvent| {
+        let context = context_signal();
+        let entries = context.items.peek().clone();
+        let enabled: Vec<bool> = entries.iter().map(|item| !item.disabled).collect();
+        let current = entries.iter().position(|item| item.value == key_value);
+        let target = roving_target(
+            &enabled,
+            current,
+            &evt.key(),
+            &Key::ArrowUp,
+            &Key::ArrowDown,
+        );
+        if let Some(target) = target {
+            evt.prevent_default();
+            document::eval(&focus_script(&context.trigger_id(&entries[target].value)));
+        }
+    };
+
+    rsx! {
+        div {
+            class: props.class.clone(),
+            h3 {
+                button {
+                    r#type: "button",
+                    id: context.trigger_id(&props.value),
+                    class: theme.trigger,
+                    disabled: props.disabled,
+                    "aria-expanded": "{open}",
+                    "aria-controls": context.panel_id(&props.value),
+                    onclick: move |_| context.toggle(&click_value),
+                    onkeydown,
+                    span { "{props.title}" }
+                    DisclosureIcon { open }
+                }
+            }
+            div {
+                id: context.panel_id(&props.value),
+                role: "region",
+                class: theme.panel,
+                hidden: !open,
+                "aria-labelledby": context.trigger_id(&props.value),
+                {props.children}
+            }
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct CollapsibleProps {
+    pub title: String,
+    /// Open state, for a controlled component; update it from `onchange`
+    #[props(default)]
+    pub open: Option<bool>,
+    /// Initial open state when uncontrolled
+    #[props(default = false)]
+    pub default_open: bool,
+    #[props(default)]
+    pub onchange: Option<EventHandler<bool>>,
+    #[props(default)]
+    pub variant: CardVariant,
+    #[props(default = false)]
+    pub disabled: bool,
+    #[props(default)]
+    pub class: Option<String>,
+    children: Element,
+}
+
+/// A `Card` whose header toggles its content, e.g. for optional settings sections.
+#[component]
+pub fn Collapsible(props: CollapsibleProps) -> Element {
+    let theme = use_theme();
+    let id = use_unique_id("collapsible");
+    let mut internal = use_signal(|| props.open.unwrap_or(props.default_open));
+    let open = props.open.unwrap_or(internal());
+    let controlled = props.open.is_some();
+    let onchange = props.onchange;
+
+    let class_string = CardClasses {
+        variant: props.variant,
+    }
+    .classes(&theme.card, props.class.as_deref().unwrap_or(""));
+    let trigger_id = format!("{id}-trigger");
+    let panel_id = format!("{id}-panel");
+
+    rsx! {
+        div {
+            class: "{class_string}",
+            h3 {
+                button {
+                    r#type: "button",
+                    id: "{trigger_id}",
+                    class: theme.accordion.trigger,
+                    disabled: props.disabled,
+                    "aria-expanded": "{open}",
+                    "aria-controls": "{panel_id}",
+                    onclick: move |_| {
+                        if !controlled {
+                            internal.set(!open);
+                        }
+                        if let Some(handler) = onchange {
+                            handler.call(!open);
+                        }
+                    },
+                    span { "{props.title}" }
+                    DisclosureIcon { open }
+                }
+            }
+            div {
+                id: "{panel_id}",
+                role: "region",
+                class: merge_classes(&[theme.card.content, "pt-0"]),
+                hidden: !open,
+                "aria-labelledby": "{trigger_id}",
+                {props.children}
+            }
+        }
+    }
+}
+
+#[component]
+fn DisclosureIcon(open: bool) -> Element {
+    let theme = use_theme().accordion;
+    let class_string = merge_classes(&[theme.icon, if open { "rotate-180" } else { "" }]);
+    rsx! {
+        svg {
+            class: "{class_string}",
+            xmlns: "http://www.w3.org/2000/svg",
+            fill: "none",
+            view_box: "0 0 24 24",
+            stroke: "currentColor",
+            stroke_width: "2",
+            "aria-hidden": "true",
+            path { stroke_linecap: "round", stroke_linejoin: "round", d: "m19.5 8.25-7.5 7.5-7.5-7.5" }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn test_toggle_single() {
+        assert_eq!(toggle_open(&[], "a", false, true), values(&["a"]));
+        assert_eq!(
+            toggle_open(&values(&["a"]), "b", false, true),
+            values(&["b"])
+        );
+        assert!(toggle_open(&values(&["a"]), "a", false, true).is_empty());
+        // Not collapsible: the open section stays open.
+        assert_eq!(
+            toggle_open(&values(&["a"]), "a", false, false),
+            values(&["a"])
+        );
+    }
+
+    #[test]
+    fn test_toggle_multiple() {
+        let open = toggle_open(&values(&["a"]), "b", true, true);
+        assert_eq!(open, values(&["a", "b"]));
+        assert_eq!(toggle_open(&open, "a", true, false), values(&["b"]));
+    }
+}
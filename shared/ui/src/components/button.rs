@@ -1,89 +1,266 @@
 use dioxus::prelude::*;
 
-#[derive(Clone, PartialEq)]
-pub enum ButtonVariant {
-    Primary,
-    Secondary,
-    Outline,
-    Destructive,
+use crate::{ButtonTheme, Spinner, SpinnerSize, merge_classes, use_theme, use_tooltip_description};
+
+crate::class_variants! {
+    /// Class recipe for `Button`; colors come from the theme's [`ButtonTheme`].
+    pub ButtonClasses(theme: &ButtonTheme) {
+        base: theme.base,
+        variants: {
+            variant: ButtonVariant = Primary {
+                Primary => theme.primary,
+                Secondary => theme.secondary,
+                Outline => theme.outline,
+                Destructive => theme.destructive,
+            },
+            size: ButtonSize = Medium {
+                Small => "px-3 py-1.5 text-sm",
+                Medium => "px-4 py-2 text-base",
+                Large => "px-6 py-3 text-lg",
+            },
+            /// `IconOnly` for [`IconButton`]
+            shape: ButtonShape = Default {
+                Default => "",
+                IconOnly => "",
+            },
+        },
+        compound: [
+            { size: ButtonSize::Small, shape: ButtonShape::IconOnly } => "p-1.5",
+            { size: ButtonSize::Medium, shape: ButtonShape::IconOnly } => "p-2",
+            { size: ButtonSize::Large, shape: ButtonShape::IconOnly } => "p-3",
+        ],
+    }
 }
 
+/// HTML `type` of the rendered `<button>`. Defaults to `Button` so that a button
+/// inside a `<form>` only submits when asked to.
 #[derive(Clone, PartialEq)]
-pub enum ButtonSize {
-    Small,
-    Medium,
-    Large,
+pub enum ButtonType {
+    Button,
+    Submit,
+    Reset,
+}
+
+impl ButtonType {
+    fn to_str(&self) -> &'static str {
+        match self {
+            ButtonType::Button => "button",
+            ButtonType::Submit => "submit",
+            ButtonType::Reset => "reset",
+        }
+    }
 }
 
 #[derive(Props, Clone, PartialEq)]
 pub struct ButtonProps {
-    #[props(default = "Button".to_string())]
-    pub text: String,
-    #[props(default = ButtonVariant::Primary)]
+    /// Plain-text label, rendered before `children`
+    #[props(default)]
+    pub text: Option<String>,
+    #[props(default)]
     pub variant: ButtonVariant,
-    #[props(default = ButtonSize::Medium)]
+    #[props(default)]
     pub size: ButtonSize,
     #[props(default = false)]
     pub disabled: bool,
+    /// Shows a spinner, sets `aria-busy` and ignores clicks
+    #[props(default = false)]
+    pub loading: bool,
+    #[props(default)]
+    pub leading_icon: Option<Element>,
+    #[props(default)]
+    pub trailing_icon: Option<Element>,
+    /// Overrides the accessible name; use [`IconButton`] for buttons without text
+    #[props(default)]
+    pub aria_label: Option<String>,
+    #[props(default = ButtonType::Button)]
+    pub button_type: ButtonType,
+    /// Id of the `<form>` this button belongs to when rendered outside of it
+    #[props(default)]
+    pub form: Option<String>,
+    /// Render as a button-styled `<a>` pointing here instead of a `<button>`
+    #[props(default)]
+    pub href: Option<String>,
+    /// Render as a button-styled router `Link` to this route instead of a `<button>`,
+    /// e.g. `Route::Settings {}.to_string()`; needs an enclosing `Router`
+    #[props(default)]
+    pub to: Option<String>,
+    /// Link target such as `_blank`; only used together with `href` or `to`
+    #[props(default)]
+    pub target: Option<String>,
     #[props(default)]
     pub onclick: Option<EventHandler<MouseEvent>>,
     #[props(default)]
     pub class: Option<String>,
+    pub children: Element,
 }
 
-impl ButtonVariant {
-    fn to_classes(&self) -> &'static str {
+impl ButtonSize {
+    fn to_spinner_size(self) -> SpinnerSize {
         match self {
-            ButtonVariant::Primary => {
-                "bg-blue-600 hover:bg-blue-700 text-white focus:ring-blue-500"
-            }
-            ButtonVariant::Secondary => {
-                "bg-gray-200 hover:bg-gray-300 text-gray-900 focus:ring-gray-400"
-            }
-            ButtonVariant::Outline => {
-                "border-2 border-blue-600 text-blue-600 hover:bg-blue-600 hover:text-white focus:ring-blue-500"
-            }
-            ButtonVariant::Destructive => {
-                "bg-red-600 hover:bg-red-700 text-white focus:ring-red-500"
-            }
+            ButtonSize::Small => SpinnerSize::Small,
+            ButtonSize::Medium => SpinnerSize::Medium,
+            ButtonSize::Large => SpinnerSize::Large,
         }
     }
 }
 
-impl ButtonSize {
-    fn to_classes(&self) -> &'static str {
-        match self {
-            ButtonSize::Small => "px-3 py-1.5 text-sm",
-            ButtonSize::Medium => "px-4 py-2 text-base",
-            ButtonSize::Large => "px-6 py-3 text-lg",
-        }
-    }
+#[derive(Props, Clone, PartialEq)]
+pub struct IconButtonProps {
+    /// Names the button for screen readers, as it shows no text
+    pub aria_label: String,
+    #[props(default)]
+    pub variant: ButtonVariant,
+    #[props(default)]
+    pub size: ButtonSize,
+    #[props(default = false)]
+    pub disabled: bool,
+    /// Shows a spinner instead of the icon, sets `aria-busy` and ignores clicks
+    #[props(default = false)]
+    pub loading: bool,
+    #[props(default = ButtonType::Button)]
+    pub button_type: ButtonType,
+    /// Id of the `<form>` this button belongs to when rendered outside of it
+    #[props(default)]
+    pub form: Option<String>,
+    /// Render as a button-styled `<a>` pointing here instead of a `<button>`
+    #[props(default)]
+    pub href: Option<String>,
+    /// Render as a button-styled router `Link` to this route instead of a `<button>`,
+    /// e.g. `Route::Settings {}.to_string()`; needs an enclosing `Router`
+    #[props(default)]
+    pub to: Option<String>,
+    /// Link target such as `_blank`; only used together with `href` or `to`
+    #[props(default)]
+    pub target: Option<String>,
+    #[props(default)]
+    pub onclick: Option<EventHandler<MouseEvent>>,
+    #[props(default)]
+    pub class: Option<String>,
+    /// The icon
+    pub children: Element,
 }
 
 #[component]
 pub fn Button(props: ButtonProps) -> Element {
-    let base_classes = "font-medium rounded-lg transition-colors duration-200 focus:outline-none focus:ring-2 focus:ring-opacity-50 disabled:opacity-50 disabled:cursor-not-allowed";
-    let variant_classes = props.variant.to_classes();
-    let size_classes = props.size.to_classes();
+    render_button(props, ButtonShape::Default)
+}
+
+/// A square [`Button`] showing only an icon, named by its required `aria_label`.
+#[component]
+pub fn IconButton(props: IconButtonProps) -> Element {
+    render_button(
+        ButtonProps {
+            text: None,
+            variant: props.variant,
+            size: props.size,
+            disabled: props.disabled,
+            loading: props.loading,
+            leading_icon: Some(props.children),
+            trailing_icon: None,
+            aria_label: Some(props.aria_label),
+            button_type: props.button_type,
+            form: props.form,
+            href: props.href,
+            to: props.to,
+            target: props.target,
+            onclick: props.onclick,
+            class: props.class,
+            children: VNode::empty(),
+        },
+        ButtonShape::IconOnly,
+    )
+}
+
+fn render_button(props: ButtonProps, shape: ButtonShape) -> Element {
+    let theme = use_theme().button;
     let additional_classes = props.class.as_deref().unwrap_or("");
 
-    let class_string = format!(
-        "{} {} {} {}",
-        base_classes, variant_classes, size_classes, additional_classes
-    );
+    let class_string = ButtonClasses {
+        variant: props.variant,
+        size: props.size,
+        shape,
+    }
+    .classes(&theme, additional_classes);
+    let aria_label = props.aria_label.clone();
+    let describedby = use_tooltip_description();
+    let inactive = props.disabled || props.loading;
+    let onclick = move |evt: MouseEvent| {
+        if inactive {
+            evt.prevent_default();
+        } else if let Some(handler) = &props.onclick {
+            handler.call(evt);
+        }
+    };
+
+    let content = rsx! {
+        if props.loading {
+            Spinner { size: props.size.to_spinner_size() }
+        } else if let Some(icon) = &props.leading_icon {
+            span { class: "inline-flex shrink-0", "aria-hidden": "true", {icon} }
+        }
+        if shape != ButtonShape::IconOnly {
+            if let Some(text) = &props.text {
+                "{text}"
+            }
+            {props.children}
+            if let Some(icon) = &props.trailing_icon {
+                span { class: "inline-flex shrink-0", "aria-hidden": "true", {icon} }
+            }
+        }
+    };
+
+    // Links cannot be disabled natively: drop the href and take them out of the tab order.
+    let link_classes = "aria-disabled:opacity-50 aria-disabled:cursor-not-allowed";
+    let opens_new_context = props.target.as_deref().is_some_and(|t| t != "_self");
+
+    if let (Some(to), false) = (&props.to, props.disabled) {
+        return rsx! {
+            Link {
+                to: to.clone(),
+                class: merge_classes(&[link_classes, &class_string]),
+                new_tab: opens_new_context,
+                // While loading, `onclick` only cancels the click instead of navigating.
+                onclick_only: inactive,
+                aria_busy: props.loading.then_some("true"),
+                aria_label: aria_label.clone(),
+                aria_describedby: describedby.clone(),
+                onclick,
+                {content}
+            }
+        };
+    }
+
+    if props.href.is_some() || props.to.is_some() {
+        return rsx! {
+            a {
+                class: merge_classes(&[link_classes, &class_string]),
+                href: props.href.clone().filter(|_| !props.disabled),
+                target: props.target.clone(),
+                rel: opens_new_context.then_some("noopener noreferrer"),
+                role: props.disabled.then_some("link"),
+                tabindex: props.disabled.then_some("-1"),
+                "aria-disabled": props.disabled.then_some("true"),
+                "aria-busy": props.loading.then_some("true"),
+                "aria-label": aria_label.clone(),
+                "aria-describedby": describedby.clone(),
+                onclick,
+                {content}
+            }
+        };
+    }
 
     rsx! {
         button {
+            r#type: props.button_type.to_str(),
             class: "{class_string}",
+            form: props.form.clone(),
             disabled: props.disabled,
-            onclick: move |evt| {
-                if !props.disabled {
-                    if let Some(handler) = &props.onclick {
-                        handler.call(evt);
-                    }
-                }
-            },
-            "{props.text}"
+            "aria-busy": props.loading.then_some("true"),
+            "aria-disabled": props.loading.then_some("true"),
+            "aria-label": aria_label.clone(),
+            "aria-describedby": describedby.clone(),
+            onclick,
+            {content}
         }
     }
 }
@@ -1,7 +1,25 @@
 use dioxus::prelude::*;
 
+use crate::{CardTheme, merge_classes, use_theme};
+
+crate::class_variants! {
+    /// Class recipe for the `Card` root.
+    pub CardClasses(theme: &CardTheme) {
+        base: theme.root,
+        variants: {
+            variant: CardVariant = Elevated {
+                Elevated => "",
+                Outlined => "shadow-none",
+                Ghost => "shadow-none border-0 bg-transparent dark:bg-transparent",
+            },
+        },
+    }
+}
+
 #[derive(Props, Clone, PartialEq)]
 pub struct CardProps {
+    #[props(default)]
+    pub variant: CardVariant,
     #[props(default)]
     pub class: Option<String>,
     children: Element,
@@ -23,9 +41,12 @@ pub struct CardContentProps {
 
 #[component]
 pub fn Card(props: CardProps) -> Element {
-    let base_classes = "bg-white dark:bg-gray-800 shadow-lg border border-gray-200 dark:border-gray-700 rounded-lg overflow-hidden";
+    let theme = use_theme().card;
     let additional_classes = props.class.as_deref().unwrap_or("");
-    let class_string = format!("{} {}", base_classes, additional_classes);
+    let class_string = CardClasses {
+        variant: props.variant,
+    }
+    .classes(&theme, additional_classes);
 
     rsx! {
         div {
@@ -37,9 +58,9 @@ pub fn Card(props: CardProps) -> Element {
 
 #[component]
 pub fn CardHeader(props: CardHeaderProps) -> Element {
-    let base_classes = "px-6 py-4 border-b border-gray-200 dark:border-gray-700";
+    let base_classes = use_theme().card.header;
     let additional_classes = props.class.as_deref().unwrap_or("");
-    let class_string = format!("{} {}", base_classes, additional_classes);
+    let class_string = merge_classes(&[base_classes, additional_classes]);
 
     rsx! {
         div {
@@ -51,9 +72,9 @@ pub fn CardHeader(props: CardHeaderProps) -> Element {
 
 #[component]
 pub fn CardContent(props: CardContentProps) -> Element {
-    let base_classes = "px-6 py-4";
+    let base_classes = use_theme().card.content;
     let additional_classes = props.class.as_deref().unwrap_or("");
-    let class_string = format!("{} {}", base_classes, additional_classes);
+    let class_string = merge_classes(&[base_classes, additional_classes]);
 
     rsx! {
         div {
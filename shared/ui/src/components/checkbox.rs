@@ -0,0 +1,87 @@
+use dioxus::document;
+use dioxus::prelude::*;
+
+use crate::{merge_classes, use_field_control, use_theme, use_unique_id};
+
+#[derive(Props, Clone, PartialEq)]
+pub struct CheckboxProps {
+    #[props(default = false)]
+    pub checked: bool,
+    /// Shown as a dash, e.g. for a "select all" box when only some rows are selected
+    #[props(default = false)]
+    pub indeterminate: bool,
+    #[props(default)]
+    pub label: Option<String>,
+    #[props(default = false)]
+    pub disabled: bool,
+    #[props(default = false)]
+    pub required: bool,
+    /// Sets `aria-invalid`; set automatically inside a `Field` with an error
+    #[props(default = false)]
+    pub invalid: bool,
+    /// Called with the new checked state
+    #[props(default)]
+    pub onchange: Option<EventHandler<bool>>,
+    #[props(default)]
+    pub class: Option<String>,
+    #[props(default)]
+    pub id: Option<String>,
+    #[props(default)]
+    pub name: Option<String>,
+    /// Submitted value when checked; the browser sends `on` without it
+    #[props(default)]
+    pub value: Option<String>,
+    /// Extra label content after `label`
+    children: Element,
+}
+
+#[component]
+pub fn Checkbox(props: CheckboxProps) -> Element {
+    let theme = use_theme().input;
+    let control = use_field_control(props.id.as_ref(), props.required, props.invalid);
+    let generated_id = use_unique_id("checkbox");
+    let id = control.id.clone().unwrap_or(generated_id);
+
+    // `indeterminate` only exists as a DOM property, not as an attribute. Clicking clears
+    // it in the DOM, so it is also re-applied whenever `checked` changes.
+    let script_id = id.clone();
+    use_effect(use_reactive(
+        (&props.indeterminate, &props.checked),
+        move |(indeterminate, _)| {
+            document::eval(&format!(
+                "const el = document.getElementById({:?}); if (el) el.indeterminate = {};",
+                script_id, indeterminate
+            ));
+        },
+    ));
+
+    let additional_classes = props.class.as_deref().unwrap_or("");
+    let class_string = merge_classes(&[theme.checkbox, additional_classes]);
+
+    rsx! {
+        label {
+            class: theme.choice_label,
+            input {
+                r#type: "checkbox",
+                id: "{id}",
+                class: "{class_string}",
+                checked: props.checked,
+                disabled: props.disabled,
+                required: control.required,
+                "aria-invalid": control.invalid.then_some("true"),
+                "aria-describedby": control.describedby.clone(),
+                name: props.name.clone(),
+                value: props.value.clone(),
+                onchange: move |evt: FormEvent| {
+                    if let Some(handler) = &props.onchange {
+                        handler.call(evt.checked());
+                    }
+                }
+            }
+            if let Some(label) = &props.label {
+                span { "{label}" }
+            }
+            {props.children}
+        }
+    }
+}
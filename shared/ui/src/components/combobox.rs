@@ -0,0 +1,556 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+use dioxus::document;
+use dioxus::prelude::*;
+use shared_models::User;
+
+use crate::{
+    InputClasses, InputSize, Placement, Popover, SelectOption, sleep, use_field_control, use_theme,
+    use_unique_id,
+};
+
+/// Something a `Combobox` can offer and select.
+pub trait ComboboxOption: Clone + PartialEq + 'static {
+    /// Stable identity, used to compare selections and as the submitted form value
+    fn key(&self) -> String;
+    fn label(&self) -> String;
+    /// Secondary line under the label
+    fn description(&self) -> Option<String> {
+        None
+    }
+}
+
+impl ComboboxOption for User {
+    fn key(&self) -> String {
+        self.id.to_string()
+    }
+
+    fn label(&self) -> String {
+        self.name.clone()
+    }
+
+    fn description(&self) -> Option<String> {
+        Some(self.email.clone())
+    }
+}
+
+impl ComboboxOption for SelectOption {
+    fn key(&self) -> String {
+        self.value.clone()
+    }
+
+    fn label(&self) -> String {
+        self.label.clone()
+    }
+}
+
+type LoadFuture<T> = Pin<Box<dyn Future<Output = Result<Vec<T>, String>>>>;
+
+/// Async search for a `Combobox`, called with the typed query once typing pauses.
+/// Create it once (e.g. in `use_hook`) so the combobox does not re-render needlessly.
+pub struct ComboboxLoader<T> {
+    load: Rc<dyn Fn(String) -> LoadFuture<T>>,
+}
+
+impl<T: 'static> ComboboxLoader<T> {
+    pub fn new<F, Fut>(load: F) -> Self
+    where
+        F: Fn(String) -> Fut + 'static,
+        Fut: Future<Output = Result<Vec<T>, String>> + 'static,
+    {
+        Self {
+            load: Rc::new(move |query| Box::pin(load(query))),
+        }
+    }
+}
+
+impl<T> Clone for ComboboxLoader<T> {
+    fn clone(&self) -> Self {
+        Self {
+            load: self.load.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for ComboboxLoader<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.load, &other.load)
+    }
+}
+
+/// Up to `limit` options whose label or description contains `query`, ignoring case.
+pub fn filter_options<T: ComboboxOption>(options: &[T], query: &str, limit: usize) -> Vec<T> {
+    let query = query.trim().to_lowercase();
+    options
+        .iter()
+        .filter(|option| {
+            query.is_empty()
+                || option.label().to_lowercase().contains(&query)
+                || option
+                    .description()
+                    .is_some_and(|d| d.to_lowercase().contains(&query))
+        })
+        .take(limit)
+        .cloned()
+        .collect()
+}
+
+/// The typed text, if it should be offered as a new entry.
+fn creatable_label<T: ComboboxOption>(
+    query: &str,
+    options: &[T],
+    selected: &[T],
+) -> Option<String> {
+    let query = query.trim();
+    let exists = options
+        .iter()
+        .chain(selected)
+        .any(|option| option.label().eq_ignore_ascii_case(query));
+    (!query.is_empty() && !exists).then(|| query.to_string())
+}
+
+fn scroll_into_view_script(element_id: &str) -> String {
+    format!(r#"document.getElementById({element_id:?})?.scrollIntoView({{ block: "nearest" }});"#)
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct ComboboxProps<T: ComboboxOption> {
+    /// Options filtered locally as the user types; ignored when `loader` is set
+    #[props(default)]
+    pub options: Vec<T>,
+    /// Fetch options for the typed query instead of filtering `options`
+    #[props(default)]
+    pub loader: Option<ComboboxLoader<T>>,
+    /// Pause in typing before `loader` runs; earlier, unfinished searches are cancelled
+    #[props(default = 250)]
+    pub debounce_ms: u32,
+    /// Selected options; at most one unless `multiple`
+    #[props(default)]
+    pub selected: Vec<T>,
+    /// Called with the new selection
+    #[props(default)]
+    pub onchange: Option<EventHandler<Vec<T>>>,
+    /// Select several options, shown as removable chips
+    #[props(default = false)]
+    pub multiple: bool,
+    /// Offer the typed text as a new entry; called with it when chosen
+    #[props(default)]
+    pub oncreate: Option<EventHandler<String>>,
+    /// Cap on rendered options, to keep long lists fast
+    #[props(default = 100)]
+    pub max_results: usize,
+    #[props(default = "No results".to_string())]
+    pub empty_text: String,
+    #[props(default)]
+    pub placeholder: Option<String>,
+    #[props(default)]
+    pub size: InputSize,
+    #[props(default = false)]
+    pub disabled: bool,
+    #[props(default = false)]
+    pub required: bool,
+    /// Error styling and `aria-invalid`; set automatically inside a `Field` with an error
+    #[props(default = false)]
+    pub invalid: bool,
+    /// Submits the keys of the selected options under this name
+    #[props(default)]
+    pub name: Option<String>,
+    #[props(default)]
+    pub id: Option<String>,
+    #[props(default)]
+    pub class: Option<String>,
+}
+
+/// The options shown for `query`: the loader's latest results or the local matches.
+fn visible_options<T: ComboboxOption>(
+    props: &ComboboxProps<T>,
+    query: &str,
+    loaded: Option<&Vec<T>>,
+) -> Vec<T> {
+    match (&props.loader, loaded) {
+        (Some(_), Some(loaded)) => loaded.iter().take(props.max_results).cloned().collect(),
+        (Some(_), None) => Vec::new(),
+        (None, _) => filter_options(&props.options, query, props.max_results),
+    }
+}
+
+/// A text input with a popup listbox, following the WAI-ARIA combobox pattern: focus
+/// stays in the input while Up/Down move the active option and Enter picks it.
+///
+/// Generic over the option type, e.g. `Combobox::<User> { .. }`.
+#[component]
+pub fn Combobox<T: ComboboxOption>(props: ComboboxProps<T>) -> Element {
+    let theme = use_theme();
+    let control = use_field_control(props.id.as_ref(), props.required, props.invalid);
+    let generated_id = use_unique_id("combobox");
+    let input_id = control.id.clone().unwrap_or(generated_id);
+    let listbox_id = format!("{input_id}-listbox");
+
+    let mut latest = use_hook(|| CopyValue::new(props.clone()));
+    latest.set(props.clone());
+    let option_ids = use_hook(|| CopyValue::new(listbox_id.clone()));
+    let option_id = move |index: usize| format!("{}-option-{index}", option_ids.read());
+
+    let mut query = use_signal(String::new);
+    // Single selection: the input shows the selected label until the user types.
+    let mut editing = use_signal(|| false);
+    let mut open = use_signal(|| false);
+    let mut active = use_signal(|| None::<usize>);
+    let mut loaded = use_signal(|| None::<Vec<T>>);
+    let mut loading = use_signal(|| false);
+    let mut load_error = use_signal(|| None::<String>);
+    let mut search_task = use_signal(|| None::<Task>);
+
+    let mut search = move |text: String| {
+        let Some(loader) = latest.read().loader.clone() else {
+            return;
+        };
+        if let Some(task) = search_task.take() {
+            task.cancel();
+        }
+        let debounce_ms = latest.read().debounce_ms;
+        loading.set(true);
+        search_task.set(Some(spawn(async move {
+            sleep(debounce_ms).await;
+            let result = (loader.load)(text).await;
+            match result {
+                Ok(options) => {
+                    active.set((!options.is_empty()).then_some(0));
+                    loaded.set(Some(options));
+                    load_error.set(None);
+                }
+                Err(error) => {
+                    active.set(None);
+                    loaded.set(None);
+                    load_error.set(Some(error));
+                }
+            }
+            loading.set(false);
+            search_task.set(None);
+        })));
+    };
+
+    let mut show = move || {
+        if open() {
+            return;
+        }
+        open.set(true);
+        if loaded.peek().is_none() {
+            search(query.peek().clone());
+        }
+    };
+
+    // Forget the loaded results too: they belong to the query being cleared, and the
+    // next `show` searches again for the empty query.
+    let mut close = move || {
+        open.set(false);
+        editing.set(false);
+        query.set(String::new());
+        if let Some(task) = search_task.take() {
+            task.cancel();
+        }
+        loading.set(false);
+        loaded.set(None);
+        load_error.set(None);
+        active.set(None);
+    };
+
+    let emit = move |selected: Vec<T>| {
+        if let Some(handler) = &latest.read().onchange {
+            handler.call(selected);
+        }
+    };
+
+    let mut choose = move |index: usize| {
+        let props = latest.read().clone();
+        let options = visible_options(&props, &query.peek(), loaded.peek().as_ref());
+        if let Some(option) = options.get(index) {
+            let mut selected = props.selected.clone();
+            if props.multiple {
+                match selected.iter().position(|s| s.key() == option.key()) {
+                    Some(position) => {
+                        selected.remove(position);
+                    }
+                    None => selected.push(option.clone()),
+                }
+                if !query.peek().is_empty() {
+                    query.set(String::new());
+                    search(String::new());
+                }
+            } else {
+                selected = vec![option.clone()];
+                close();
+            }
+            emit(selected);
+        } else if let Some(handler) = &props.oncreate {
+            handler.call(query.peek().trim().to_string());
+            if props.multiple {
+                query.set(String::new());
+                search(String::new());
+            } else {
+                close();
+            }
+        }
+    };
+
+    let selected = props.selected.clone();
+    let options = visible_options(&props, &query(), loaded().as_ref());
+    let create = if props.oncreate.is_some() {
+        creatable_label(&query(), &options, &selected)
+    } else {
+        None
+    };
+    let count = options.len() + usize::from(create.is_some());
+    let current = active().filter(|&index| index < count);
+
+    use_effect(move || {
+        if let (true, Some(index)) = (open(), active()) {
+            document::eval(&scroll_into_view_script(&option_id(index)));
+        }
+    });
+
+    let onkeydown = move |evt: KeyboardEvent| match evt.key() {
+        Key::ArrowDown | Key::ArrowUp if !open() => {
+            evt.prevent_default();
+            show();
+        }
+        Key::ArrowDown if count > 0 => {
+            evt.prevent_default();
+            active.set(Some(current.map_or(0, |index| (index + 1) % count)));
+        }
+        Key::ArrowUp if count > 0 => {
+            evt.prevent_default();
+            active.set(Some(
+                current.map_or(count - 1, |index| (index + count - 1) % count),
+            ));
+        }
+        Key::Enter if open() => {
+            if let Some(index) = current {
+                evt.prevent_default();
+                choose(index);
+            }
+        }
+        Key::Backspace if query.peek().is_empty() && latest.read().multiple => {
+            let mut selected = latest.read().selected.clone();
+            if selected.pop().is_some() {
+                emit(selected);
+            }
+        }
+        Key::Tab => close(),
+        _ => {}
+    };
+
+    let single_label = (!props.multiple && !editing())
+        .then(|| selected.first().map(ComboboxOption::label))
+        .flatten();
+    let input_value = single_label.unwrap_or_else(|| query.read().clone());
+    let placeholder = if props.multiple && !selected.is_empty() {
+        None
+    } else {
+        props.placeholder.clone()
+    };
+    let control_classes = InputClasses {
+        size: props.size,
+        state: control.state(),
+    }
+    .classes(
+        &theme.input,
+        &format!(
+            "{} {}",
+            theme.combobox.control,
+            props.class.as_deref().unwrap_or("")
+        ),
+    );
+    let selected_keys: Vec<String> = selected.iter().map(ComboboxOption::key).collect();
+    let combobox_theme = theme.combobox;
+
+    rsx! {
+        Popover {
+            open: open() && !props.disabled,
+            onclose: move |_| close(),
+            placement: Placement::BOTTOM_START,
+            match_anchor_width: true,
+            anchor_class: "flex w-full",
+            anchor: rsx! {
+                div {
+                    class: "{control_classes}",
+                    "aria-disabled": props.disabled.then_some("true"),
+                    if props.multiple {
+                        for option in selected.iter() {
+                            span {
+                                key: "{option.key()}",
+                                class: combobox_theme.chip,
+                                "{option.label()}"
+                                button {
+                                    r#type: "button",
+                                    class: combobox_theme.chip_remove,
+                                    disabled: props.disabled,
+                                    "aria-label": "Remove {option.label()}",
+                                    onclick: {
+                                        let key = option.key();
+                                        move |_| {
+                                            let mut selected = latest.read().selected.clone();
+                                            selected.retain(|s| s.key() != key);
+                                            emit(selected);
+                                        }
+                                    },
+                                    svg {
+                                        class: "h-3 w-3",
+                                        xmlns: "http://www.w3.org/2000/svg",
+                                        fill: "none",
+                                        view_box: "0 0 24 24",
+                                        stroke: "currentColor",
+                                        stroke_width: "2",
+                                        "aria-hidden": "true",
+                                        path { stroke_linecap: "round", stroke_linejoin: "round", d: "M6 18 18 6M6 6l12 12" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    input {
+                        id: "{input_id}",
+                        r#type: "text",
+                        role: "combobox",
+                        class: combobox_theme.input,
+                        value: "{input_value}",
+                        placeholder,
+                        disabled: props.disabled,
+                        autocomplete: "off",
+                        "aria-autocomplete": "list",
+                        "aria-expanded": "{open}",
+                        "aria-controls": "{listbox_id}",
+                        "aria-activedescendant": current.filter(|_| open()).map(option_id),
+                        "aria-invalid": control.invalid.then_some("true"),
+                        "aria-required": control.required.then_some("true"),
+                        "aria-describedby": control.describedby.clone(),
+                        oninput: move |evt: FormEvent| {
+                            let text = evt.value();
+                            query.set(text.clone());
+                            editing.set(true);
+                            active.set(Some(0));
+                            open.set(true);
+                            search(text);
+                        },
+                        onclick: move |_| show(),
+                        onkeydown,
+                    }
+                    if let Some(name) = &props.name {
+                        for key in selected_keys.iter() {
+                            input { key: "{key}", r#type: "hidden", name: "{name}", value: "{key}" }
+                        }
+                    }
+                }
+            },
+            div {
+                id: "{listbox_id}",
+                role: "listbox",
+                class: combobox_theme.listbox,
+                "aria-multiselectable": props.multiple.then_some("true"),
+                if loading() {
+                    div { class: combobox_theme.status, role: "status", "Searching..." }
+                }
+                for (index, option) in options.iter().enumerate() {
+                    div {
+                        key: "{option.key()}",
+                        id: option_id(index),
+                        role: "option",
+                        class: combobox_theme.option,
+                        "aria-selected": "{selected_keys.contains(&option.key())}",
+                        "data-active": "{current == Some(index)}",
+                        // Keep focus in the input.
+                        onmousedown: move |evt: MouseEvent| evt.prevent_default(),
+                        onmouseenter: move |_| active.set(Some(index)),
+                        onclick: move |_| choose(index),
+                        span {
+                            class: "inline-flex h-4 w-4 shrink-0",
+                            "aria-hidden": "true",
+                            if selected_keys.contains(&option.key()) {
+                                svg {
+                                    class: "h-4 w-4",
+                                    xmlns: "http://www.w3.org/2000/svg",
+                                    fill: "none",
+                                    view_box: "0 0 24 24",
+                                    stroke: "currentColor",
+                                    stroke_width: "2",
+                                    path { stroke_linecap: "round", stroke_linejoin: "round", d: "m4.5 12.75 6 6 9-13.5" }
+                                }
+                            }
+                        }
+                        span {
+                            class: "min-w-0 flex-1 truncate",
+                            "{option.label()}"
+                            if let Some(description) = option.description() {
+                                span { class: combobox_theme.option_description, "{description}" }
+                            }
+                        }
+                    }
+                }
+                if let Some(label) = &create {
+                    div {
+                        id: option_id(options.len()),
+                        role: "option",
+                        class: combobox_theme.option,
+                        "aria-selected": "false",
+                        "data-active": "{current == Some(options.len())}",
+                        onmousedown: move |evt: MouseEvent| evt.prevent_default(),
+                        onmouseenter: {
+                            let index = options.len();
+                            move |_| active.set(Some(index))
+                        },
+                        onclick: {
+                            let index = options.len();
+                            move |_| choose(index)
+                        },
+                        span { class: "inline-flex h-4 w-4 shrink-0", "aria-hidden": "true", "+" }
+                        "Create \"{label}\""
+                    }
+                }
+                if let Some(error) = load_error() {
+                    div { class: combobox_theme.status, role: "alert", "{error}" }
+                } else if count == 0 && !loading() {
+                    div { class: combobox_theme.status, "{props.empty_text}" }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn users() -> Vec<User> {
+        vec![
+            User::new(1, "Ada Lovelace".into(), "ada@example.com".into()).unwrap(),
+            User::new(2, "Alan Turing".into(), "alan@example.com".into()).unwrap(),
+            User::new(3, "Grace Hopper".into(), "grace@navy.mil".into()).unwrap(),
+        ]
+    }
+
+    #[test]
+    fn test_filter_matches_label_and_description() {
+        let users = users();
+        let keys = |found: Vec<User>| found.iter().map(|u| u.id).collect::<Vec<_>>();
+        assert_eq!(keys(filter_options(&users, "a", 10)), vec![1, 2, 3]);
+        assert_eq!(keys(filter_options(&users, "  TURING ", 10)), vec![2]);
+        assert_eq!(keys(filter_options(&users, "navy", 10)), vec![3]);
+        assert_eq!(keys(filter_options(&users, "", 2)), vec![1, 2]);
+        assert!(filter_options(&users, "zzz", 10).is_empty());
+    }
+
+    #[test]
+    fn test_creatable_label() {
+        let tags = vec![SelectOption::new("rust", "Rust")];
+        let selected = vec![SelectOption::new("wasm", "Wasm")];
+        assert_eq!(
+            creatable_label(" Dioxus ", &tags, &selected),
+            Some("Dioxus".to_string())
+        );
+        assert_eq!(creatable_label("rust", &tags, &selected), None);
+        assert_eq!(creatable_label("WASM", &tags, &selected), None);
+        assert_eq!(creatable_label("   ", &tags, &selected), None);
+    }
+}
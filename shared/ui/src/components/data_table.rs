@@ -0,0 +1,816 @@
+use std::cmp::Ordering;
+use std::rc::Rc;
+
+use dioxus::document;
+use dioxus::prelude::*;
+use shared_models::User;
+use shared_models::api::{PageRequest, Pagination};
+
+use crate::{
+    Button, ButtonSize, ButtonVariant, Spinner, SpinnerSize, Virtualizer, merge_classes, use_theme,
+    use_unique_id,
+};
+
+/// A row of a `DataTable`.
+pub trait TableRow: Clone + PartialEq + 'static {
+    /// Stable identity, used for selection so it survives sorting and paging
+    fn row_key(&self) -> String;
+}
+
+impl TableRow for User {
+    fn row_key(&self) -> String {
+        self.id.to_string()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CellAlign {
+    #[default]
+    Start,
+    Center,
+    End,
+}
+
+impl CellAlign {
+    fn to_class(self) -> &'static str {
+        match self {
+            CellAlign::Start => "text-left",
+            CellAlign::Center => "text-center",
+            CellAlign::End => "text-right",
+        }
+    }
+}
+
+type CellRenderer<T> = Rc<dyn Fn(&T) -> Element>;
+type Comparator<T> = Rc<dyn Fn(&T, &T) -> Ordering>;
+
+/// One column of a `DataTable`: its header, how to render a cell and how to sort by it.
+///
+/// Columns hold closures, so build them once (e.g. `use_hook(user_columns)`) rather than
+/// on every render.
+pub struct Column<T> {
+    pub id: String,
+    pub header: String,
+    /// CSS width such as `12rem` or `20%`
+    pub width: Option<String>,
+    pub align: CellAlign,
+    sortable: bool,
+    cell: CellRenderer<T>,
+    compare: Option<Comparator<T>>,
+}
+
+impl<T: 'static> Column<T> {
+    pub fn new(
+        id: impl Into<String>,
+        header: impl Into<String>,
+        cell: impl Fn(&T) -> Element + 'static,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            header: header.into(),
+            width: None,
+            align: CellAlign::default(),
+            sortable: false,
+            cell: Rc::new(cell),
+            compare: None,
+        }
+    }
+
+    /// A column showing plain text.
+    pub fn text(
+        id: impl Into<String>,
+        header: impl Into<String>,
+        text: impl Fn(&T) -> String + 'static,
+    ) -> Self {
+        Self::new(id, header, move |row: &T| rsx! { "{text(row)}" })
+    }
+
+    /// Sort by the value `key` extracts; also makes the column sortable.
+    pub fn sort_key<K: Ord>(mut self, key: impl Fn(&T) -> K + 'static) -> Self {
+        self.sortable = true;
+        self.compare = Some(Rc::new(move |a: &T, b: &T| key(a).cmp(&key(b))));
+        self
+    }
+
+    /// Make the column sortable without a local sort key, for server-side sorting.
+    pub fn sortable(mut self) -> Self {
+        self.sortable = true;
+        self
+    }
+
+    pub fn width(mut self, width: impl Into<String>) -> Self {
+        self.width = Some(width.into());
+        self
+    }
+
+    pub fn align(mut self, align: CellAlign) -> Self {
+        self.align = align;
+        self
+    }
+
+    pub fn is_sortable(&self) -> bool {
+        self.sortable
+    }
+}
+
+impl<T> Clone for Column<T> {
+    fn clone(&self) -> Self {
+        Self {
+            id: self.id.clone(),
+            header: self.header.clone(),
+            width: self.width.clone(),
+            align: self.align,
+            sortable: self.sortable,
+            cell: self.cell.clone(),
+            compare: self.compare.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for Column<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.header == other.header
+            && self.width == other.width
+            && self.align == other.align
+            && self.sortable == other.sortable
+            && Rc::ptr_eq(&self.cell, &other.cell)
+            && match (&self.compare, &other.compare) {
+                (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+                (None, None) => true,
+                _ => false,
+            }
+    }
+}
+
+/// Id, name and email columns for a table of users, all sortable on the client.
+pub fn user_columns() -> Vec<Column<User>> {
+    vec![
+        Column::text("id", "ID", |user: &User| user.id.to_string())
+            .sort_key(|user| user.id)
+            .width("6rem")
+            .align(CellAlign::End),
+        Column::text("name", "Name", |user: &User| user.name.clone())
+            .sort_key(|user| user.name.to_lowercase()),
+        Column::text("email", "Email", |user: &User| user.email.clone())
+            .sort_key(|user| user.email.to_lowercase()),
+    ]
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+impl SortDirection {
+    /// Value for `aria-sort`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SortDirection::Ascending => "ascending",
+            SortDirection::Descending => "descending",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SortState {
+    /// `Column::id` of the sorted column
+    pub column: String,
+    pub direction: SortDirection,
+}
+
+impl SortState {
+    pub fn ascending(column: impl Into<String>) -> Self {
+        Self {
+            column: column.into(),
+            direction: SortDirection::Ascending,
+        }
+    }
+
+    pub fn descending(column: impl Into<String>) -> Self {
+        Self {
+            column: column.into(),
+            direction: SortDirection::Descending,
+        }
+    }
+}
+
+/// Clicking a header cycles ascending, descending, unsorted; another column starts over.
+pub fn next_sort(current: Option<&SortState>, column: &str) -> Option<SortState> {
+    match current {
+        Some(sort) if sort.column == column => match sort.direction {
+            SortDirection::Ascending => Some(SortState::descending(column)),
+            SortDirection::Descending => None,
+        },
+        _ => Some(SortState::ascending(column)),
+    }
+}
+
+/// What the table is showing, for fetching the matching page from a server.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableQuery {
+    pub sort: Option<SortState>,
+    /// Normalized like the table's own pagination; `per_page` is 0 when it shows every row
+    pub page: PageRequest,
+}
+
+/// Indices of `rows` in display order. The sort is stable, so equal rows keep their order.
+fn sorted_indices<T>(rows: &[T], columns: &[Column<T>], sort: Option<&SortState>) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..rows.len()).collect();
+    let compare = sort.and_then(|sort| {
+        let column = columns.iter().find(|c| c.id == sort.column)?;
+        Some((column.compare.clone()?, sort.direction))
+    });
+    if let Some((compare, direction)) = compare {
+        order.sort_by(|&a, &b| {
+            let ordering = compare(&rows[a], &rows[b]);
+            match direction {
+                SortDirection::Ascending => ordering,
+                SortDirection::Descending => ordering.reverse(),
+            }
+        });
+    }
+    order
+}
+
+/// The selection after clicking the row at `index` of `keys`. The row flips, and with an
+/// `anchor` (the previously clicked row, on shift-click) every row in between is set to
+/// the same state.
+fn select_rows(
+    keys: &[String],
+    selected: &[String],
+    anchor: Option<usize>,
+    index: usize,
+) -> Vec<String> {
+    let Some(key) = keys.get(index) else {
+        return selected.to_vec();
+    };
+    let select = !selected.contains(key);
+    let (start, end) = match anchor.filter(|&anchor| anchor < keys.len()) {
+        Some(anchor) => (anchor.min(index), anchor.max(index)),
+        None => (index, index),
+    };
+    set_selected(selected, &keys[start..=end], select)
+}
+
+/// Select every row of `keys`, or clear them if they are all selected already.
+fn toggle_all(keys: &[String], selected: &[String]) -> Vec<String> {
+    let all = !keys.is_empty() && keys.iter().all(|key| selected.contains(key));
+    set_selected(selected, keys, !all)
+}
+
+fn set_selected(selected: &[String], keys: &[String], select: bool) -> Vec<String> {
+    let mut next: Vec<String> = selected
+        .iter()
+        .filter(|key| select || !keys.contains(key))
+        .cloned()
+        .collect();
+    if select {
+        for key in keys {
+            if !next.contains(key) {
+                next.push(key.clone());
+            }
+        }
+    }
+    next
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct DataTableProps<T: TableRow> {
+    /// All rows, or with `server_side` only the current page
+    pub rows: Vec<T>,
+    pub columns: Vec<Column<T>>,
+    /// Rows arrive sorted and paged from a server: fetch them in `onquerychange`
+    #[props(default = false)]
+    pub server_side: bool,
+    /// Total row count across all pages, for server-side pagination
+    #[props(default)]
+    pub total: Option<u64>,
+    #[props(default)]
+    pub default_sort: Option<SortState>,
+    /// Rows per page, at most `PageRequest::MAX_PER_PAGE`; 0 shows every row
+    #[props(default = 20)]
+    pub per_page: u32,
+    /// Called when the sort or page changes; sorting returns to the first page
+    #[props(default)]
+    pub onquerychange: Option<EventHandler<TableQuery>>,
+    /// Show a checkbox column; shift-click selects a range
+    #[props(default = false)]
+    pub selectable: bool,
+    /// `TableRow::row_key`s of the selected rows
+    #[props(default)]
+    pub selected: Vec<String>,
+    #[props(default)]
+    pub onselectionchange: Option<EventHandler<Vec<String>>>,
+    #[props(default)]
+    pub onrowclick: Option<EventHandler<T>>,
+    /// Shows a spinner in place of an empty table, or dims the current rows
+    #[props(default = false)]
+    pub loading: bool,
+    #[props(default = "No results".to_string())]
+    pub empty_text: String,
+    /// Limit the height, e.g. `24rem`; the header stays in view while the rows scroll
+    #[props(default)]
+    pub max_height: Option<String>,
+    /// Render only the rows scrolled into view, for long tables without pagination
+    /// (`per_page: 0`). Create it with `use_virtualizer(rows.len(), ..)`; needs `max_height`
+    #[props(default)]
+    pub virtualizer: Option<Virtualizer>,
+    /// Accessible name of the table
+    #[props(default)]
+    pub caption: Option<String>,
+    #[props(default)]
+    pub class: Option<String>,
+}
+
+/// A table of `rows` with sortable columns, pagination and optional row selection.
+///
+/// By default sorting and paging happen in the browser over all `rows`. With
+/// `server_side`, the table only tracks the sort and page and reports them through
+/// `onquerychange`; pass the fetched page as `rows` and the row count as `total`.
+///
+/// Generic over the row type, e.g. `DataTable::<User> { columns: user_columns(), .. }`.
+#[component]
+pub fn DataTable<T: TableRow>(props: DataTableProps<T>) -> Element {
+    let theme = use_theme();
+    let table_theme = theme.data_table;
+    let select_all_id = use_unique_id("data-table-select-all");
+
+    let mut sort = use_signal(|| props.default_sort.clone());
+    let mut page = use_signal(|| 1_u32);
+    // Last clicked row on this page, where a shift-click range starts.
+    let mut anchor = use_signal(|| None::<usize>);
+
+    let per_page = props.per_page;
+    let total = match (props.server_side, props.total) {
+        (true, Some(total)) => total,
+        _ => props.rows.len() as u64,
+    };
+    let pagination = (per_page > 0).then(|| {
+        let pagination = Pagination::new(
+            PageRequest {
+                page: page(),
+                per_page,
+            },
+            total,
+        );
+        // Fewer rows than before: stay on the last page that still exists.
+        Pagination::new(
+            PageRequest {
+                page: pagination.page.min(pagination.total_pages.max(1)),
+                per_page,
+            },
+            total,
+        )
+    });
+    let current_page = pagination.map_or(1, |p| p.page);
+
+    let visible: Vec<&T> = if props.server_side {
+        props.rows.iter().collect()
+    } else {
+        let order = sorted_indices(&props.rows, &props.columns, sort.read().as_ref());
+        let (skip, take) = match pagination {
+            Some(p) => (
+                PageRequest {
+                    page: p.page,
+                    per_page: p.per_page,
+                }
+                .offset(),
+                p.per_page as usize,
+            ),
+            None => (0, order.len()),
+        };
+        order
+            .into_iter()
+            .skip(skip)
+            .take(take)
+            .map(|index| &props.rows[index])
+            .collect()
+    };
+
+    let onquerychange = props.onquerychange;
+    let emit = move |sort: Option<SortState>, page: u32| {
+        if let Some(handler) = onquerychange {
+            // Clamped like `pagination`, so the server returns the page size the footer shows.
+            let request = PageRequest { page, per_page };
+            handler.call(TableQuery {
+                sort,
+                page: if per_page == 0 {
+                    request
+                } else {
+                    request.normalized()
+                },
+            });
+        }
+    };
+    let mut sort_by = move |column: String| {
+        let next = next_sort(sort.peek().as_ref(), &column);
+        sort.set(next.clone());
+        page.set(1);
+        anchor.set(None);
+        emit(next, 1);
+    };
+    let mut go_to = move |target: u32| {
+        page.set(target);
+        anchor.set(None);
+        emit(sort.peek().clone(), target);
+    };
+
+    let keys: Vec<String> = visible.iter().map(|row| row.row_key()).collect();
+
+    // The virtualizer caches heights by position. Appended rows keep the positions of the
+    // rows before them; any other change (sorting, another page, inserted or removed
+    // rows) moves rows, so their cached heights are dropped.
+    let mut measured_keys = use_hook(|| CopyValue::new(Vec::<String>::new()));
+    let virtualizer = props.virtualizer;
+    use_effect(use_reactive!(|(keys)| {
+        if let Some(mut virtualizer) = virtualizer {
+            if !keys.starts_with(&measured_keys.peek()) {
+                virtualizer.reset_measurements();
+            }
+        }
+        measured_keys.set(keys);
+    }));
+    let mut selection = use_hook(|| CopyValue::new((Vec::<String>::new(), Vec::<String>::new())));
+    selection.set((keys.clone(), props.selected.clone()));
+    let onselectionchange = props.onselectionchange;
+    let mut toggle_row = move |index: usize, extend: bool| {
+        let (keys, selected) = selection.read().clone();
+        let range_start = if extend { *anchor.peek() } else { None };
+        anchor.set(Some(index));
+        if let Some(handler) = onselectionchange {
+            handler.call(select_rows(&keys, &selected, range_start, index));
+        }
+    };
+    let toggle_page = move |_| {
+        let (keys, selected) = selection.read().clone();
+        if let Some(handler) = onselectionchange {
+            handler.call(toggle_all(&keys, &selected));
+        }
+    };
+
+    let selected_count = keys
+        .iter()
+        .filter(|key| props.selected.contains(key))
+        .count();
+    let all_selected = !keys.is_empty() && selected_count == keys.len();
+    let some_selected = selected_count > 0 && !all_selected;
+    // As in `Checkbox`: clicking clears `indeterminate`, so re-apply it on every change.
+    let script_id = select_all_id.clone();
+    use_effect(use_reactive(
+        (&some_selected, &all_selected),
+        move |(some_selected, _)| {
+            document::eval(&format!(
+                "const el = document.getElementById({:?}); if (el) el.indeterminate = {};",
+                script_id, some_selected
+            ));
+        },
+    ));
+
+    let rendered = virtualizer.map_or(0..visible.len(), |v| v.range());
+    let rendered = rendered.start.min(visible.len())..rendered.end.min(visible.len());
+    let (space_before, space_after) = virtualizer.map_or((0.0, 0.0), |v| v.padding());
+    // 1-based position among all rows, counting the header row, for `aria-rowindex`.
+    let first_row_index = pagination
+        .map_or(0, |p| {
+            PageRequest {
+                page: p.page,
+                per_page: p.per_page,
+            }
+            .offset()
+        })
+        .saturating_add(2);
+
+    let column_count = props.columns.len() + usize::from(props.selectable);
+    let container_class =
+        merge_classes(&[table_theme.container, props.class.as_deref().unwrap_or("")]);
+    let container_style = props
+        .max_height
+        .as_ref()
+        .map(|height| format!("max-height: {height};"));
+    let current_sort = sort();
+    let clickable = props.onrowclick.is_some();
+    let row_class = merge_classes(&[
+        table_theme.row,
+        if clickable { "cursor-pointer" } else { "" },
+    ]);
+    let body_class = if props.loading && !visible.is_empty() {
+        "opacity-50 transition-opacity"
+    } else {
+        "transition-opacity"
+    };
+
+    rsx! {
+        div {
+            div {
+                id: virtualizer.map(|v| v.container_id()),
+                class: "{container_class}",
+                style: container_style,
+                onmounted: move |evt| {
+                    if let Some(mut virtualizer) = virtualizer {
+                        virtualizer.attach(evt);
+                    }
+                },
+                onscroll: move |_| {
+                    if let Some(virtualizer) = virtualizer {
+                        virtualizer.handle_scroll();
+                    }
+                },
+                onresize: move |evt| {
+                    if let Some(mut virtualizer) = virtualizer {
+                        virtualizer.handle_resize(evt);
+                    }
+                },
+                table {
+                    class: table_theme.table,
+                    "aria-busy": props.loading.then_some("true"),
+                    "aria-rowcount": (per_page > 0 || virtualizer.is_some()).then(|| (total + 1).to_string()),
+                    if let Some(caption) = &props.caption {
+                        caption { class: "sr-only", "{caption}" }
+                    }
+                    colgroup {
+                        if props.selectable {
+                            col { style: "width: 3rem;" }
+                        }
+                        for column in props.columns.iter() {
+                            col {
+                                key: "{column.id}",
+                                style: column.width.as_ref().map(|width| format!("width: {width};")),
+                            }
+                        }
+                    }
+                    thead {
+                        tr {
+                            if props.selectable {
+                                th {
+                                    scope: "col",
+                                    class: table_theme.header_cell,
+                                    input {
+                                        id: "{select_all_id}",
+                                        r#type: "checkbox",
+                                        class: theme.input.checkbox,
+                                        checked: all_selected,
+                                        disabled: keys.is_empty(),
+                                        "aria-label": "Select all rows on this page",
+                                        onchange: toggle_page,
+                                    }
+                                }
+                            }
+                            for column in props.columns.iter() {
+                                th {
+                                    key: "{column.id}",
+                                    scope: "col",
+                                    class: merge_classes(&[table_theme.header_cell, column.align.to_class()]),
+                                    "aria-sort": column.sortable.then(|| {
+                                        match current_sort.as_ref().filter(|sort| sort.column == column.id) {
+                                            Some(sort) => sort.direction.as_str(),
+                                            None => "none",
+                                        }
+                                    }),
+                                    if column.sortable {
+                                        button {
+                                            r#type: "button",
+                                            class: table_theme.sort_button,
+                                            onclick: {
+                                                let id = column.id.clone();
+                                                move |_| sort_by(id.clone())
+                                            },
+                                            "{column.header}"
+                                            SortIcon {
+                                                direction: current_sort
+                                                    .as_ref()
+                                                    .filter(|sort| sort.column == column.id)
+                                                    .map(|sort| sort.direction),
+                                            }
+                                        }
+                                    } else {
+                                        "{column.header}"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    tbody {
+                        class: body_class,
+                        if visible.is_empty() {
+                            tr {
+                                td {
+                                    colspan: "{column_count}",
+                                    class: table_theme.status,
+                                    if props.loading {
+                                        span {
+                                            class: "inline-flex items-center gap-2",
+                                            role: "status",
+                                            Spinner { size: SpinnerSize::Small }
+                                            "Loading..."
+                                        }
+                                    } else {
+                                        "{props.empty_text}"
+                                    }
+                                }
+                            }
+                        }
+                        if space_before > 0.0 {
+                            tr { "aria-hidden": "true", td { colspan: "{column_count}", style: "height: {space_before}px; padding: 0;" } }
+                        }
+                        for index in rendered {
+                            tr {
+                                key: "{keys[index]}",
+                                class: "{row_class}",
+                                "aria-rowindex": "{first_row_index + index}",
+                                onresize: move |evt| {
+                                    if let Some(mut virtualizer) = virtualizer {
+                                        virtualizer.measure(index, evt);
+                                    }
+                                },
+                                // Not `aria-selected`, which plain table rows don't support; the row
+                                // checkbox carries the state for assistive technology.
+                                "data-selected": props.selectable.then(|| props.selected.contains(&keys[index]).to_string()),
+                                onclick: {
+                                    let row = visible[index].clone();
+                                    let onrowclick = props.onrowclick;
+                                    move |_| {
+                                        if let Some(handler) = onrowclick {
+                                            handler.call(row.clone());
+                                        }
+                                    }
+                                },
+                                if props.selectable {
+                                    td {
+                                        class: table_theme.cell,
+                                        input {
+                                            r#type: "checkbox",
+                                            class: theme.input.checkbox,
+                                            checked: props.selected.contains(&keys[index]),
+                                            "aria-label": "Select row",
+                                            // Shift-click would otherwise also select text.
+                                            onmousedown: move |evt: MouseEvent| {
+                                                if evt.modifiers().shift() {
+                                                    evt.prevent_default();
+                                                }
+                                            },
+                                            onclick: move |evt: MouseEvent| {
+                                                evt.stop_propagation();
+                                                toggle_row(index, evt.modifiers().shift());
+                                            },
+                                        }
+                                    }
+                                }
+                                for column in props.columns.iter() {
+                                    td {
+                                        key: "{column.id}",
+                                        class: merge_classes(&[table_theme.cell, column.align.to_class()]),
+                                        {(column.cell)(visible[index])}
+                                    }
+                                }
+                            }
+                        }
+                        if space_after > 0.0 {
+                            tr { "aria-hidden": "true", td { colspan: "{column_count}", style: "height: {space_after}px; padding: 0;" } }
+                        }
+                    }
+                }
+            }
+            if let Some(pagination) = pagination.filter(|p| p.total_pages > 1) {
+                nav {
+                    class: table_theme.footer,
+                    "aria-label": "Pagination",
+                    span {
+                        "Showing {(u64::from(current_page) - 1) * u64::from(pagination.per_page) + 1}"
+                        "–{(u64::from(current_page) * u64::from(pagination.per_page)).min(total)} of {total}"
+                    }
+                    div {
+                        class: "flex items-center gap-2",
+                        span { "Page {current_page} of {pagination.total_pages}" }
+                        Button {
+                            text: "Previous",
+                            variant: ButtonVariant::Outline,
+                            size: ButtonSize::Small,
+                            disabled: !pagination.has_previous(),
+                            onclick: move |_| go_to(current_page - 1),
+                        }
+                        Button {
+                            text: "Next",
+                            variant: ButtonVariant::Outline,
+                            size: ButtonSize::Small,
+                            disabled: !pagination.has_next(),
+                            onclick: move |_| go_to(current_page + 1),
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn SortIcon(direction: Option<SortDirection>) -> Element {
+    let (path, class) = match direction {
+        Some(SortDirection::Ascending) => ("m4.5 15.75 7.5-7.5 7.5 7.5", "h-3.5 w-3.5"),
+        Some(SortDirection::Descending) => ("m19.5 8.25-7.5 7.5-7.5-7.5", "h-3.5 w-3.5"),
+        None => (
+            "M8.25 15 12 18.75 15.75 15m-7.5-6L12 5.25 15.75 9",
+            "h-3.5 w-3.5 opacity-40",
+        ),
+    };
+    rsx! {
+        svg {
+            class,
+            xmlns: "http://www.w3.org/2000/svg",
+            fill: "none",
+            view_box: "0 0 24 24",
+            stroke: "currentColor",
+            stroke_width: "2",
+            "aria-hidden": "true",
+            path { stroke_linecap: "round", stroke_linejoin: "round", d: path }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys(keys: &[&str]) -> Vec<String> {
+        keys.iter().map(|key| key.to_string()).collect()
+    }
+
+    #[test]
+    fn test_next_sort_cycles() {
+        let asc = next_sort(None, "name");
+        assert_eq!(asc, Some(SortState::ascending("name")));
+        let desc = next_sort(asc.as_ref(), "name");
+        assert_eq!(desc, Some(SortState::descending("name")));
+        assert_eq!(next_sort(desc.as_ref(), "name"), None);
+        assert_eq!(
+            next_sort(desc.as_ref(), "email"),
+            Some(SortState::ascending("email"))
+        );
+    }
+
+    #[test]
+    fn test_sorted_indices() {
+        let users = vec![
+            User::new(1, "grace".into(), "g@example.com".into()).unwrap(),
+            User::new(2, "Ada".into(), "a@example.com".into()).unwrap(),
+            User::new(3, "Alan".into(), "a@example.com".into()).unwrap(),
+        ];
+        let columns = user_columns();
+        assert_eq!(sorted_indices(&users, &columns, None), vec![0, 1, 2]);
+        let by_name = SortState::ascending("name");
+        assert_eq!(
+            sorted_indices(&users, &columns, Some(&by_name)),
+            vec![1, 2, 0]
+        );
+        let by_id = SortState::descending("id");
+        assert_eq!(
+            sorted_indices(&users, &columns, Some(&by_id)),
+            vec![2, 1, 0]
+        );
+        // Stable: equal emails keep their original order in both directions.
+        let by_email = SortState::descending("email");
+        assert_eq!(
+            sorted_indices(&users, &columns, Some(&by_email)),
+            vec![0, 1, 2]
+        );
+        let unknown = SortState::ascending("missing");
+        assert_eq!(
+            sorted_indices(&users, &columns, Some(&unknown)),
+            vec![0, 1, 2]
+        );
+    }
+
+    #[test]
+    fn test_select_rows_with_shift_ranges() {
+        let rows = keys(&["a", "b", "c", "d", "e"]);
+
+        let selected = select_rows(&rows, &[], None, 1);
+        assert_eq!(selected, keys(&["b"]));
+
+        // Shift-click from b to d selects the range.
+        let selected = select_rows(&rows, &selected, Some(1), 3);
+        assert_eq!(selected, keys(&["b", "c", "d"]));
+
+        // Shift-click on a selected row clears the range back to the anchor.
+        let selected = select_rows(&rows, &selected, Some(3), 2);
+        assert_eq!(selected, keys(&["b"]));
+
+        // Works upwards and keeps keys from other pages.
+        let selected = select_rows(&rows, &keys(&["x"]), Some(4), 2);
+        assert_eq!(selected, keys(&["x", "c", "d", "e"]));
+
+        assert_eq!(select_rows(&rows, &keys(&["a"]), None, 9), keys(&["a"]));
+    }
+
+    #[test]
+    fn test_toggle_all() {
+        let rows = keys(&["a", "b"]);
+        assert_eq!(
+            toggle_all(&rows, &keys(&["x", "a"])),
+            keys(&["x", "a", "b"])
+        );
+        assert_eq!(toggle_all(&rows, &keys(&["a", "x", "b"])), keys(&["x"]));
+        assert!(toggle_all(&[], &[]).is_empty());
+    }
+}
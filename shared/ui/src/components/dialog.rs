@@ -0,0 +1,278 @@
+use dioxus::document;
+use dioxus::prelude::*;
+
+use crate::components::portal::portal_script;
+use crate::{Button, ButtonType, ButtonVariant, DialogTheme, Portal, use_theme, use_unique_id};
+
+crate::class_variants! {
+    /// Class recipe for the `Dialog` panel.
+    pub DialogClasses(theme: &DialogTheme) {
+        base: theme.panel,
+        variants: {
+            size: DialogSize = Medium {
+                Small => "max-w-sm",
+                Medium => "max-w-lg",
+                Large => "max-w-2xl",
+                Full => "max-w-[calc(100vw-2rem)]",
+            },
+        },
+    }
+}
+
+const FOCUSABLE: &str = r#"a[href], button:not([disabled]), input:not([disabled]):not([type="hidden"]), select:not([disabled]), textarea:not([disabled]), [tabindex]:not([tabindex="-1"])"#;
+
+/// Move into the portal, remember the focused element, focus the `data-autofocus`
+/// element (or the first focusable one), trap Tab inside the panel and lock scrolling.
+/// Scroll locks are counted so stacked dialogs release the page only when the last closes.
+fn open_script(panel_id: &str) -> String {
+    format!(
+        r#"{portal}
+(function () {{
+    const panel = document.getElementById({panel_id:?});
+    if (!panel) return;
+    window.__sharedUiDialogFocus = window.__sharedUiDialogFocus || {{}};
+    window.__sharedUiDialogFocus[{panel_id:?}] = document.activeElement;
+
+    const selector = {FOCUSABLE:?};
+    const focusables = () => Array.from(panel.querySelectorAll(selector))
+        .filter((el) => el.getClientRects().length > 0);
+    const preferred = panel.querySelector("[data-autofocus]");
+    const target = (preferred && (preferred.matches(selector) ? preferred : preferred.querySelector(selector)))
+        || focusables()[0] || panel;
+    target.focus();
+
+    panel.addEventListener("keydown", (event) => {{
+        if (event.key !== "Tab") return;
+        // A nested dialog's keydown bubbles through here too; leave it to its own trap.
+        if (event.target.closest('[aria-modal="true"]') !== panel) return;
+        const items = focusables();
+        if (items.length === 0) {{
+            event.preventDefault();
+            return;
+        }}
+        const first = items[0];
+        const last = items[items.length - 1];
+        const active = document.activeElement;
+        if (event.shiftKey && (active === first || active === panel)) {{
+            event.preventDefault();
+            last.focus();
+        }} else if (!event.shiftKey && active === last) {{
+            event.preventDefault();
+            first.focus();
+        }}
+    }});
+
+    const body = document.body;
+    const locks = Number(body.dataset.scrollLocks || 0);
+    if (locks === 0) body.dataset.scrollRestore = body.style.overflow;
+    body.dataset.scrollLocks = String(locks + 1);
+    body.style.overflow = "hidden";
+}})();"#,
+        portal = portal_script(panel_id)
+    )
+}
+
+/// Undo [`open_script`]: release the scroll lock and restore focus.
+fn close_script(panel_id: &str) -> String {
+    format!(
+        r#"(function () {{
+    const body = document.body;
+    const locks = Number(body.dataset.scrollLocks || 1) - 1;
+    if (locks <= 0) {{
+        body.style.overflow = body.dataset.scrollRestore || "";
+        delete body.dataset.scrollLocks;
+        delete body.dataset.scrollRestore;
+    }} else {{
+        body.dataset.scrollLocks = String(locks);
+    }}
+    const store = window.__sharedUiDialogFocus || {{}};
+    const previous = store[{panel_id:?}];
+    delete store[{panel_id:?}];
+    if (previous && previous.isConnected && previous.focus) previous.focus();
+}})();"#
+    )
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct DialogProps {
+    pub open: bool,
+    /// Called when the dialog asks to close (Escape, backdrop, close button)
+    #[props(default)]
+    pub onclose: Option<EventHandler<()>>,
+    /// Labels the dialog through `aria-labelledby`
+    #[props(default)]
+    pub title: Option<String>,
+    /// Describes the dialog through `aria-describedby`
+    #[props(default)]
+    pub description: Option<String>,
+    #[props(default)]
+    pub size: DialogSize,
+    #[props(default = true)]
+    pub close_on_escape: bool,
+    #[props(default = true)]
+    pub close_on_backdrop: bool,
+    /// Use `role="alertdialog"` for confirmations that interrupt the user
+    #[props(default = false)]
+    pub alert: bool,
+    /// Buttons shown below the content
+    #[props(default)]
+    pub footer: Option<Element>,
+    #[props(default)]
+    pub class: Option<String>,
+    children: Element,
+}
+
+/// A modal dialog rendered in a [`Portal`]. Focus moves into the dialog when it opens,
+/// stays there while it is open and returns to where it was when it closes. Mark the
+/// element to focus first with a `data-autofocus` attribute.
+#[component]
+pub fn Dialog(props: DialogProps) -> Element {
+    rsx! {
+        if props.open {
+            Portal {
+                DialogPanel { dialog: props.clone() }
+            }
+        }
+    }
+}
+
+#[component]
+fn DialogPanel(dialog: DialogProps) -> Element {
+    let theme = use_theme().dialog;
+    let id = use_unique_id("dialog");
+    let title_id = format!("{}-title", id);
+    let description_id = format!("{}-description", id);
+
+    let release_id = id.clone();
+    use_drop(move || {
+        document::eval(&close_script(&release_id));
+    });
+
+    let additional_classes = dialog.class.as_deref().unwrap_or("");
+    let class_string = DialogClasses { size: dialog.size }.classes(&theme, additional_classes);
+
+    let onclose = dialog.onclose;
+    let close = move || {
+        if let Some(handler) = &onclose {
+            handler.call(());
+        }
+    };
+    let close_on_escape = dialog.close_on_escape;
+    let close_on_backdrop = dialog.close_on_backdrop;
+    let mount_id = id.clone();
+
+    rsx! {
+        div {
+            class: "fixed inset-0 z-50 flex items-center justify-center p-4",
+            div {
+                class: theme.backdrop,
+                "aria-hidden": "true",
+                onclick: move |_| {
+                    if close_on_backdrop {
+                        close();
+                    }
+                }
+            }
+            div {
+                id: "{id}",
+                class: "{class_string}",
+                role: if dialog.alert { "alertdialog" } else { "dialog" },
+                tabindex: "-1",
+                "aria-modal": "true",
+                "aria-labelledby": dialog.title.as_ref().map(|_| title_id.clone()),
+                "aria-describedby": dialog.description.as_ref().map(|_| description_id.clone()),
+                onmounted: move |_| {
+                    document::eval(&open_script(&mount_id));
+                },
+                onkeydown: move |evt: KeyboardEvent| {
+                    if close_on_escape && evt.key() == Key::Escape {
+                        evt.stop_propagation();
+                        close();
+                    }
+                },
+                if let Some(title) = &dialog.title {
+                    h2 { id: "{title_id}", class: theme.title, "{title}" }
+                }
+                if let Some(description) = &dialog.description {
+                    p { id: "{description_id}", class: theme.description, "{description}" }
+                }
+                {dialog.children}
+                if let Some(footer) = &dialog.footer {
+                    div { class: theme.footer, {footer} }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct ConfirmDialogProps {
+    pub open: bool,
+    pub title: String,
+    #[props(default)]
+    pub message: Option<String>,
+    #[props(default = "Confirm".to_string())]
+    pub confirm_text: String,
+    #[props(default = "Cancel".to_string())]
+    pub cancel_text: String,
+    /// Red confirm button, and Cancel focused first so Enter does not destroy anything
+    #[props(default = true)]
+    pub destructive: bool,
+    /// Shows a spinner on the confirm button while the action runs
+    #[props(default = false)]
+    pub loading: bool,
+    #[props(default)]
+    pub onconfirm: Option<EventHandler<()>>,
+    #[props(default)]
+    pub oncancel: Option<EventHandler<()>>,
+}
+
+/// Preset asking the user to confirm an action, destructive by default.
+#[component]
+pub fn ConfirmDialog(props: ConfirmDialogProps) -> Element {
+    let oncancel = props.oncancel;
+    let onconfirm = props.onconfirm;
+    let loading = props.loading;
+    let cancel = move || {
+        if !loading {
+            if let Some(handler) = &oncancel {
+                handler.call(());
+            }
+        }
+    };
+
+    rsx! {
+        Dialog {
+            open: props.open,
+            title: props.title.clone(),
+            description: props.message.clone(),
+            size: DialogSize::Small,
+            alert: true,
+            close_on_backdrop: !props.destructive,
+            onclose: move |_| cancel(),
+            footer: rsx! {
+                div {
+                    "data-autofocus": props.destructive.then_some("true"),
+                    class: "contents",
+                    Button {
+                        text: props.cancel_text.clone(),
+                        variant: ButtonVariant::Outline,
+                        disabled: props.loading,
+                        onclick: move |_| cancel()
+                    }
+                }
+                Button {
+                    text: props.confirm_text.clone(),
+                    variant: if props.destructive { ButtonVariant::Destructive } else { ButtonVariant::Primary },
+                    button_type: ButtonType::Button,
+                    loading: props.loading,
+                    onclick: move |_| {
+                        if let Some(handler) = &onconfirm {
+                            handler.call(());
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use dioxus::prelude::*;
+
+use crate::{InputState, Label, merge_classes, use_theme, use_tooltip_description, use_unique_id};
+
+/// Ids and state a `Field` shares with the control inside it.
+#[derive(Clone, PartialEq)]
+pub struct FieldContext {
+    pub id: String,
+    pub hint_id: Option<String>,
+    pub error_id: Option<String>,
+    pub required: bool,
+    pub invalid: bool,
+}
+
+impl FieldContext {
+    /// Space-separated ids of the hint and error, for `aria-describedby`.
+    pub fn describedby(&self) -> Option<String> {
+        let ids: Vec<&str> = [&self.hint_id, &self.error_id]
+            .into_iter()
+            .flatten()
+            .map(String::as_str)
+            .collect();
+        (!ids.is_empty()).then(|| ids.join(" "))
+    }
+}
+
+/// The context of the enclosing `Field`, if any.
+pub fn use_field() -> Option<FieldContext> {
+    try_use_context::<Signal<FieldContext>>().map(|field| field.read().clone())
+}
+
+/// Attributes for a form control, combining its own props with the enclosing `Field`.
+/// Explicit props win for the id; `required` and `invalid` are set by either. The
+/// description of an enclosing `Tooltip` joins the field's hint and error.
+pub(crate) struct FieldControl {
+    pub id: Option<String>,
+    pub required: bool,
+    pub invalid: bool,
+    pub describedby: Option<String>,
+}
+
+impl FieldControl {
+    pub fn state(&self) -> InputState {
+        if self.invalid {
+            InputState::Invalid
+        } else {
+            InputState::Default
+        }
+    }
+}
+
+pub(crate) fn use_field_control(
+    id: Option<&String>,
+    required: bool,
+    invalid: bool,
+) -> FieldControl {
+    let field = use_field();
+    let describedby: Vec<String> = [
+        field.as_ref().and_then(FieldContext::describedby),
+        use_tooltip_description(),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+    FieldControl {
+        id: id.cloned().or_else(|| field.as_ref().map(|f| f.id.clone())),
+        required: required || field.as_ref().is_some_and(|f| f.required),
+        invalid: invalid || field.as_ref().is_some_and(|f| f.invalid),
+        describedby: (!describedby.is_empty()).then(|| describedby.join(" ")),
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct FieldProps {
+    #[props(default)]
+    pub label: Option<String>,
+    /// Help text shown below the control
+    #[props(default)]
+    pub hint: Option<String>,
+    /// Validation message; marks the control invalid
+    #[props(default)]
+    pub error: Option<String>,
+    #[props(default = false)]
+    pub required: bool,
+    /// Id for the control; generated when not given
+    #[props(default)]
+    pub id: Option<String>,
+    #[props(default)]
+    pub class: Option<String>,
+    /// The control, e.g. an `Input`, `Select`, `Checkbox` or `RadioGroup`
+    children: Element,
+}
+
+/// Labels a form control and shows its hint and error, wiring up `id`, `aria-invalid`,
+/// `aria-describedby` and `aria-required` on the control automatically.
+#[component]
+pub fn Field(props: FieldProps) -> Element {
+    let theme = use_theme().input;
+    let generated_id = use_unique_id("field");
+    let id = props.id.clone().unwrap_or(generated_id);
+
+    let error = props.error.clone().filter(|e| !e.is_empty());
+    let hint = props.hint.clone();
+    let context = FieldContext {
+        hint_id: hint.as_ref().map(|_| format!("{}-hint", id)),
+        error_id: error.as_ref().map(|_| format!("{}-error", id)),
+        required: props.required,
+        invalid: error.is_some(),
+        id: id.clone(),
+    };
+
+    let mut field = use_context_provider(|| Signal::new(context.clone()));
+    let current = context.clone();
+    use_effect(use_reactive!(|(current)| {
+        if *field.peek() != current {
+            field.set(current);
+        }
+    }));
+
+    let additional_classes = props.class.as_deref().unwrap_or("");
+    let class_string = merge_classes(&["flex flex-col", additional_classes]);
+
+    rsx! {
+        div {
+            class: "{class_string}",
+            if let Some(label) = &props.label {
+                Label {
+                    for_input: id.clone(),
+                    required: props.required,
+                    "{label}"
+                }
+            }
+            {props.children}
+            if let (Some(hint), Some(hint_id)) = (&hint, &context.hint_id) {
+                p { id: "{hint_id}", class: theme.hint, "{hint}" }
+            }
+            if let (Some(error), Some(error_id)) = (&error, &context.error_id) {
+                p { id: "{error_id}", class: theme.error, role: "alert", "{error}" }
+            }
+        }
+    }
+}
@@ -1,5 +1,25 @@
 use dioxus::prelude::*;
 
+use crate::{InputTheme, merge_classes, use_field_control, use_theme};
+
+crate::class_variants! {
+    /// Class recipe for `Input`.
+    pub InputClasses(theme: &InputTheme) {
+        base: theme.input,
+        variants: {
+            size: InputSize = Medium {
+                Small => "px-2.5 py-1.5 text-sm",
+                Medium => "",
+                Large => "px-4 py-3 text-lg",
+            },
+            state: InputState = Default {
+                Default => "",
+                Invalid => theme.input_invalid,
+            },
+        },
+    }
+}
+
 #[derive(Clone, PartialEq)]
 pub enum InputType {
     Text,
@@ -28,6 +48,8 @@ pub struct InputProps {
     #[props(default = InputType::Text)]
     pub input_type: InputType,
     #[props(default)]
+    pub size: InputSize,
+    #[props(default)]
     pub placeholder: Option<String>,
     #[props(default)]
     pub value: Option<String>,
@@ -35,11 +57,16 @@ pub struct InputProps {
     pub disabled: bool,
     #[props(default = false)]
     pub required: bool,
+    /// Error styling and `aria-invalid`; set automatically inside a `Field` with an error
+    #[props(default = false)]
+    pub invalid: bool,
     #[props(default)]
     pub oninput: Option<EventHandler<FormEvent>>,
     #[props(default)]
     pub onchange: Option<EventHandler<FormEvent>>,
     #[props(default)]
+    pub onblur: Option<EventHandler<FocusEvent>>,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub id: Option<String>,
@@ -60,9 +87,14 @@ pub struct LabelProps {
 
 #[component]
 pub fn Input(props: InputProps) -> Element {
-    let base_classes = "w-full px-3 py-2 border border-gray-300 dark:border-gray-600 rounded-lg focus:outline-none focus:ring-2 focus:ring-blue-500 focus:border-transparent bg-white dark:bg-gray-800 text-gray-900 dark:text-gray-100 disabled:opacity-50 disabled:cursor-not-allowed";
+    let theme = use_theme().input;
+    let control = use_field_control(props.id.as_ref(), props.required, props.invalid);
     let additional_classes = props.class.as_deref().unwrap_or("");
-    let class_string = format!("{} {}", base_classes, additional_classes);
+    let class_string = InputClasses {
+        size: props.size,
+        state: control.state(),
+    }
+    .classes(&theme, additional_classes);
 
     rsx! {
         input {
@@ -71,9 +103,12 @@ pub fn Input(props: InputProps) -> Element {
             placeholder: props.placeholder.as_deref().unwrap_or(""),
             value: props.value.as_deref().unwrap_or(""),
             disabled: props.disabled,
-            required: props.required,
-            id: props.id.as_deref().unwrap_or(""),
+            required: control.required,
+            id: control.id.as_deref().unwrap_or(""),
             name: props.name.as_deref().unwrap_or(""),
+            "aria-invalid": control.invalid.then_some("true"),
+            "aria-required": control.required.then_some("true"),
+            "aria-describedby": control.describedby,
             oninput: move |evt| {
                 if let Some(handler) = &props.oninput {
                     handler.call(evt);
@@ -83,6 +118,11 @@ pub fn Input(props: InputProps) -> Element {
                 if let Some(handler) = &props.onchange {
                     handler.call(evt);
                 }
+            },
+            onblur: move |evt| {
+                if let Some(handler) = &props.onblur {
+                    handler.call(evt);
+                }
             }
         }
     }
@@ -90,9 +130,10 @@ pub fn Input(props: InputProps) -> Element {
 
 #[component]
 pub fn Label(props: LabelProps) -> Element {
-    let base_classes = "block text-sm font-medium text-gray-700 dark:text-gray-300 mb-1";
+    let theme = use_theme().input;
+    let base_classes = theme.label;
     let additional_classes = props.class.as_deref().unwrap_or("");
-    let class_string = format!("{} {}", base_classes, additional_classes);
+    let class_string = merge_classes(&[base_classes, additional_classes]);
 
     rsx! {
         label {
@@ -101,7 +142,7 @@ pub fn Label(props: LabelProps) -> Element {
             {props.children}
             if props.required {
                 span {
-                    class: "text-red-500 ml-1",
+                    class: theme.required_marker,
                     "*"
                 }
             }
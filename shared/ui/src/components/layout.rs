@@ -1,5 +1,8 @@
 use dioxus::prelude::*;
 
+use super::sidebar::{DEFAULT_SIDEBAR_STORAGE_KEY, SidebarState, use_sidebar_provider};
+use crate::{merge_classes, use_theme};
+
 #[derive(Props, Clone, PartialEq)]
 pub struct LayoutProps {
     #[props(default)]
@@ -16,6 +19,36 @@ pub struct HeaderProps {
     children: Element,
 }
 
+#[derive(Props, Clone, PartialEq)]
+pub struct FooterProps {
+    #[props(default)]
+    pub class: Option<String>,
+    children: Element,
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct AppShellProps {
+    /// Usually a [`Header`], which gets a button opening the sidebar on small screens
+    pub header: Element,
+    /// Usually a [`Sidebar`](crate::Sidebar) of `NavItem`s and `NavGroup`s
+    pub sidebar: Element,
+    #[props(default)]
+    pub footer: Option<Element>,
+    /// Route that nav items are highlighted against, e.g. from a router; follows the
+    /// browser's location (including history and hash changes) when unset
+    #[props(default)]
+    pub current_path: Option<String>,
+    /// Whether the sidebar starts collapsed before a stored choice is loaded
+    #[props(default = false)]
+    pub default_collapsed: bool,
+    /// localStorage key holding whether the sidebar is collapsed
+    #[props(default = DEFAULT_SIDEBAR_STORAGE_KEY)]
+    pub storage_key: &'static str,
+    #[props(default)]
+    pub class: Option<String>,
+    children: Element,
+}
+
 #[derive(Props, Clone, PartialEq)]
 pub struct MainProps {
     #[props(default)]
@@ -25,9 +58,9 @@ pub struct MainProps {
 
 #[component]
 pub fn Layout(props: LayoutProps) -> Element {
-    let base_classes = "min-h-screen bg-gray-50 dark:bg-gray-900 flex flex-col";
+    let base_classes = use_theme().layout.layout;
     let additional_classes = props.class.as_deref().unwrap_or("");
-    let class_string = format!("{} {}", base_classes, additional_classes);
+    let class_string = merge_classes(&[base_classes, additional_classes]);
 
     rsx! {
         div {
@@ -37,24 +70,71 @@ pub fn Layout(props: LayoutProps) -> Element {
     }
 }
 
+/// A layout with a header, a [`Sidebar`](crate::Sidebar) beside the content and an
+/// optional footer. Whether the sidebar is collapsed is remembered in localStorage; see
+/// [`use_sidebar`](crate::use_sidebar) for the shared state.
+#[component]
+pub fn AppShell(props: AppShellProps) -> Element {
+    use_sidebar_provider(
+        props.default_collapsed,
+        props.storage_key,
+        props.current_path.clone(),
+    );
+    let base_classes = use_theme().layout.layout;
+    let additional_classes = props.class.as_deref().unwrap_or("");
+    let class_string = merge_classes(&[base_classes, additional_classes]);
+
+    rsx! {
+        div {
+            class: "{class_string}",
+            {props.header}
+            div {
+                class: "flex flex-1",
+                {props.sidebar}
+                div {
+                    class: "flex min-w-0 flex-1 flex-col",
+                    {props.children}
+                    {props.footer}
+                }
+            }
+        }
+    }
+}
+
+/// Page header with an optional title. Inside an [`AppShell`] it spans the full width and
+/// shows a button opening the sidebar on small screens.
 #[component]
 pub fn Header(props: HeaderProps) -> Element {
-    let base_classes =
-        "bg-white dark:bg-gray-800 shadow-sm border-b border-gray-200 dark:border-gray-700";
+    let theme = use_theme().layout;
+    let sidebar = try_use_context::<SidebarState>();
+    let base_classes = theme.header;
     let additional_classes = props.class.as_deref().unwrap_or("");
-    let class_string = format!("{} {}", base_classes, additional_classes);
+    let class_string = merge_classes(&[base_classes, additional_classes]);
+    let container_classes = if sidebar.is_some() {
+        "px-4 sm:px-6 lg:px-8"
+    } else {
+        "max-w-7xl mx-auto px-4 sm:px-6 lg:px-8"
+    };
 
     rsx! {
         header {
             class: "{class_string}",
             div {
-                class: "max-w-7xl mx-auto px-4 sm:px-6 lg:px-8",
+                class: container_classes,
                 div {
                     class: "flex justify-between items-center py-6",
-                    if let Some(title) = &props.title {
-                        h1 {
-                            class: "text-2xl font-bold text-gray-900 dark:text-white",
-                            "{title}"
+                    if sidebar.is_some() || props.title.is_some() {
+                        div {
+                            class: "flex items-center gap-3",
+                            if let Some(sidebar) = sidebar {
+                                MenuButton { sidebar }
+                            }
+                            if let Some(title) = &props.title {
+                                h1 {
+                                    class: theme.header_title,
+                                    "{title}"
+                                }
+                            }
                         }
                     }
                     {props.children}
@@ -64,11 +144,41 @@ pub fn Header(props: HeaderProps) -> Element {
     }
 }
 
+#[component]
+fn MenuButton(sidebar: SidebarState) -> Element {
+    let theme = use_theme().layout;
+    let mut sidebar = sidebar;
+    let open = sidebar.mobile_open();
+    let class_string = merge_classes(&[theme.menu_button, "lg:hidden"]);
+
+    rsx! {
+        button {
+            r#type: "button",
+            id: sidebar.toggle_id(),
+            class: "{class_string}",
+            "aria-label": "Open navigation",
+            "aria-expanded": "{open}",
+            "aria-controls": sidebar.drawer_id(),
+            onclick: move |_| sidebar.set_mobile_open(!open),
+            svg {
+                class: "h-6 w-6",
+                xmlns: "http://www.w3.org/2000/svg",
+                fill: "none",
+                view_box: "0 0 24 24",
+                stroke: "currentColor",
+                stroke_width: "1.5",
+                "aria-hidden": "true",
+                path { stroke_linecap: "round", stroke_linejoin: "round", d: "M3.75 6.75h16.5M3.75 12h16.5m-16.5 5.25h16.5" }
+            }
+        }
+    }
+}
+
 #[component]
 pub fn Main(props: MainProps) -> Element {
-    let base_classes = "flex-1 max-w-7xl mx-auto px-4 sm:px-6 lg:px-8 py-8";
+    let base_classes = use_theme().layout.main;
     let additional_classes = props.class.as_deref().unwrap_or("");
-    let class_string = format!("{} {}", base_classes, additional_classes);
+    let class_string = merge_classes(&[base_classes, additional_classes]);
 
     rsx! {
         main {
@@ -77,3 +187,20 @@ pub fn Main(props: MainProps) -> Element {
         }
     }
 }
+
+#[component]
+pub fn Footer(props: FooterProps) -> Element {
+    let base_classes = use_theme().layout.footer;
+    let additional_classes = props.class.as_deref().unwrap_or("");
+    let class_string = merge_classes(&[base_classes, additional_classes]);
+
+    rsx! {
+        footer {
+            class: "{class_string}",
+            div {
+                class: "max-w-7xl mx-auto px-4 sm:px-6 lg:px-8 py-6",
+                {props.children}
+            }
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use dioxus::document;
+use dioxus::prelude::*;
+
+use crate::{
+    InputClasses, InputSize, Mask, MaskedValue, use_field_control, use_theme, use_unique_id,
+};
+
+#[derive(Props, Clone, PartialEq)]
+pub struct MaskedInputProps {
+    pub mask: Mask,
+    /// Raw value (see [`MaskedValue::raw`]); formatted for display
+    #[props(default)]
+    pub value: Option<String>,
+    /// Called on every edit with the raw and formatted value
+    #[props(default)]
+    pub onchange: Option<EventHandler<MaskedValue>>,
+    #[props(default)]
+    pub onblur: Option<EventHandler<FocusEvent>>,
+    #[props(default)]
+    pub size: InputSize,
+    #[props(default)]
+    pub placeholder: Option<String>,
+    #[props(default = false)]
+    pub disabled: bool,
+    #[props(default = false)]
+    pub required: bool,
+    #[props(default = false)]
+    pub invalid: bool,
+    #[props(default)]
+    pub class: Option<String>,
+    #[props(default)]
+    pub id: Option<String>,
+    /// Submits the raw value under this name
+    #[props(default)]
+    pub name: Option<String>,
+}
+
+/// A text input that formats as the user types, e.g. phone numbers, dates or amounts.
+#[component]
+pub fn MaskedInput(props: MaskedInputProps) -> Element {
+    let theme = use_theme().input;
+    let control = use_field_control(props.id.as_ref(), props.required, props.invalid);
+    let generated_id = use_unique_id("masked-input");
+    let id = control.id.clone().unwrap_or(generated_id);
+
+    let mut current = use_signal(|| props.mask.format(props.value.as_deref().unwrap_or("")));
+    // Caret to restore once the reformatted value has been rendered.
+    let mut pending_cursor = use_signal(|| None::<usize>);
+    // Bumped on every input so results of earlier, slower reads are dropped.
+    let mut input_generation = use_hook(|| CopyValue::new(0u64));
+
+    // Follow value changes made by the parent, e.g. a form reset. Comparing raw values
+    // keeps partial input such as a trailing decimal separator while typing.
+    let value = props.value.clone().unwrap_or_default();
+    let format_mask = props.mask.clone();
+    use_effect(use_reactive!(|(value)| {
+        if current.peek().raw != value {
+            current.set(format_mask.format(&value));
+        }
+    }));
+    let mask = props.mask.clone();
+    use_effect(use_reactive!(|(mask)| {
+        let formatted = mask.format(&current.peek().raw);
+        if *current.peek() != formatted {
+            current.set(formatted);
+        }
+    }));
+
+    let cursor_id = id.clone();
+    use_effect(move || {
+        if let Some(cursor) = pending_cursor() {
+            pending_cursor.set(None);
+            // A rejected keystroke leaves `formatted` unchanged, so no `value` update is
+            // rendered and the typed character would stay in the DOM: write it back here.
+            let formatted = current.peek().formatted.clone();
+            document::eval(&format!(
+                "const el = document.getElementById({cursor_id:?}); \
+                 if (el) {{ \
+                     if (el.value !== {formatted:?}) el.value = {formatted:?}; \
+                     if (document.activeElement === el) el.setSelectionRange({cursor}, {cursor}); \
+                 }}"
+            ));
+        }
+    });
+
+    let additional_classes = props.class.as_deref().unwrap_or("");
+    let class_string = InputClasses {
+        size: props.size,
+        state: control.state(),
+    }
+    .classes(&theme, additional_classes);
+
+    let input_id = id.clone();
+    rsx! {
+        input {
+            r#type: "text",
+            id: "{id}",
+            class: "{class_string}",
+            inputmode: props.mask.input_mode(),
+            autocomplete: "off",
+            placeholder: props.placeholder.as_deref().unwrap_or(""),
+            value: "{current.read().formatted}",
+            disabled: props.disabled,
+            required: control.required,
+            "aria-invalid": control.invalid.then_some("true"),
+            "aria-required": control.required.then_some("true"),
+            "aria-describedby": control.describedby.clone(),
+            oninput: move |evt: FormEvent| {
+                let generation = *input_generation.peek() + 1;
+                input_generation.set(generation);
+                // The caret is only available from the DOM, so read it together with the
+                // value it belongs to. Typing may continue before the read resolves.
+                let read = document::eval(&format!(
+                    "const el = document.getElementById({:?}); \
+                     return el ? [el.value, el.selectionStart] : null;",
+                    input_id
+                ));
+                let typed = evt.value();
+                let mask = props.mask.clone();
+                let onchange = props.onchange;
+                spawn(async move {
+                    let read = read.await.ok();
+                    if *input_generation.peek() != generation {
+                        return;
+                    }
+                    let (typed, caret) = match read.as_ref().and_then(|v| v.as_array()) {
+                        Some(pair) => (
+                            pair.first().and_then(|v| v.as_str()).map_or(typed, str::to_string),
+                            pair.get(1).and_then(|c| c.as_u64()),
+                        ),
+                        None => (typed, None),
+                    };
+                    let cursor = caret.map_or(typed.encode_utf16().count(), |c| c as usize);
+                    let masked = mask.apply(&typed, cursor);
+                    pending_cursor.set(Some(masked.cursor));
+                    current.set(masked.clone());
+                    if let Some(handler) = onchange {
+                        handler.call(masked);
+                    }
+                });
+            },
+            onblur: move |evt| {
+                if let Some(handler) = &props.onblur {
+                    handler.call(evt);
+                }
+            }
+        }
+        if let Some(name) = &props.name {
+            input { r#type: "hidden", name: "{name}", value: "{current.read().raw}" }
+        }
+    }
+}
@@ -0,0 +1,668 @@
+use dioxus::document;
+use dioxus::prelude::*;
+
+use crate::{
+    ButtonClasses, ButtonShape, ButtonSize, ButtonVariant, Placement, Popover, PopoverCloseReason,
+    merge_classes, sleep, use_theme, use_unique_id,
+};
+
+/// Typed characters are combined into one search until the user pauses this long.
+const TYPEAHEAD_RESET_MS: u32 = 500;
+
+#[derive(Clone, PartialEq)]
+pub enum MenuItemKind {
+    Action,
+    /// Toggles without closing the menu, so several can be changed in a row
+    Checkbox {
+        checked: bool,
+    },
+    Submenu(Vec<MenuItem>),
+    Separator,
+    /// Non-interactive heading for the items below it
+    Label,
+}
+
+/// One entry of a `DropdownMenu` or `ContextMenu`.
+#[derive(Clone, PartialEq)]
+pub struct MenuItem {
+    /// Passed to `onselect` when the item is chosen
+    pub id: String,
+    pub label: String,
+    pub kind: MenuItemKind,
+    /// Hint such as `Ctrl+E`; the menu does not bind it
+    pub shortcut: Option<String>,
+    pub disabled: bool,
+    pub destructive: bool,
+}
+
+impl MenuItem {
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            kind: MenuItemKind::Action,
+            shortcut: None,
+            disabled: false,
+            destructive: false,
+        }
+    }
+
+    pub fn checkbox(id: impl Into<String>, label: impl Into<String>, checked: bool) -> Self {
+        Self {
+            kind: MenuItemKind::Checkbox { checked },
+            ..Self::new(id, label)
+        }
+    }
+
+    pub fn submenu(label: impl Into<String>, items: Vec<MenuItem>) -> Self {
+        let label = label.into();
+        Self {
+            kind: MenuItemKind::Submenu(items),
+            ..Self::new(label.clone(), label)
+        }
+    }
+
+    pub fn separator() -> Self {
+        Self {
+            kind: MenuItemKind::Separator,
+            ..Self::new("", "")
+        }
+    }
+
+    pub fn label(text: impl Into<String>) -> Self {
+        Self {
+            kind: MenuItemKind::Label,
+            ..Self::new("", text)
+        }
+    }
+
+    pub fn shortcut(mut self, shortcut: impl Into<String>) -> Self {
+        self.shortcut = Some(shortcut.into());
+        self
+    }
+
+    pub fn disabled(mut self) -> Self {
+        self.disabled = true;
+        self
+    }
+
+    pub fn destructive(mut self) -> Self {
+        self.destructive = true;
+        self
+    }
+
+    /// Whether arrow keys and typeahead can land on this item. Disabled items stay
+    /// focusable so screen reader users can discover them.
+    fn is_focusable(&self) -> bool {
+        !matches!(self.kind, MenuItemKind::Separator | MenuItemKind::Label)
+    }
+}
+
+/// The next focusable item `step` places away from `from`, wrapping around.
+fn move_focus(items: &[MenuItem], from: Option<usize>, step: isize) -> Option<usize> {
+    let len = items.len() as isize;
+    if len == 0 {
+        return None;
+    }
+    let start = match from {
+        Some(index) => index as isize,
+        None if step > 0 => -1,
+        None => len,
+    };
+    (1..=len)
+        .map(|offset| (start + offset * step).rem_euclid(len) as usize)
+        .find(|&index| items[index].is_focusable())
+}
+
+fn first_focusable(items: &[MenuItem]) -> Option<usize> {
+    items.iter().position(MenuItem::is_focusable)
+}
+
+fn last_focusable(items: &[MenuItem]) -> Option<usize> {
+    items.iter().rposition(MenuItem::is_focusable)
+}
+
+/// The first item after `from` whose label starts with `query`, wrapping around. A
+/// repeated single character (`"ff"`) cycles through items starting with it.
+fn typeahead(items: &[MenuItem], from: Option<usize>, query: &str) -> Option<usize> {
+    let query = query.to_lowercase();
+    let mut chars = query.chars();
+    let first = chars.next()?;
+    let cycling = chars.all(|c| c == first);
+    let needle = if cycling {
+        first.to_string()
+    } else {
+        query.clone()
+    };
+
+    let len = items.len();
+    // A longer query keeps matching the current item while it still fits.
+    let skip_current = if cycling { 1 } else { 0 };
+    let start = from.map_or(0, |index| index + skip_current);
+    (0..len)
+        .map(|offset| (start + offset) % len)
+        .find(|&index| {
+            items[index].is_focusable() && items[index].label.to_lowercase().starts_with(&needle)
+        })
+}
+
+fn focus_script(element_id: &str) -> String {
+    format!(r#"document.getElementById({element_id:?})?.focus({{ preventScroll: true }});"#)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum MenuFocus {
+    #[default]
+    None,
+    First,
+    Last,
+}
+
+#[component]
+fn MenuList(
+    id: String,
+    items: Vec<MenuItem>,
+    initial_focus: MenuFocus,
+    labelled_by: Option<String>,
+    /// Names the menu when no element labels it
+    label: Option<String>,
+    onselect: EventHandler<String>,
+    /// Close the whole menu tree
+    ondismiss: EventHandler<()>,
+    /// Close this submenu and go back to its parent item
+    onback: Option<EventHandler<()>>,
+) -> Element {
+    let theme = use_theme().menu;
+    let menu_id = use_hook(|| CopyValue::new(id.clone()));
+    let mut items_ref = use_hook(|| CopyValue::new(items.clone()));
+    items_ref.set(items.clone());
+
+    let mut active = use_signal(|| None::<usize>);
+    let mut open_submenu = use_signal(|| None::<usize>);
+    let mut submenu_focus = use_signal(MenuFocus::default);
+    let mut query = use_signal(String::new);
+    let mut query_generation = use_signal(|| 0_u32);
+
+    let item_id = move |index: usize| format!("{}-item-{index}", menu_id.read());
+
+    use_effect(use_reactive!(|(initial_focus)| {
+        let target = match initial_focus {
+            MenuFocus::First => first_focusable(&items_ref.read()),
+            MenuFocus::Last => last_focusable(&items_ref.read()),
+            MenuFocus::None => None,
+        };
+        if target.is_some() {
+            active.set(target);
+        }
+    }));
+
+    use_effect(move || {
+        if let Some(index) = active() {
+            document::eval(&focus_script(&item_id(index)));
+        }
+    });
+
+    let mut close_submenu = move |refocus: bool| {
+        open_submenu.set(None);
+        submenu_focus.set(MenuFocus::None);
+        if let (true, Some(index)) = (refocus, *active.peek()) {
+            document::eval(&focus_script(&item_id(index)));
+        }
+    };
+
+    let mut activate = move |index: usize| {
+        let item = items_ref.read()[index].clone();
+        if item.disabled {
+            return;
+        }
+        match item.kind {
+            MenuItemKind::Action => {
+                onselect.call(item.id);
+                ondismiss.call(());
+            }
+            MenuItemKind::Checkbox { .. } => onselect.call(item.id),
+            MenuItemKind::Submenu(_) => {
+                submenu_focus.set(MenuFocus::First);
+                open_submenu.set(Some(index));
+            }
+            MenuItemKind::Separator | MenuItemKind::Label => {}
+        }
+    };
+
+    let onkeydown = move |evt: KeyboardEvent| {
+        // Submenus live in portals but still bubble through this menu in the tree.
+        evt.stop_propagation();
+        let items = items_ref.read().clone();
+        let current = active();
+        let next = match evt.key() {
+            Key::ArrowDown => move_focus(&items, current, 1),
+            Key::ArrowUp => move_focus(&items, current, -1),
+            Key::Home => first_focusable(&items),
+            Key::End => last_focusable(&items),
+            Key::ArrowRight => {
+                if let Some(index) = current {
+                    if matches!(items[index].kind, MenuItemKind::Submenu(_)) {
+                        activate(index);
+                    }
+                }
+                None
+            }
+            Key::ArrowLeft => {
+                if let Some(back) = &onback {
+                    back.call(());
+                }
+                None
+            }
+            Key::Enter => {
+                if let Some(index) = current {
+                    activate(index);
+                }
+                None
+            }
+            Key::Tab => {
+                ondismiss.call(());
+                None
+            }
+            Key::Character(c) if c == " " => {
+                if let Some(index) = current {
+                    activate(index);
+                }
+                None
+            }
+            Key::Character(c)
+                if !evt
+                    .modifiers()
+                    .intersects(Modifiers::CONTROL | Modifiers::META | Modifiers::ALT) =>
+            {
+                query.write().push_str(&c);
+                query_generation += 1;
+                let generation = query_generation();
+                spawn(async move {
+                    sleep(TYPEAHEAD_RESET_MS).await;
+                    if *query_generation.peek() == generation {
+                        query.set(String::new());
+                    }
+                });
+                typeahead(&items, current, &query.read())
+            }
+            _ => return,
+        };
+        evt.prevent_default();
+        if next.is_some() {
+            active.set(next);
+        }
+    };
+
+    let entries = items.iter().enumerate().map(|(index, item)| {
+        let id = item_id(index);
+        let class_string = merge_classes(&[theme.item, if item.destructive { theme.destructive } else { "" }]);
+        let tabindex = if active() == Some(index) { "0" } else { "-1" };
+        let is_submenu = matches!(item.kind, MenuItemKind::Submenu(_));
+        let disabled = item.disabled;
+        let onmouseenter = move |_| {
+            active.set(Some(index));
+            if is_submenu && !disabled {
+                if *open_submenu.peek() != Some(index) {
+                    submenu_focus.set(MenuFocus::None);
+                    open_submenu.set(Some(index));
+                }
+            } else if open_submenu.peek().is_some() {
+                close_submenu(false);
+            }
+        };
+
+        let content = rsx! {
+            if let MenuItemKind::Checkbox { checked } = item.kind {
+                span {
+                    class: "inline-flex h-4 w-4 shrink-0",
+                    "aria-hidden": "true",
+                    if checked {
+                        svg {
+                            class: "h-4 w-4",
+                            xmlns: "http://www.w3.org/2000/svg",
+                            fill: "none",
+                            view_box: "0 0 24 24",
+                            stroke: "currentColor",
+                            stroke_width: "2",
+                            path { stroke_linecap: "round", stroke_linejoin: "round", d: "m4.5 12.75 6 6 9-13.5" }
+                        }
+                    }
+                }
+            }
+            span { class: "flex-1 truncate text-left", "{item.label}" }
+            if let Some(shortcut) = &item.shortcut {
+                span { class: theme.shortcut, "{shortcut}" }
+            }
+            if is_submenu {
+                svg {
+                    class: "h-4 w-4 shrink-0",
+                    xmlns: "http://www.w3.org/2000/svg",
+                    fill: "none",
+                    view_box: "0 0 24 24",
+                    stroke: "currentColor",
+                    stroke_width: "2",
+                    "aria-hidden": "true",
+                    path { stroke_linecap: "round", stroke_linejoin: "round", d: "m8.25 4.5 7.5 7.5-7.5 7.5" }
+                }
+            }
+        };
+
+        match &item.kind {
+            MenuItemKind::Separator => rsx! {
+                div { key: "{id}", role: "separator", class: theme.separator }
+            },
+            MenuItemKind::Label => rsx! {
+                div { key: "{id}", role: "presentation", class: theme.label, "{item.label}" }
+            },
+            MenuItemKind::Action | MenuItemKind::Checkbox { .. } => {
+                let checked = match item.kind {
+                    MenuItemKind::Checkbox { checked } => Some(checked.to_string()),
+                    _ => None,
+                };
+                rsx! {
+                    div {
+                        key: "{id}",
+                        id: "{id}",
+                        role: if checked.is_some() { "menuitemcheckbox" } else { "menuitem" },
+                        class: "{class_string}",
+                        tabindex,
+                        "aria-checked": checked,
+                        "aria-disabled": item.disabled.then_some("true"),
+                        onclick: move |_| activate(index),
+                        onmouseenter,
+                        {content}
+                    }
+                }
+            }
+            MenuItemKind::Submenu(children) => rsx! {
+                Popover {
+                    key: "{id}",
+                    open: open_submenu() == Some(index),
+                    onclose: move |reason| close_submenu(reason == PopoverCloseReason::Escape),
+                    placement: Placement::RIGHT_START,
+                    offset: 0.0,
+                    anchor_class: "flex w-full",
+                    anchor: rsx! {
+                        div {
+                            id: "{id}",
+                            role: "menuitem",
+                            class: "{class_string}",
+                            tabindex,
+                            "aria-haspopup": "menu",
+                            "aria-expanded": "{open_submenu() == Some(index)}",
+                            "aria-disabled": item.disabled.then_some("true"),
+                            onclick: move |_| activate(index),
+                            onmouseenter,
+                            {content}
+                        }
+                    },
+                    MenuList {
+                        id: "{id}-menu",
+                        items: children.clone(),
+                        initial_focus: submenu_focus(),
+                        labelled_by: id.clone(),
+                        onselect,
+                        ondismiss,
+                        onback: move |_| close_submenu(true),
+                    }
+                }
+            },
+        }
+    });
+
+    rsx! {
+        div {
+            id: "{id}",
+            role: "menu",
+            class: theme.menu,
+            "aria-orientation": "vertical",
+            "aria-labelledby": labelled_by,
+            "aria-label": label,
+            onkeydown,
+            {entries}
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct DropdownMenuProps {
+    pub items: Vec<MenuItem>,
+    /// Called with the id of the chosen item
+    #[props(default)]
+    pub onselect: Option<EventHandler<String>>,
+    #[props(default)]
+    pub placement: Placement,
+    #[props(default)]
+    pub variant: ButtonVariant,
+    #[props(default)]
+    pub size: ButtonSize,
+    /// Makes the trigger a square button showing only the children, e.g. an icon, and
+    /// names it for screen readers
+    #[props(default)]
+    pub icon_label: Option<String>,
+    #[props(default = false)]
+    pub disabled: bool,
+    /// Classes for the trigger button
+    #[props(default)]
+    pub class: Option<String>,
+    /// Content of the trigger button
+    children: Element,
+}
+
+/// A button opening a menu of [`MenuItem`]s, following the WAI-ARIA menu button
+/// pattern: arrow keys, Home/End and typeahead move between items, Right and Left
+/// open and close submenus, and Escape closes the menu and focuses the button again.
+#[component]
+pub fn DropdownMenu(props: DropdownMenuProps) -> Element {
+    let theme = use_theme().button;
+    let trigger_id = use_unique_id("menu-trigger");
+    let menu_id = use_unique_id("menu");
+    let mut open = use_signal(|| false);
+    let mut focus = use_signal(MenuFocus::default);
+    let mut show = move |first_focus: MenuFocus| {
+        focus.set(first_focus);
+        open.set(true);
+    };
+
+    let shape = if props.icon_label.is_some() {
+        ButtonShape::IconOnly
+    } else {
+        ButtonShape::Default
+    };
+    let class_string = ButtonClasses {
+        variant: props.variant,
+        size: props.size,
+        shape,
+    }
+    .classes(&theme, props.class.as_deref().unwrap_or(""));
+
+    let onselect = props.onselect;
+    let escape_trigger_id = trigger_id.clone();
+    let dismiss_trigger_id = trigger_id.clone();
+
+    rsx! {
+        Popover {
+            open: open(),
+            onclose: move |reason| {
+                open.set(false);
+                if reason == PopoverCloseReason::Escape {
+                    document::eval(&focus_script(&escape_trigger_id));
+                }
+            },
+            placement: props.placement,
+            anchor: rsx! {
+                button {
+                    id: "{trigger_id}",
+                    r#type: "button",
+                    class: "{class_string}",
+                    disabled: props.disabled,
+                    "aria-haspopup": "menu",
+                    "aria-expanded": "{open}",
+                    "aria-controls": open().then(|| menu_id.clone()),
+                    "aria-label": props.icon_label.clone(),
+                    onclick: move |_| {
+                        if open() {
+                            open.set(false);
+                        } else {
+                            show(MenuFocus::First);
+                        }
+                    },
+                    onkeydown: move |evt: KeyboardEvent| match evt.key() {
+                        Key::ArrowDown => {
+                            evt.prevent_default();
+                            show(MenuFocus::First);
+                        }
+                        Key::ArrowUp => {
+                            evt.prevent_default();
+                            show(MenuFocus::Last);
+                        }
+                        _ => {}
+                    },
+                    {props.children}
+                    if props.icon_label.is_none() {
+                        svg {
+                            class: "h-4 w-4 shrink-0",
+                            xmlns: "http://www.w3.org/2000/svg",
+                            fill: "none",
+                            view_box: "0 0 24 24",
+                            stroke: "currentColor",
+                            stroke_width: "2",
+                            "aria-hidden": "true",
+                            path { stroke_linecap: "round", stroke_linejoin: "round", d: "m19.5 8.25-7.5 7.5-7.5-7.5" }
+                        }
+                    }
+                }
+            },
+            MenuList {
+                id: "{menu_id}",
+                items: props.items.clone(),
+                initial_focus: focus(),
+                labelled_by: trigger_id.clone(),
+                onselect: move |id| {
+                    if let Some(handler) = &onselect {
+                        handler.call(id);
+                    }
+                },
+                ondismiss: move |_| {
+                    open.set(false);
+                    document::eval(&focus_script(&dismiss_trigger_id));
+                },
+            }
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct ContextMenuProps {
+    pub items: Vec<MenuItem>,
+    /// Called with the id of the chosen item
+    #[props(default)]
+    pub onselect: Option<EventHandler<String>>,
+    /// Names the menu for screen readers, e.g. "File actions"
+    #[props(default)]
+    pub aria_label: Option<String>,
+    /// Leave the browser's own context menu in place
+    #[props(default = false)]
+    pub disabled: bool,
+    /// Classes for the region that reacts to right-clicks
+    #[props(default)]
+    pub class: Option<String>,
+    children: Element,
+}
+
+/// Opens a menu of [`MenuItem`]s at the pointer when its children are right-clicked
+/// (or the context menu key is pressed), with the same keyboard support as `DropdownMenu`.
+#[component]
+pub fn ContextMenu(props: ContextMenuProps) -> Element {
+    let menu_id = use_unique_id("context-menu");
+    let mut point = use_signal(|| None::<(f64, f64)>);
+    let disabled = props.disabled;
+    let onselect = props.onselect;
+    let region_class = merge_classes(&["block", props.class.as_deref().unwrap_or("")]);
+
+    rsx! {
+        Popover {
+            open: point().is_some(),
+            onclose: move |_| point.set(None),
+            anchor_point: point(),
+            anchor_class: region_class,
+            anchor: rsx! {
+                div {
+                    class: "contents",
+                    oncontextmenu: move |evt: MouseEvent| {
+                        if disabled {
+                            return;
+                        }
+                        evt.prevent_default();
+                        let at = evt.client_coordinates();
+                        point.set(Some((at.x, at.y)));
+                    },
+                    {props.children}
+                }
+            },
+            MenuList {
+                id: "{menu_id}",
+                items: props.items.clone(),
+                initial_focus: MenuFocus::First,
+                label: props.aria_label.clone(),
+                onselect: move |id| {
+                    if let Some(handler) = &onselect {
+                        handler.call(id);
+                    }
+                },
+                ondismiss: move |_| point.set(None),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn items() -> Vec<MenuItem> {
+        vec![
+            MenuItem::label("File"),
+            MenuItem::new("new", "New"),
+            MenuItem::new("open", "Open"),
+            MenuItem::separator(),
+            MenuItem::new("save", "Save").disabled(),
+            MenuItem::new("save-as", "Save as"),
+            MenuItem::submenu("Share", vec![MenuItem::new("email", "Email")]),
+        ]
+    }
+
+    #[test]
+    fn test_arrow_keys_skip_separators_and_labels_and_wrap() {
+        let items = items();
+        assert_eq!(move_focus(&items, None, 1), Some(1));
+        assert_eq!(move_focus(&items, None, -1), Some(6));
+        assert_eq!(move_focus(&items, Some(2), 1), Some(4));
+        assert_eq!(move_focus(&items, Some(6), 1), Some(1));
+        assert_eq!(move_focus(&items, Some(1), -1), Some(6));
+        assert_eq!(move_focus(&[], None, 1), None);
+        assert_eq!(move_focus(&[MenuItem::separator()], None, 1), None);
+    }
+
+    #[test]
+    fn test_home_end() {
+        let items = items();
+        assert_eq!(first_focusable(&items), Some(1));
+        assert_eq!(last_focusable(&items), Some(6));
+    }
+
+    #[test]
+    fn test_typeahead() {
+        let items = items();
+        assert_eq!(typeahead(&items, None, "o"), Some(2));
+        assert_eq!(typeahead(&items, Some(1), "SH"), Some(6));
+        // Repeating a character cycles through "Save", "Save as" and "Share".
+        assert_eq!(typeahead(&items, Some(4), "s"), Some(5));
+        assert_eq!(typeahead(&items, Some(5), "ss"), Some(6));
+        assert_eq!(typeahead(&items, Some(6), "sss"), Some(4));
+        // A longer query keeps the current item while it still matches.
+        assert_eq!(typeahead(&items, Some(5), "save "), Some(5));
+        assert_eq!(typeahead(&items, Some(1), "x"), None);
+        assert_eq!(typeahead(&items, Some(1), "file"), None);
+    }
+}
@@ -1,9 +1,49 @@
+pub mod accordion;
 pub mod button;
 pub mod card;
+pub mod checkbox;
+pub mod combobox;
+pub mod data_table;
+pub mod dialog;
+pub mod field;
 pub mod input;
 pub mod layout;
+pub mod masked_input;
+pub mod menu;
+pub mod popover;
+pub mod portal;
+pub mod radio_group;
+pub mod select;
+pub mod sidebar;
+pub mod spinner;
+pub mod switch;
+pub mod tabs;
+pub mod textarea;
+pub mod toast;
+pub mod tooltip;
+pub mod virtual_list;
 
+pub use accordion::*;
 pub use button::*;
 pub use card::*;
+pub use checkbox::*;
+pub use combobox::*;
+pub use data_table::*;
+pub use dialog::*;
+pub use field::*;
 pub use input::*;
 pub use layout::*;
+pub use masked_input::*;
+pub use menu::*;
+pub use popover::*;
+pub use portal::*;
+pub use radio_group::*;
+pub use select::*;
+pub use sidebar::*;
+pub use spinner::*;
+pub use switch::*;
+pub use tabs::*;
+pub use textarea::*;
+pub use toast::*;
+pub use tooltip::*;
+pub use virtual_list::*;
@@ -0,0 +1,299 @@
+use std::rc::Rc;
+
+use dioxus::document;
+use dioxus::html::geometry::PixelsRect;
+use dioxus::prelude::*;
+
+use crate::{
+    Placement, PlacementOptions, Portal, Position, Rect, compute_position, merge_classes,
+    use_theme, use_unique_id,
+};
+
+/// Listen for anything that should move or close the popover `panel_id` and report it
+/// through `dioxus.send` as `[kind, viewport width, viewport height]`.
+///
+/// Open popovers form a stack so only the innermost one reacts to Escape, and a click
+/// inside a nested popover (a submenu) does not count as outside its parents.
+fn listen_script(panel_id: &str, anchor_id: &str, close_on_outside_click: bool) -> String {
+    format!(
+        r#"const panelId = {panel_id:?};
+const anchorId = {anchor_id:?};
+const registry = window.__sharedUiPopovers = window.__sharedUiPopovers || {{ stack: [], cleanup: {{}} }};
+registry.stack.push(panelId);
+
+const report = (kind) => dioxus.send([kind, window.innerWidth, window.innerHeight]);
+let frame = 0;
+const reposition = () => {{
+    if (frame) return;
+    frame = requestAnimationFrame(() => {{
+        frame = 0;
+        report("reposition");
+    }});
+}};
+const contains = (id, target) => {{
+    const el = document.getElementById(id);
+    return el && el.contains(target);
+}};
+const onPointerDown = (event) => {{
+    const nested = registry.stack.slice(registry.stack.indexOf(panelId));
+    if (contains(anchorId, event.target) || nested.some((id) => contains(id, event.target))) return;
+    if ({close_on_outside_click}) report("outside");
+}};
+const onKeyDown = (event) => {{
+    if (event.key !== "Escape" || registry.stack[registry.stack.length - 1] !== panelId) return;
+    event.stopImmediatePropagation();
+    event.preventDefault();
+    report("escape");
+}};
+
+window.addEventListener("scroll", reposition, true);
+window.addEventListener("resize", reposition);
+document.addEventListener("pointerdown", onPointerDown, true);
+document.addEventListener("keydown", onKeyDown, true);
+const observer = typeof ResizeObserver === "undefined" ? null : new ResizeObserver(reposition);
+for (const id of [panelId, anchorId]) {{
+    const el = document.getElementById(id);
+    if (el && observer) observer.observe(el);
+}}
+
+registry.cleanup[panelId] = () => {{
+    window.removeEventListener("scroll", reposition, true);
+    window.removeEventListener("resize", reposition);
+    document.removeEventListener("pointerdown", onPointerDown, true);
+    document.removeEventListener("keydown", onKeyDown, true);
+    if (observer) observer.disconnect();
+    if (frame) cancelAnimationFrame(frame);
+    registry.stack = registry.stack.filter((id) => id !== panelId);
+    delete registry.cleanup[panelId];
+}};
+report("reposition");
+await new Promise(() => {{}});"#
+    )
+}
+
+fn cleanup_script(panel_id: &str) -> String {
+    format!(
+        r#"const registry = window.__sharedUiPopovers;
+if (registry && registry.cleanup[{panel_id:?}]) registry.cleanup[{panel_id:?}]();"#
+    )
+}
+
+fn to_rect(rect: PixelsRect) -> Rect {
+    Rect::new(
+        rect.origin.x,
+        rect.origin.y,
+        rect.size.width,
+        rect.size.height,
+    )
+}
+
+/// Why a popover asked to close.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PopoverCloseReason {
+    Escape,
+    OutsideClick,
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct PopoverProps {
+    pub open: bool,
+    /// Called on Escape or a click outside both the anchor and the popover
+    #[props(default)]
+    pub onclose: Option<EventHandler<PopoverCloseReason>>,
+    /// What the popover is positioned against, usually the button that opens it
+    pub anchor: Element,
+    /// Position against this viewport point instead of `anchor`, e.g. the cursor for a
+    /// context menu; clicks on `anchor` still count as inside
+    #[props(default)]
+    pub anchor_point: Option<(f64, f64)>,
+    /// Classes for the inline wrapper around `anchor`
+    #[props(default)]
+    pub anchor_class: Option<String>,
+    /// Preferred placement; flips to the other side when there is not enough room
+    #[props(default)]
+    pub placement: Placement,
+    #[props(default = 4.0)]
+    pub offset: f64,
+    /// Make the popover at least as wide as the anchor, e.g. for a listbox under an input
+    #[props(default = false)]
+    pub match_anchor_width: bool,
+    #[props(default = true)]
+    pub close_on_outside_click: bool,
+    /// Id of the popover element, for `aria-controls` on the trigger
+    #[props(default)]
+    pub id: Option<String>,
+    /// ARIA role of the popover element, e.g. `menu`, `listbox` or `dialog`
+    #[props(default)]
+    pub role: Option<String>,
+    #[props(default)]
+    pub aria_label: Option<String>,
+    /// Skip the theme's panel classes, for overlays with their own look such as tooltips
+    #[props(default = false)]
+    pub unstyled: bool,
+    #[props(default)]
+    pub class: Option<String>,
+    children: Element,
+}
+
+/// A floating panel positioned next to `anchor` and rendered in a [`Portal`]. It is
+/// measured through element refs and placed with [`compute_position`], and follows
+/// the anchor while the page scrolls or resizes.
+///
+/// The popover does not toggle itself: wire `open` to the trigger and `onclose` back
+/// to the same state, and set `aria-expanded` / `aria-controls` on the trigger.
+#[component]
+pub fn Popover(props: PopoverProps) -> Element {
+    let generated_anchor_id = use_unique_id("popover-anchor");
+    let anchor_ref = use_signal(|| None::<Rc<MountedData>>);
+    let mut anchor_ref_setter = anchor_ref;
+
+    let anchor_class = merge_classes(&["inline-flex", props.anchor_class.as_deref().unwrap_or("")]);
+
+    rsx! {
+        div {
+            id: "{generated_anchor_id}",
+            class: "{anchor_class}",
+            onmounted: move |evt: MountedEvent| anchor_ref_setter.set(Some(evt.data())),
+            {props.anchor.clone()}
+        }
+        if props.open {
+            Portal {
+                PopoverPanel {
+                    popover: props.clone(),
+                    anchor_id: generated_anchor_id.clone(),
+                    anchor_ref,
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn PopoverPanel(
+    popover: PopoverProps,
+    anchor_id: String,
+    anchor_ref: Signal<Option<Rc<MountedData>>>,
+) -> Element {
+    let theme = use_theme().popover;
+    let generated_id = use_unique_id("popover");
+    let id = popover.id.clone().unwrap_or(generated_id);
+    let mut position = use_signal(|| None::<(Position, f64)>);
+    let mut panel_ref = use_signal(|| None::<Rc<MountedData>>);
+    let mut viewport = use_signal(|| None::<Rect>);
+    let mut anchor_point = use_signal(|| popover.anchor_point);
+
+    let cleanup_id = id.clone();
+    use_drop(move || {
+        document::eval(&cleanup_script(&cleanup_id));
+    });
+
+    let options = use_hook(|| CopyValue::new(PlacementOptions::default()));
+    let mut options_setter = options;
+    options_setter.set(PlacementOptions {
+        placement: popover.placement,
+        offset: popover.offset,
+        ..PlacementOptions::default()
+    });
+
+    let place = move || {
+        spawn(async move {
+            let (Some(panel), Some(viewport)) = (panel_ref.peek().clone(), *viewport.peek()) else {
+                return;
+            };
+            let anchor_rect = match *anchor_point.peek() {
+                Some((x, y)) => Rect::new(x, y, 0.0, 0.0),
+                None => {
+                    let Some(anchor) = anchor_ref.peek().clone() else {
+                        return;
+                    };
+                    let Ok(rect) = anchor.get_client_rect().await else {
+                        return;
+                    };
+                    to_rect(rect)
+                }
+            };
+            let Ok(panel_rect) = panel.get_client_rect().await else {
+                return;
+            };
+            let panel_rect = to_rect(panel_rect);
+            let at = compute_position(
+                anchor_rect,
+                panel_rect.width,
+                panel_rect.height,
+                viewport,
+                &options(),
+            );
+            position.set(Some((at, anchor_rect.width)));
+        });
+    };
+
+    // A context menu opened again at another point moves without reopening.
+    use_effect(use_reactive!(|(popover)| {
+        if *anchor_point.peek() != popover.anchor_point {
+            anchor_point.set(popover.anchor_point);
+            place();
+        }
+    }));
+
+    let onclose = popover.onclose;
+    let close_on_outside_click = popover.close_on_outside_click;
+    let listen_id = id.clone();
+
+    let style = match position() {
+        Some((at, anchor_width)) => {
+            let min_width = if popover.match_anchor_width {
+                format!(" min-width: {anchor_width}px;")
+            } else {
+                String::new()
+            };
+            format!(
+                "position: fixed; left: {}px; top: {}px;{min_width}",
+                at.x, at.y
+            )
+        }
+        // Measured before it is shown so it never flashes in the wrong place; transparent
+        // rather than hidden so content can take focus right away.
+        None => "position: fixed; left: 0; top: 0; opacity: 0; pointer-events: none;".to_string(),
+    };
+    let side = position().map(|(at, _)| at.placement.side.as_str());
+    let base_classes = if popover.unstyled { "" } else { theme.panel };
+    let class_string = merge_classes(&[base_classes, popover.class.as_deref().unwrap_or("")]);
+
+    rsx! {
+        div {
+            id: "{id}",
+            class: "{class_string}",
+            style: "{style}",
+            role: popover.role.clone(),
+            "aria-label": popover.aria_label.clone(),
+            "data-side": side,
+            onmounted: move |evt: MountedEvent| {
+                panel_ref.set(Some(evt.data()));
+                let listen_id = listen_id.clone();
+                let anchor_id = anchor_id.clone();
+                async move {
+                    let mut events = document::eval(&listen_script(&listen_id, &anchor_id, close_on_outside_click));
+                    while let Ok((kind, width, height)) = events.recv::<(String, f64, f64)>().await {
+                        let reason = match kind.as_str() {
+                            "escape" => Some(PopoverCloseReason::Escape),
+                            "outside" => Some(PopoverCloseReason::OutsideClick),
+                            _ => None,
+                        };
+                        match reason {
+                            Some(reason) => {
+                                if let Some(handler) = &onclose {
+                                    handler.call(reason);
+                                }
+                            }
+                            None => {
+                                viewport.set(Some(Rect::new(0.0, 0.0, width, height)));
+                                place();
+                            }
+                        }
+                    }
+                }
+            },
+            {popover.children}
+        }
+    }
+}
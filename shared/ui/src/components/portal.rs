@@ -0,0 +1,65 @@
+use dioxus::document;
+use dioxus::prelude::*;
+
+use crate::use_unique_id;
+
+/// Base classes of the portal root. As a manual popover in the top layer it covers the
+/// viewport without catching pointer events; its children catch them again unless their
+/// own classes say otherwise (`:where` keeps that rule weaker than theirs).
+const PORTAL_CLASSES: &str = "fixed inset-0 z-50 m-0 h-auto w-auto max-h-none max-w-none overflow-visible border-0 bg-transparent p-0 text-inherit pointer-events-none [:where(&>*)]:pointer-events-auto";
+
+/// JavaScript that shows the portal containing `element_id` in the browser's top layer.
+/// Idempotent, so overlays can also run it before moving focus into themselves.
+pub(crate) fn portal_script(element_id: &str) -> String {
+    format!(
+        r#"(function () {{
+    const el = document.getElementById({element_id:?});
+    const root = el && el.closest("[data-portal]");
+    if (root && root.showPopover && !root.matches(":popover-open")) root.showPopover();
+}})();"#
+    )
+}
+
+/// JavaScript that moves the portal containing `element_id` above the portals shown after
+/// it, e.g. to keep notifications above a dialog that opened later. Skipped while focus is
+/// inside, as hiding the portal for a moment would drop it.
+pub(crate) fn bring_to_front_script(element_id: &str) -> String {
+    format!(
+        r#"(function () {{
+    const el = document.getElementById({element_id:?});
+    const root = el && el.closest("[data-portal]");
+    if (!root || !root.showPopover || root.contains(document.activeElement)) return;
+    if (root.matches(":popover-open")) root.hidePopover();
+    root.showPopover();
+}})();"#
+    )
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct PortalProps {
+    children: Element,
+}
+
+/// Renders its children in the browser's top layer: above `Layout` and everything else,
+/// and out of any `overflow: hidden` or transformed ancestor. The children stay where
+/// they are in the DOM and in this component's tree, for context, signals and events.
+///
+/// Portals shown later stack above earlier ones. Browsers without the popover API show
+/// the children in a `fixed` layer over the page instead.
+#[component]
+pub fn Portal(props: PortalProps) -> Element {
+    let id = use_unique_id("portal");
+
+    rsx! {
+        div {
+            id: "{id}",
+            class: PORTAL_CLASSES,
+            "data-portal": "true",
+            "popover": "manual",
+            onmounted: move |_| {
+                document::eval(&portal_script(&id));
+            },
+            {props.children}
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use dioxus::prelude::*;
+
+use crate::{merge_classes, use_field_control, use_theme, use_unique_id};
+
+#[derive(Clone, PartialEq)]
+pub struct RadioOption {
+    pub value: String,
+    pub label: String,
+    pub description: Option<String>,
+    pub disabled: bool,
+}
+
+impl RadioOption {
+    pub fn new(value: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            value: value.into(),
+            label: label.into(),
+            description: None,
+            disabled: false,
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn disabled(mut self) -> Self {
+        self.disabled = true;
+        self
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Default)]
+pub enum RadioOrientation {
+    #[default]
+    Vertical,
+    Horizontal,
+}
+
+impl RadioOrientation {
+    fn to_classes(self) -> &'static str {
+        match self {
+            RadioOrientation::Vertical => "flex flex-col gap-2",
+            RadioOrientation::Horizontal => "flex flex-wrap gap-4",
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct RadioGroupProps {
+    pub options: Vec<RadioOption>,
+    /// Selected option value
+    #[props(default)]
+    pub value: Option<String>,
+    /// Rendered as the group's `<legend>`
+    #[props(default)]
+    pub legend: Option<String>,
+    #[props(default)]
+    pub orientation: RadioOrientation,
+    #[props(default = false)]
+    pub disabled: bool,
+    #[props(default = false)]
+    pub required: bool,
+    /// Sets `aria-invalid`; set automatically inside a `Field` with an error
+    #[props(default = false)]
+    pub invalid: bool,
+    /// Called with the newly selected value
+    #[props(default)]
+    pub onchange: Option<EventHandler<String>>,
+    #[props(default)]
+    pub class: Option<String>,
+    #[props(default)]
+    pub id: Option<String>,
+    /// Shared `name` of the radios; generated when not given
+    #[props(default)]
+    pub name: Option<String>,
+}
+
+/// Native radios sharing one `name`, so arrow keys move the selection and Tab
+/// enters and leaves the group as a single stop.
+#[component]
+pub fn RadioGroup(props: RadioGroupProps) -> Element {
+    let theme = use_theme().input;
+    let control = use_field_control(props.id.as_ref(), props.required, props.invalid);
+    let generated_name = use_unique_id("radio-group");
+    let name = props.name.clone().unwrap_or(generated_name);
+
+    let additional_classes = props.class.as_deref().unwrap_or("");
+    let class_string = merge_classes(&["min-w-0 border-0 p-0 m-0", additional_classes]);
+    let selected = props.value.as_deref();
+
+    rsx! {
+        fieldset {
+            class: "{class_string}",
+            id: control.id.clone(),
+            role: "radiogroup",
+            disabled: props.disabled,
+            "aria-required": control.required.then_some("true"),
+            "aria-invalid": control.invalid.then_some("true"),
+            "aria-describedby": control.describedby.clone(),
+            if let Some(legend) = &props.legend {
+                legend {
+                    class: theme.label,
+                    "{legend}"
+                    if control.required {
+                        span { class: theme.required_marker, "*" }
+                    }
+                }
+            }
+            div {
+                class: props.orientation.to_classes(),
+                for option in props.options.iter() {
+                    label {
+                        key: "{option.value}",
+                        class: merge_classes(&[theme.choice_label, "items-start"]),
+                        input {
+                            r#type: "radio",
+                            class: merge_classes(&[theme.radio, "mt-0.5"]),
+                            name: "{name}",
+                            value: "{option.value}",
+                            checked: selected == Some(option.value.as_str()),
+                            disabled: option.disabled,
+                            required: control.required,
+                            onchange: {
+                                let value = option.value.clone();
+                                let onchange = props.onchange;
+                                move |_| {
+                                    if let Some(handler) = &onchange {
+                                        handler.call(value.clone());
+                                    }
+                                }
+                            }
+                        }
+                        span {
+                            class: "flex flex-col",
+                            span { "{option.label}" }
+                            if let Some(description) = &option.description {
+                                span { class: theme.radio_description, "{description}" }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use dioxus::prelude::*;
+
+use crate::{InputClasses, InputSize, use_field_control, use_theme};
+
+#[derive(Clone, PartialEq)]
+pub struct SelectOption {
+    pub value: String,
+    pub label: String,
+    pub disabled: bool,
+}
+
+impl SelectOption {
+    pub fn new(value: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            value: value.into(),
+            label: label.into(),
+            disabled: false,
+        }
+    }
+
+    pub fn disabled(mut self) -> Self {
+        self.disabled = true;
+        self
+    }
+}
+
+/// Options rendered inside an `<optgroup>`.
+#[derive(Clone, PartialEq)]
+pub struct SelectGroup {
+    pub label: String,
+    pub options: Vec<SelectOption>,
+    pub disabled: bool,
+}
+
+impl SelectGroup {
+    pub fn new(label: impl Into<String>, options: Vec<SelectOption>) -> Self {
+        Self {
+            label: label.into(),
+            options,
+            disabled: false,
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct SelectProps {
+    /// Ungrouped options, rendered before `groups`
+    #[props(default)]
+    pub options: Vec<SelectOption>,
+    #[props(default)]
+    pub groups: Vec<SelectGroup>,
+    /// Selected option value
+    #[props(default)]
+    pub value: Option<String>,
+    /// Disabled first option shown while nothing is selected
+    #[props(default)]
+    pub placeholder: Option<String>,
+    #[props(default)]
+    pub size: InputSize,
+    #[props(default = false)]
+    pub disabled: bool,
+    #[props(default = false)]
+    pub required: bool,
+    /// Error styling and `aria-invalid`; set automatically inside a `Field` with an error
+    #[props(default = false)]
+    pub invalid: bool,
+    /// Called with the newly selected value
+    #[props(default)]
+    pub onchange: Option<EventHandler<String>>,
+    #[props(default)]
+    pub class: Option<String>,
+    #[props(default)]
+    pub id: Option<String>,
+    #[props(default)]
+    pub name: Option<String>,
+}
+
+#[component]
+pub fn Select(props: SelectProps) -> Element {
+    let theme = use_theme().input;
+    let control = use_field_control(props.id.as_ref(), props.required, props.invalid);
+    let additional_classes = props.class.as_deref().unwrap_or("");
+    let class_string = InputClasses {
+        size: props.size,
+        state: control.state(),
+    }
+    .classes(&theme, additional_classes);
+
+    let selected = props.value.as_deref().unwrap_or("");
+    let render_option = |option: &SelectOption| {
+        rsx! {
+            option {
+                key: "{option.value}",
+                value: "{option.value}",
+                disabled: option.disabled,
+                selected: option.value == selected,
+                "{option.label}"
+            }
+        }
+    };
+
+    rsx! {
+        select {
+            class: "{class_string}",
+            disabled: props.disabled,
+            required: control.required,
+            "aria-invalid": control.invalid.then_some("true"),
+            "aria-required": control.required.then_some("true"),
+            "aria-describedby": control.describedby.clone(),
+            id: control.id.as_deref().unwrap_or(""),
+            name: props.name.as_deref().unwrap_or(""),
+            onchange: move |evt: FormEvent| {
+                if let Some(handler) = &props.onchange {
+                    handler.call(evt.value());
+                }
+            },
+            if let Some(placeholder) = &props.placeholder {
+                option {
+                    value: "",
+                    disabled: true,
+                    selected: selected.is_empty(),
+                    "{placeholder}"
+                }
+            }
+            for item in props.options.iter() {
+                {render_option(item)}
+            }
+            for group in props.groups.iter() {
+                optgroup {
+                    key: "{group.label}",
+                    label: "{group.label}",
+                    disabled: group.disabled,
+                    for item in group.options.iter() {
+                        {render_option(item)}
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,429 @@
+use dioxus::document;
+use dioxus::prelude::*;
+
+use super::tabs::focus_script;
+use crate::{merge_classes, use_theme, use_unique_id};
+
+pub const DEFAULT_SIDEBAR_STORAGE_KEY: &str = "sidebar-collapsed";
+
+/// Splits a URL into its path without query string or trailing slash, and its fragment.
+fn split_location(url: &str) -> (&str, Option<&str>) {
+    let (rest, fragment) = match url.split_once('#') {
+        Some((rest, fragment)) => (rest, Some(fragment).filter(|f| !f.is_empty())),
+        None => (url, None),
+    };
+    let path = rest.split('?').next().unwrap_or("").trim_end_matches('/');
+    (if path.is_empty() { "/" } else { path }, fragment)
+}
+
+/// Whether a nav link to `href` points at the page at `location`. Query strings and
+/// trailing slashes are ignored. A link with a `#fragment` matches only that fragment;
+/// other links also match the pages below them (`/users` for `/users/42`) unless
+/// `exact`, except `/`, which only matches itself.
+fn is_active(location: &str, href: &str, exact: bool) -> bool {
+    let (location_path, location_fragment) = split_location(location);
+    let (href_path, href_fragment) = split_location(href);
+    if href_fragment.is_some() {
+        return (href.starts_with('#') || location_path == href_path)
+            && location_fragment == href_fragment;
+    }
+    location_path == href_path
+        || (!exact
+            && href_path != "/"
+            && location_path
+                .strip_prefix(href_path)
+                .is_some_and(|rest| rest.starts_with('/')))
+}
+
+/// Sidebar state shared through context by `AppShell`.
+#[derive(Clone, Copy, PartialEq)]
+pub struct SidebarState {
+    collapsed: Signal<bool>,
+    mobile_open: Signal<bool>,
+    location: Signal<String>,
+    id: CopyValue<String>,
+    storage_key: &'static str,
+}
+
+impl SidebarState {
+    /// Whether the sidebar shows icons only on large screens.
+    pub fn collapsed(&self) -> bool {
+        *self.collapsed.read()
+    }
+
+    /// Change and persist the collapsed state.
+    pub fn set_collapsed(&mut self, collapsed: bool) {
+        self.collapsed.set(collapsed);
+        document::eval(&format!(
+            "try {{ localStorage.setItem({:?}, {:?}); }} catch (e) {{}}",
+            self.storage_key,
+            collapsed.to_string()
+        ));
+    }
+
+    pub fn toggle_collapsed(&mut self) {
+        let collapsed = !self.collapsed();
+        self.set_collapsed(collapsed);
+    }
+
+    /// Whether the off-canvas drawer is open on small screens.
+    pub fn mobile_open(&self) -> bool {
+        *self.mobile_open.read()
+    }
+
+    pub fn set_mobile_open(&mut self, open: bool) {
+        if *self.mobile_open.peek() != open {
+            self.mobile_open.set(open);
+        }
+    }
+
+    /// The current route that nav items are highlighted against.
+    pub fn location(&self) -> String {
+        self.location.read().clone()
+    }
+
+    pub(crate) fn drawer_id(&self) -> String {
+        format!("{}-drawer", self.id.read())
+    }
+
+    pub(crate) fn toggle_id(&self) -> String {
+        format!("{}-toggle", self.id.read())
+    }
+}
+
+/// Create the sidebar state, load the stored collapsed state and follow the browser's
+/// location unless `current_path` is given. Called by `AppShell`.
+pub(crate) fn use_sidebar_provider(
+    default_collapsed: bool,
+    storage_key: &'static str,
+    current_path: Option<String>,
+) -> SidebarState {
+    let id = use_unique_id("sidebar");
+    let mut collapsed = use_signal(|| default_collapsed);
+    let mobile_open = use_signal(|| false);
+    let mut location = use_signal(|| current_path.clone().unwrap_or_default());
+    let follow_browser = use_hook(|| current_path.is_none());
+
+    use_hook(move || {
+        let stored = document::eval(&format!(
+            "try {{ return localStorage.getItem({storage_key:?}); }} catch (e) {{ return null; }}"
+        ));
+        spawn(async move {
+            if let Some(stored) = stored
+                .await
+                .ok()
+                .and_then(|v| v.as_str().and_then(|s| s.parse().ok()))
+            {
+                collapsed.set(stored);
+            }
+        });
+    });
+
+    // Without a router there is no navigation event beyond history and hash changes.
+    use_future(move || async move {
+        if !follow_browser {
+            return;
+        }
+        let mut changes = document::eval(
+            r#"
+            const send = () => dioxus.send(location.pathname + location.search + location.hash);
+            send();
+            window.addEventListener("popstate", send);
+            window.addEventListener("hashchange", send);
+            await new Promise(() => {});
+            "#,
+        );
+        while let Ok(current) = changes.recv::<String>().await {
+            location.set(current);
+        }
+    });
+
+    use_effect(use_reactive!(|(current_path)| {
+        if let Some(current_path) = current_path {
+            if *location.peek() != current_path {
+                location.set(current_path);
+            }
+        }
+    }));
+
+    use_context_provider(|| SidebarState {
+        collapsed,
+        mobile_open,
+        location,
+        id: CopyValue::new(id),
+        storage_key,
+    })
+}
+
+/// The sidebar state of the enclosing `AppShell`.
+pub fn use_sidebar() -> SidebarState {
+    use_context::<SidebarState>()
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct SidebarProps {
+    /// Names the navigation landmark
+    #[props(default = "Main navigation".to_string())]
+    pub aria_label: String,
+    #[props(default)]
+    pub class: Option<String>,
+    children: Element,
+}
+
+/// Navigation for an `AppShell`: a column on large screens that collapses to icons, and
+/// an off-canvas drawer opened from the `Header` on small ones. Escape, the backdrop and
+/// following a link close the drawer.
+#[component]
+pub fn Sidebar(props: SidebarProps) -> Element {
+    let theme = use_theme().layout;
+    let mut sidebar = use_sidebar();
+    let open = sidebar.mobile_open();
+    let collapsed = sidebar.collapsed();
+    let drawer_id = sidebar.drawer_id();
+
+    let focus_id = drawer_id.clone();
+    use_effect(use_reactive!(|(open)| {
+        if open {
+            document::eval(&format!(
+                r#"document.getElementById({focus_id:?})?.querySelector("a[href], button:not([disabled])")?.focus();"#
+            ));
+        }
+    }));
+
+    let state_classes = match (open, collapsed) {
+        (true, true) => "translate-x-0 lg:w-16",
+        (true, false) => "translate-x-0 lg:w-64",
+        (false, true) => "-translate-x-full max-lg:invisible lg:w-16",
+        (false, false) => "-translate-x-full max-lg:invisible lg:w-64",
+    };
+    let class_string = merge_classes(&[
+        theme.sidebar,
+        state_classes,
+        props.class.as_deref().unwrap_or(""),
+    ]);
+    let toggle_label = if collapsed {
+        "Expand sidebar"
+    } else {
+        "Collapse sidebar"
+    };
+    let icon_class = if collapsed {
+        "h-5 w-5 rotate-180"
+    } else {
+        "h-5 w-5"
+    };
+
+    rsx! {
+        if open {
+            div {
+                class: theme.sidebar_backdrop,
+                "aria-hidden": "true",
+                onclick: move |_| sidebar.set_mobile_open(false),
+            }
+        }
+        aside {
+            id: "{drawer_id}",
+            class: "{class_string}",
+            onkeydown: move |evt: KeyboardEvent| {
+                if evt.key() == Key::Escape && sidebar.mobile_open() {
+                    evt.stop_propagation();
+                    sidebar.set_mobile_open(false);
+                    document::eval(&focus_script(&sidebar.toggle_id()));
+                }
+            },
+            nav {
+                class: "flex-1 space-y-1 overflow-y-auto overflow-x-hidden p-3",
+                "aria-label": "{props.aria_label}",
+                {props.children}
+            }
+            div {
+                class: "hidden p-3 lg:flex",
+                class: if collapsed { "justify-center" } else { "justify-end" },
+                button {
+                    r#type: "button",
+                    class: theme.menu_button,
+                    title: toggle_label,
+                    "aria-label": toggle_label,
+                    "aria-expanded": "{!collapsed}",
+                    "aria-controls": sidebar.drawer_id(),
+                    onclick: move |_| sidebar.toggle_collapsed(),
+                    svg {
+                        class: icon_class,
+                        xmlns: "http://www.w3.org/2000/svg",
+                        fill: "none",
+                        view_box: "0 0 24 24",
+                        stroke: "currentColor",
+                        stroke_width: "2",
+                        "aria-hidden": "true",
+                        path { stroke_linecap: "round", stroke_linejoin: "round", d: "m18.75 4.5-7.5 7.5 7.5 7.5m-6-15L5.25 12l7.5 7.5" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Open-state signals of the enclosing `NavGroup`s, innermost last.
+#[derive(Clone, PartialEq)]
+struct NavGroupContext {
+    open: Vec<Signal<bool>>,
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct NavItemProps {
+    pub href: String,
+    pub label: String,
+    #[props(default)]
+    pub icon: Option<Element>,
+    /// Only highlight on this exact route, not on the routes below it
+    #[props(default = false)]
+    pub exact: bool,
+    #[props(default)]
+    pub class: Option<String>,
+}
+
+/// A link in a [`Sidebar`], marked with `aria-current="page"` while its route is
+/// current. A `NavGroup` containing the current item opens itself.
+#[component]
+pub fn NavItem(props: NavItemProps) -> Element {
+    let theme = use_theme().layout;
+    let mut sidebar = use_sidebar();
+    let collapsed = sidebar.collapsed();
+    let active = is_active(&sidebar.location(), &props.href, props.exact);
+
+    let groups = try_use_context::<NavGroupContext>();
+    use_effect(use_reactive!(|(active, groups)| {
+        if let (true, Some(groups)) = (active, groups) {
+            for mut open in groups.open {
+                if !*open.peek() {
+                    open.set(true);
+                }
+            }
+        }
+    }));
+
+    let class_string = merge_classes(&[theme.nav_item, props.class.as_deref().unwrap_or("")]);
+
+    rsx! {
+        a {
+            href: "{props.href}",
+            class: "{class_string}",
+            title: collapsed.then(|| props.label.clone()),
+            "aria-current": active.then_some("page"),
+            onclick: move |_| sidebar.set_mobile_open(false),
+            if let Some(icon) = &props.icon {
+                span { class: theme.nav_icon, "aria-hidden": "true", {icon} }
+            }
+            span {
+                class: if collapsed { "truncate lg:sr-only" } else { "truncate" },
+                "{props.label}"
+            }
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct NavGroupProps {
+    pub label: String,
+    #[props(default)]
+    pub icon: Option<Element>,
+    #[props(default = false)]
+    pub default_open: bool,
+    #[props(default)]
+    pub class: Option<String>,
+    children: Element,
+}
+
+/// A labelled, expandable set of [`NavItem`]s in a [`Sidebar`]. Groups nest. While the
+/// sidebar is collapsed the items are hidden, and opening the group expands the sidebar.
+#[component]
+pub fn NavGroup(props: NavGroupProps) -> Element {
+    let theme = use_theme().layout;
+    let mut sidebar = use_sidebar();
+    let collapsed = sidebar.collapsed();
+    let id = use_unique_id("nav-group");
+    let mut open = use_signal(|| props.default_open);
+
+    let parent = try_use_context::<NavGroupContext>();
+    use_context_provider(|| {
+        let mut context = parent.unwrap_or(NavGroupContext { open: Vec::new() });
+        context.open.push(open);
+        context
+    });
+
+    let is_open = open();
+    let class_string = merge_classes(&[theme.nav_item, props.class.as_deref().unwrap_or("")]);
+    let panel_id = format!("{id}-items");
+    let panel_class = merge_classes(&[theme.nav_group, if collapsed { "lg:hidden" } else { "" }]);
+
+    rsx! {
+        div {
+            button {
+                r#type: "button",
+                class: "{class_string}",
+                title: collapsed.then(|| props.label.clone()),
+                "aria-expanded": "{is_open && !collapsed}",
+                "aria-controls": "{panel_id}",
+                onclick: move |_| {
+                    if sidebar.collapsed() {
+                        sidebar.set_collapsed(false);
+                        open.set(true);
+                    } else {
+                        open.set(!is_open);
+                    }
+                },
+                if let Some(icon) = &props.icon {
+                    span { class: theme.nav_icon, "aria-hidden": "true", {icon} }
+                }
+                span {
+                    class: if collapsed { "flex-1 truncate lg:sr-only" } else { "flex-1 truncate" },
+                    "{props.label}"
+                }
+                svg {
+                    class: if collapsed { "h-4 w-4 shrink-0 transition-transform lg:hidden" } else { "h-4 w-4 shrink-0 transition-transform" },
+                    class: if is_open { "rotate-90" } else { "" },
+                    xmlns: "http://www.w3.org/2000/svg",
+                    fill: "none",
+                    view_box: "0 0 24 24",
+                    stroke: "currentColor",
+                    stroke_width: "2",
+                    "aria-hidden": "true",
+                    path { stroke_linecap: "round", stroke_linejoin: "round", d: "m8.25 4.5 7.5 7.5-7.5 7.5" }
+                }
+            }
+            div {
+                id: "{panel_id}",
+                class: "{panel_class}",
+                hidden: !is_open,
+                {props.children}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_active_prefix() {
+        assert!(is_active("/users", "/users", false));
+        assert!(is_active("/users/42/", "/users", false));
+        assert!(is_active("/users?page=2", "/users/", false));
+        assert!(!is_active("/users-archive", "/users", false));
+        assert!(!is_active("/users/42", "/users", true));
+        // The root link would otherwise be active everywhere.
+        assert!(is_active("/", "/", false));
+        assert!(!is_active("/users", "/", false));
+    }
+
+    #[test]
+    fn test_is_active_fragment() {
+        assert!(is_active("/#tables", "#tables", false));
+        assert!(is_active("/docs#tables", "/docs#tables", false));
+        assert!(!is_active("/docs#forms", "/docs#tables", false));
+        assert!(!is_active("/docs", "/docs#tables", false));
+        assert!(!is_active("/blog#tables", "/docs#tables", false));
+        // Links without a fragment ignore it.
+        assert!(is_active("/docs#tables", "/docs", false));
+    }
+}
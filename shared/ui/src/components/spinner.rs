@@ -0,0 +1,60 @@
+use dioxus::prelude::*;
+
+use crate::merge_classes;
+
+#[derive(Clone, PartialEq)]
+pub enum SpinnerSize {
+    Small,
+    Medium,
+    Large,
+}
+
+impl SpinnerSize {
+    fn to_classes(&self) -> &'static str {
+        match self {
+            SpinnerSize::Small => "h-4 w-4",
+            SpinnerSize::Medium => "h-5 w-5",
+            SpinnerSize::Large => "h-6 w-6",
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct SpinnerProps {
+    #[props(default = SpinnerSize::Medium)]
+    pub size: SpinnerSize,
+    #[props(default)]
+    pub class: Option<String>,
+}
+
+/// Decorative loading indicator; announce the busy state on the surrounding element.
+#[component]
+pub fn Spinner(props: SpinnerProps) -> Element {
+    let base_classes = "animate-spin shrink-0";
+    let size_classes = props.size.to_classes();
+    let additional_classes = props.class.as_deref().unwrap_or("");
+    let class_string = merge_classes(&[base_classes, size_classes, additional_classes]);
+
+    rsx! {
+        svg {
+            class: "{class_string}",
+            xmlns: "http://www.w3.org/2000/svg",
+            fill: "none",
+            view_box: "0 0 24 24",
+            "aria-hidden": "true",
+            circle {
+                class: "opacity-25",
+                cx: "12",
+                cy: "12",
+                r: "10",
+                stroke: "currentColor",
+                stroke_width: "4",
+            }
+            path {
+                class: "opacity-75",
+                fill: "currentColor",
+                d: "M4 12a8 8 0 018-8V0C5.373 0 0 5.373 0 12h4z",
+            }
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use dioxus::prelude::*;
+
+use crate::{merge_classes, use_field_control, use_theme};
+
+#[derive(Props, Clone, PartialEq)]
+pub struct SwitchProps {
+    #[props(default = false)]
+    pub checked: bool,
+    #[props(default)]
+    pub label: Option<String>,
+    #[props(default = false)]
+    pub disabled: bool,
+    /// Sets `aria-invalid`; set automatically inside a `Field` with an error
+    #[props(default = false)]
+    pub invalid: bool,
+    /// Called with the new checked state
+    #[props(default)]
+    pub onchange: Option<EventHandler<bool>>,
+    #[props(default)]
+    pub class: Option<String>,
+    #[props(default)]
+    pub id: Option<String>,
+    /// Submitted with the value `on` while checked, like a checkbox
+    #[props(default)]
+    pub name: Option<String>,
+}
+
+/// An on/off toggle (`role="switch"`), operated with Space or Enter. It always has a
+/// value, so unlike a checkbox it cannot be `required`.
+#[component]
+pub fn Switch(props: SwitchProps) -> Element {
+    let theme = use_theme().input;
+    let control = use_field_control(props.id.as_ref(), false, props.invalid);
+    let additional_classes = props.class.as_deref().unwrap_or("");
+    let class_string = merge_classes(&[theme.switch_track, additional_classes]);
+    let checked = props.checked;
+
+    rsx! {
+        label {
+            class: theme.choice_label,
+            button {
+                r#type: "button",
+                role: "switch",
+                id: control.id.clone(),
+                class: "{class_string}",
+                disabled: props.disabled,
+                "aria-checked": if checked { "true" } else { "false" },
+                "aria-invalid": control.invalid.then_some("true"),
+                "aria-describedby": control.describedby.clone(),
+                onclick: move |_| {
+                    if let Some(handler) = &props.onchange {
+                        handler.call(!checked);
+                    }
+                },
+                span { class: theme.switch_thumb, "aria-hidden": "true" }
+            }
+            if let Some(label) = &props.label {
+                span { "{label}" }
+            }
+            if let (Some(name), true) = (&props.name, checked) {
+                input { r#type: "hidden", name: "{name}", value: "on" }
+            }
+        }
+    }
+}
@@ -0,0 +1,445 @@
+use dioxus::document;
+use dioxus::prelude::*;
+
+use crate::{merge_classes, use_theme, use_unique_id};
+
+/// When keyboard focus on a tab shows its panel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TabsActivation {
+    /// Arrow keys move focus and show the focused tab's panel
+    #[default]
+    Automatic,
+    /// Arrow keys only move focus; Enter or Space shows the panel, for panels that are
+    /// slow to render
+    Manual,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TabsOrientation {
+    #[default]
+    Horizontal,
+    Vertical,
+}
+
+impl TabsOrientation {
+    fn as_str(self) -> &'static str {
+        match self {
+            TabsOrientation::Horizontal => "horizontal",
+            TabsOrientation::Vertical => "vertical",
+        }
+    }
+}
+
+/// The item a roving-focus key moves to among `enabled` items: `previous` and `next`
+/// step and wrap around, Home and End jump to the ends. Disabled items are skipped.
+pub(crate) fn roving_target(
+    enabled: &[bool],
+    current: Option<usize>,
+    key: &Key,
+    previous: &Key,
+    next: &Key,
+) -> Option<usize> {
+    let len = enabled.len() as isize;
+    let step = |step: isize| {
+        let start = current.map_or(if step > 0 { -1 } else { len }, |index| index as isize);
+        (1..=len)
+            .map(|offset| (start + offset * step).rem_euclid(len) as usize)
+            .find(|&index| enabled[index])
+    };
+    if len == 0 {
+        None
+    } else if key == previous {
+        step(-1)
+    } else if key == next {
+        step(1)
+    } else if *key == Key::Home {
+        enabled.iter().position(|&enabled| enabled)
+    } else if *key == Key::End {
+        enabled.iter().rposition(|&enabled| enabled)
+    } else {
+        None
+    }
+}
+
+pub(crate) fn focus_script(element_id: &str) -> String {
+    format!(r#"document.getElementById({element_id:?})?.focus();"#)
+}
+
+/// `value` made safe for use inside an element id. Values that need replacing get a hash
+/// of the original appended, so e.g. `a b` and `a-b` still get different ids.
+pub(crate) fn id_part(value: &str) -> String {
+    if value.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return value.to_string();
+    }
+    // FNV-1a, stable across builds and targets unlike `DefaultHasher`
+    let hash = value.bytes().fold(0x811c_9dc5_u32, |hash, byte| {
+        (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193)
+    });
+    let safe: String = value
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect();
+    format!("{safe}-{hash:08x}")
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct TabEntry {
+    value: String,
+    disabled: bool,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+struct TabsContext {
+    id: CopyValue<String>,
+    selected: Signal<Option<String>>,
+    /// Tabs in document order (see [`TabsContext::sync_order`]), for keyboard navigation
+    tabs: Signal<Vec<TabEntry>>,
+    /// The first enabled tab to render, set during render so the fallback selection
+    /// works before the effects filling `tabs` have run; cleared when that tab unmounts
+    first_tab: CopyValue<Option<String>>,
+    /// Panels shown at least once, which stay mounted when `lazy`
+    visited: Signal<Vec<String>>,
+    controlled: bool,
+    onchange: Option<EventHandler<String>>,
+    activation: TabsActivation,
+    orientation: TabsOrientation,
+    lazy: bool,
+}
+
+impl TabsContext {
+    /// The selected tab, falling back to the first enabled one.
+    fn current(&self) -> Option<String> {
+        self.selected.read().clone().or_else(|| {
+            let tabs = self.tabs.read();
+            if tabs.is_empty() {
+                return self.first_tab.peek().clone();
+            }
+            tabs.iter()
+                .find(|tab| !tab.disabled)
+                .map(|tab| tab.value.clone())
+        })
+    }
+
+    fn select(&mut self, value: &str) {
+        if self.current().as_deref() == Some(value) {
+            return;
+        }
+        if !self.controlled {
+            self.selected.set(Some(value.to_string()));
+        }
+        if let Some(handler) = &self.onchange {
+            handler.call(value.to_string());
+        }
+    }
+
+    /// Sort `tabs` into document order. Tabs register as they mount, so one inserted
+    /// conditionally would otherwise come last for the arrow keys and the fallback.
+    fn sync_order(&self) {
+        let prefix = format!("{}-tab-", self.id.read());
+        let read = document::eval(&format!(
+            r#"return Array.from(document.querySelectorAll('[role="tab"][id^={prefix:?}]'), (el) => el.id);"#
+        ));
+        let context = *self;
+        let mut tabs = self.tabs;
+        spawn(async move {
+            let Ok(ids) = read.join::<Vec<String>>().await else {
+                return;
+            };
+            let mut sorted = tabs.peek().clone();
+            sorted.sort_by_key(|tab| {
+                let id = context.tab_id(&tab.value);
+                ids.iter()
+                    .position(|other| *other == id)
+                    .unwrap_or(usize::MAX)
+            });
+            if *tabs.peek() != sorted {
+                tabs.set(sorted);
+            }
+        });
+    }
+
+    fn tab_id(&self, value: &str) -> String {
+        format!("{}-tab-{}", self.id.read(), id_part(value))
+    }
+
+    fn panel_id(&self, value: &str) -> String {
+        format!("{}-panel-{}", self.id.read(), id_part(value))
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct TabsProps {
+    /// Selected tab, for a controlled component; update it from `onchange`
+    #[props(default)]
+    pub value: Option<String>,
+    /// Initially selected tab when uncontrolled; defaults to the first tab
+    #[props(default)]
+    pub default_value: Option<String>,
+    #[props(default)]
+    pub onchange: Option<EventHandler<String>>,
+    #[props(default)]
+    pub activation: TabsActivation,
+    #[props(default)]
+    pub orientation: TabsOrientation,
+    /// Mount each panel the first time it is shown instead of up front; once mounted
+    /// it stays mounted, so its state survives switching tabs
+    #[props(default = false)]
+    pub lazy: bool,
+    #[props(default)]
+    pub class: Option<String>,
+    children: Element,
+}
+
+/// Tabbed sections following the WAI-ARIA tabs pattern: a [`TabList`] of [`Tab`]s with
+/// roving focus (arrow keys, Home, End) and a [`TabPanel`] per tab, matched by `value`.
+#[component]
+pub fn Tabs(props: TabsProps) -> Element {
+    let id = use_unique_id("tabs");
+    let initial = props.value.clone().or(props.default_value.clone());
+    let state = TabsContext {
+        id: use_hook(|| CopyValue::new(id)),
+        selected: use_signal(|| initial),
+        tabs: use_signal(Vec::new),
+        first_tab: use_hook(|| CopyValue::new(None)),
+        visited: use_signal(Vec::new),
+        controlled: props.value.is_some(),
+        onchange: props.onchange,
+        activation: props.activation,
+        orientation: props.orientation,
+        lazy: props.lazy,
+    };
+    let mut context = use_context_provider(|| Signal::new(state));
+    use_effect(use_reactive!(|(state)| context.set(state)));
+
+    let mut selected = state.selected;
+    let value = props.value.clone();
+    use_effect(use_reactive!(|(value)| {
+        if value.is_some() && *selected.peek() != value {
+            selected.set(value);
+        }
+    }));
+
+    let layout = match props.orientation {
+        TabsOrientation::Horizontal => "",
+        TabsOrientation::Vertical => "flex gap-6",
+    };
+    let class_string = merge_classes(&[layout, props.class.as_deref().unwrap_or("")]);
+
+    rsx! {
+        div {
+            class: "{class_string}",
+            {props.children}
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct TabListProps {
+    /// Names the tab list when there is no visible heading for it
+    #[props(default)]
+    pub aria_label: Option<String>,
+    #[props(default)]
+    pub class: Option<String>,
+    children: Element,
+}
+
+#[component]
+pub fn TabList(props: TabListProps) -> Element {
+    let theme = use_theme().tabs;
+    let context = use_context::<Signal<TabsContext>>()();
+    let base_classes = match context.orientation {
+        TabsOrientation::Horizontal => theme.list,
+        TabsOrientation::Vertical => theme.list_vertical,
+    };
+    let class_string = merge_classes(&[base_classes, props.class.as_deref().unwrap_or("")]);
+
+    rsx! {
+        div {
+            role: "tablist",
+            class: "{class_string}",
+            "aria-orientation": context.orientation.as_str(),
+            "aria-label": props.aria_label.clone(),
+            {props.children}
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct TabProps {
+    /// Matches the `TabPanel` this tab shows
+    pub value: String,
+    #[props(default = false)]
+    pub disabled: bool,
+    #[props(default)]
+    pub class: Option<String>,
+    children: Element,
+}
+
+#[component]
+pub fn Tab(props: TabProps) -> Element {
+    let theme = use_theme().tabs;
+    let context_signal = use_context::<Signal<TabsContext>>();
+    let mut context = context_signal();
+
+    let mut first_tab = context.first_tab;
+    if !props.disabled && first_tab.peek().is_none() {
+        first_tab.set(Some(props.value.clone()));
+    }
+
+    let value = props.value.clone();
+    let disabled = props.disabled;
+    let mut tabs = context.tabs;
+    use_effect(use_reactive!(|(value, disabled)| {
+        let mut entries = tabs.write();
+        match entries.iter_mut().find(|tab| tab.value == value) {
+            Some(tab) => tab.disabled = disabled,
+            None => {
+                entries.push(TabEntry { value, disabled });
+                drop(entries);
+                context.sync_order();
+            }
+        }
+    }));
+    let drop_value = props.value.clone();
+    use_drop(move || {
+        // The whole `Tabs` may be going away at the same time.
+        if let Ok(mut tabs) = tabs.try_write() {
+            tabs.retain(|tab| tab.value != drop_value);
+        }
+        if let Ok(mut first) = first_tab.try_write() {
+            if first.as_deref() == Some(drop_value.as_str()) {
+                *first = None;
+            }
+        }
+    });
+
+    let selected = context.current().as_deref() == Some(props.value.as_str());
+    let base_classes = match context.orientation {
+        TabsOrientation::Horizontal => theme.tab,
+        TabsOrientation::Vertical => theme.tab_vertical,
+    };
+    let class_string = merge_classes(&[base_classes, props.class.as_deref().unwrap_or("")]);
+    let click_value = props.value.clone();
+    let key_value = props.value.clone();
+
+    let onkeydown = move |evt: KeyboardEvent| {
+        let context = context_signal();
+        let (previous, next) = match context.orientation {
+            TabsOrientation::Horizontal => (Key::ArrowLeft, Key::ArrowRight),
+            TabsOrientation::Vertical => (Key::ArrowUp, Key::ArrowDown),
+        };
+        let entries = context.tabs.peek().clone();
+        let enabled: Vec<bool> = entries.iter().map(|tab| !tab.disabled).collect();
+        let current = entries.iter().position(|tab| tab.value == key_value);
+        let Some(target) = roving_target(&enabled, current, &evt.key(), &previous, &next) else {
+            return;
+        };
+        evt.prevent_default();
+        let target = &entries[target].value;
+        document::eval(&focus_script(&context.tab_id(target)));
+        if context.activation == TabsActivation::Automatic {
+            let mut context = context;
+            context.select(target);
+        }
+    };
+
+    rsx! {
+        button {
+            r#type: "button",
+            role: "tab",
+            id: context.tab_id(&props.value),
+            class: "{class_string}",
+            disabled: props.disabled,
+            tabindex: if selected { "0" } else { "-1" },
+            "aria-selected": "{selected}",
+            "aria-controls": context.panel_id(&props.value),
+            onclick: move |_| context.select(&click_value),
+            onkeydown,
+            {props.children}
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct TabPanelProps {
+    /// Matches the `Tab` that shows this panel
+    pub value: String,
+    #[props(default)]
+    pub class: Option<String>,
+    children: Element,
+}
+
+#[component]
+pub fn TabPanel(props: TabPanelProps) -> Element {
+    let theme = use_theme().tabs;
+    let context = use_context::<Signal<TabsContext>>()();
+    let active = context.current().as_deref() == Some(props.value.as_str());
+
+    let mut visited = context.visited;
+    let value = props.value.clone();
+    use_effect(use_reactive!(|(active, value)| {
+        if active && !visited.peek().contains(&value) {
+            visited.write().push(value);
+        }
+    }));
+
+    let mounted = active || !context.lazy || visited.read().contains(&props.value);
+    let class_string = merge_classes(&[theme.panel, props.class.as_deref().unwrap_or("")]);
+
+    rsx! {
+        div {
+            role: "tabpanel",
+            id: context.panel_id(&props.value),
+            class: "{class_string}",
+            tabindex: "0",
+            hidden: !active,
+            "aria-labelledby": context.tab_id(&props.value),
+            if mounted {
+                {props.children}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn target(enabled: &[bool], current: Option<usize>, key: Key) -> Option<usize> {
+        roving_target(enabled, current, &key, &Key::ArrowLeft, &Key::ArrowRight)
+    }
+
+    #[test]
+    fn test_roving_target_wraps_and_skips_disabled() {
+        let enabled = [true, false, true, true];
+        assert_eq!(target(&enabled, Some(0), Key::ArrowRight), Some(2));
+        assert_eq!(target(&enabled, Some(3), Key::ArrowRight), Some(0));
+        assert_eq!(target(&enabled, Some(0), Key::ArrowLeft), Some(3));
+        assert_eq!(target(&enabled, Some(2), Key::ArrowLeft), Some(0));
+        assert_eq!(target(&enabled, None, Key::ArrowRight), Some(0));
+        assert_eq!(
+            target(&[false, true, true, false], Some(2), Key::Home),
+            Some(1)
+        );
+        assert_eq!(
+            target(&[false, true, true, false], Some(1), Key::End),
+            Some(2)
+        );
+        assert_eq!(target(&enabled, Some(0), Key::ArrowDown), None);
+        assert_eq!(target(&[], None, Key::Home), None);
+        assert_eq!(target(&[false, false], Some(0), Key::ArrowRight), None);
+    }
+
+    #[test]
+    fn test_id_part() {
+        assert_eq!(id_part("billing"), "billing");
+        assert_eq!(id_part("Two Factor/2FA"), "Two-Factor-2FA-1fcf09e4");
+        assert_ne!(id_part("a b"), id_part("a-b"));
+        assert_ne!(id_part("a-b"), id_part("a_b"));
+        assert!(
+            id_part("a b")
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-')
+        );
+    }
+}
@@ -0,0 +1,130 @@
+use dioxus::document;
+use dioxus::prelude::*;
+
+use crate::{InputClasses, InputSize, use_field_control, use_theme, use_unique_id};
+
+#[derive(Props, Clone, PartialEq)]
+pub struct TextareaProps {
+    #[props(default)]
+    pub size: InputSize,
+    #[props(default)]
+    pub placeholder: Option<String>,
+    #[props(default)]
+    pub value: Option<String>,
+    /// Initial (and minimum, when auto-resizing) number of visible lines
+    #[props(default = 3)]
+    pub rows: u32,
+    /// Grow with the content instead of scrolling
+    #[props(default = true)]
+    pub auto_resize: bool,
+    /// Stop growing after this many lines and scroll instead
+    #[props(default)]
+    pub max_rows: Option<u32>,
+    #[props(default = false)]
+    pub disabled: bool,
+    #[props(default = false)]
+    pub required: bool,
+    /// Error styling and `aria-invalid`; set automatically inside a `Field` with an error
+    #[props(default = false)]
+    pub invalid: bool,
+    #[props(default)]
+    pub oninput: Option<EventHandler<FormEvent>>,
+    #[props(default)]
+    pub onchange: Option<EventHandler<FormEvent>>,
+    #[props(default)]
+    pub onblur: Option<EventHandler<FocusEvent>>,
+    #[props(default)]
+    pub class: Option<String>,
+    #[props(default)]
+    pub id: Option<String>,
+    #[props(default)]
+    pub name: Option<String>,
+}
+
+fn resize_script(id: &str, max_rows: Option<u32>) -> String {
+    format!(
+        r#"(function () {{
+    const el = document.getElementById({id:?});
+    if (!el) return;
+    const style = getComputedStyle(el);
+    const border = parseFloat(style.borderTopWidth) + parseFloat(style.borderBottomWidth);
+    const padding = parseFloat(style.paddingTop) + parseFloat(style.paddingBottom);
+    const lineHeight = parseFloat(style.lineHeight) || 1.5 * parseFloat(style.fontSize);
+    el.style.height = "auto";
+    let height = el.scrollHeight + border;
+    const max = {max} * lineHeight + padding + border;
+    el.style.overflowY = height > max ? "auto" : "hidden";
+    el.style.height = Math.min(height, max) + "px";
+}})();"#,
+        max = max_rows.map_or("Infinity".to_string(), |rows| rows.to_string())
+    )
+}
+
+#[component]
+pub fn Textarea(props: TextareaProps) -> Element {
+    let theme = use_theme().input;
+    let control = use_field_control(props.id.as_ref(), props.required, props.invalid);
+    let generated_id = use_unique_id("textarea");
+    let id = control.id.clone().unwrap_or(generated_id);
+
+    let resize_classes = if props.auto_resize {
+        "resize-none overflow-hidden"
+    } else {
+        "resize-y"
+    };
+    let additional_classes = props.class.as_deref().unwrap_or("");
+    let class_string = InputClasses {
+        size: props.size,
+        state: control.state(),
+    }
+    .classes(
+        &theme,
+        &format!("{} {}", resize_classes, additional_classes),
+    );
+
+    // Also covers values set by the parent rather than typed.
+    let _value = props.value.clone();
+    let auto_resize = props.auto_resize;
+    let max_rows = props.max_rows;
+    let script_id = id.clone();
+    use_effect(use_reactive!(|(_value, auto_resize, max_rows)| {
+        if auto_resize {
+            document::eval(&resize_script(&script_id, max_rows));
+        }
+    }));
+
+    let input_id = id.clone();
+    rsx! {
+        textarea {
+            id: "{id}",
+            class: "{class_string}",
+            rows: props.rows,
+            placeholder: props.placeholder.as_deref().unwrap_or(""),
+            value: props.value.as_deref().unwrap_or(""),
+            disabled: props.disabled,
+            required: control.required,
+            "aria-invalid": control.invalid.then_some("true"),
+            "aria-required": control.required.then_some("true"),
+            "aria-describedby": control.describedby.clone(),
+            name: props.name.as_deref().unwrap_or(""),
+            oninput: move |evt| {
+                if props.auto_resize {
+                    document::eval(&resize_script(&input_id, props.max_rows));
+                }
+                if let Some(handler) = &props.oninput {
+                    handler.call(evt);
+                }
+            },
+            onchange: move |evt| {
+                if let Some(handler) = &props.onchange {
+                    handler.call(evt);
+                }
+            },
+            onblur: move |evt| {
+                if let Some(handler) = &props.onblur {
+                    handler.call(evt);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,346 @@
+use std::fmt;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use crate::components::portal::bring_to_front_script;
+use crate::{Portal, ToastTheme, sleep, use_theme, use_unique_id};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToastKind {
+    Success,
+    Error,
+    Warning,
+    Info,
+}
+
+impl ToastKind {
+    fn icon_classes(self, theme: &ToastTheme) -> &'static str {
+        match self {
+            ToastKind::Success => theme.success,
+            ToastKind::Error => theme.error,
+            ToastKind::Warning => theme.warning,
+            ToastKind::Info => theme.info,
+        }
+    }
+
+    fn icon_path(self) -> &'static str {
+        match self {
+            ToastKind::Success => "M9 12.75 11.25 15 15 9.75M21 12a9 9 0 1 1-18 0 9 9 0 0 1 18 0Z",
+            ToastKind::Error => {
+                "M12 9v3.75m9-.75a9 9 0 1 1-18 0 9 9 0 0 1 18 0Zm-9 3.75h.008v.008H12v-.008Z"
+            }
+            ToastKind::Warning => {
+                "M12 9v3.75m-9.303 3.376c-.866 1.5.217 3.374 1.948 3.374h14.71c1.73 0 2.813-1.874 1.948-3.374L13.949 3.378c-.866-1.5-3.032-1.5-3.898 0L2.697 16.126ZM12 15.75h.007v.008H12v-.008Z"
+            }
+            ToastKind::Info => {
+                "m11.25 11.25.041-.02a.75.75 0 0 1 1.063.852l-.708 2.836a.75.75 0 0 0 1.063.853l.041-.021M21 12a9 9 0 1 1-18 0 9 9 0 0 1 18 0Zm-9-3.75h.008v.008H12V8.25Z"
+            }
+        }
+    }
+}
+
+/// Button inside a toast, e.g. "Undo". Clicking it also dismisses the toast.
+#[derive(Clone)]
+pub struct ToastAction {
+    pub label: String,
+    /// A plain callback rather than an `EventHandler`, so the action keeps working
+    /// after the component that raised the toast has unmounted.
+    pub on_action: Rc<dyn Fn()>,
+}
+
+impl PartialEq for ToastAction {
+    fn eq(&self, other: &Self) -> bool {
+        self.label == other.label && Rc::ptr_eq(&self.on_action, &other.on_action)
+    }
+}
+
+impl fmt::Debug for ToastAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ToastAction")
+            .field("label", &self.label)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Toast {
+    pub kind: ToastKind,
+    pub title: String,
+    pub description: Option<String>,
+    /// Milliseconds before the toast dismisses itself; `None` uses the provider's
+    /// default and `Some(0)` keeps it until dismissed
+    pub duration_ms: Option<u32>,
+    pub action: Option<ToastAction>,
+}
+
+impl Toast {
+    pub fn new(kind: ToastKind, title: impl Into<String>) -> Self {
+        Self {
+            kind,
+            title: title.into(),
+            description: None,
+            duration_ms: None,
+            action: None,
+        }
+    }
+
+    pub fn success(title: impl Into<String>) -> Self {
+        Self::new(ToastKind::Success, title)
+    }
+
+    pub fn error(title: impl Into<String>) -> Self {
+        Self::new(ToastKind::Error, title)
+    }
+
+    pub fn warning(title: impl Into<String>) -> Self {
+        Self::new(ToastKind::Warning, title)
+    }
+
+    pub fn info(title: impl Into<String>) -> Self {
+        Self::new(ToastKind::Info, title)
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn duration_ms(mut self, duration_ms: u32) -> Self {
+        self.duration_ms = Some(duration_ms);
+        self
+    }
+
+    /// Keep the toast until the user dismisses it.
+    pub fn sticky(self) -> Self {
+        self.duration_ms(0)
+    }
+
+    pub fn action(mut self, label: impl Into<String>, on_action: impl Fn() + 'static) -> Self {
+        self.action = Some(ToastAction {
+            label: label.into(),
+            on_action: Rc::new(on_action),
+        });
+        self
+    }
+}
+
+pub type ToastId = u64;
+
+#[derive(Clone, PartialEq)]
+struct QueuedToast {
+    id: ToastId,
+    toast: Toast,
+}
+
+/// Handle for showing toasts, from [`use_toast`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct Toaster {
+    queue: Signal<Vec<QueuedToast>>,
+    next_id: CopyValue<ToastId>,
+}
+
+impl Toaster {
+    /// Queue a toast. It shows as soon as fewer than `max_visible` toasts are on screen.
+    pub fn show(&mut self, toast: Toast) -> ToastId {
+        let id = *self.next_id.peek();
+        *self.next_id.write() += 1;
+        self.queue.write().push(QueuedToast { id, toast });
+        id
+    }
+
+    pub fn success(&mut self, title: impl Into<String>) -> ToastId {
+        self.show(Toast::success(title))
+    }
+
+    pub fn error(&mut self, title: impl Into<String>) -> ToastId {
+        self.show(Toast::error(title))
+    }
+
+    pub fn warning(&mut self, title: impl Into<String>) -> ToastId {
+        self.show(Toast::warning(title))
+    }
+
+    pub fn info(&mut self, title: impl Into<String>) -> ToastId {
+        self.show(Toast::info(title))
+    }
+
+    pub fn dismiss(&mut self, id: ToastId) {
+        self.queue.write().retain(|queued| queued.id != id);
+    }
+
+    pub fn dismiss_all(&mut self) {
+        self.queue.write().clear();
+    }
+}
+
+/// The toaster of the nearest `ToastProvider`.
+pub fn use_toast() -> Toaster {
+    use_context::<Toaster>()
+}
+
+#[derive(Clone, Copy, PartialEq, Default)]
+pub enum ToastPosition {
+    TopLeft,
+    TopCenter,
+    TopRight,
+    BottomLeft,
+    BottomCenter,
+    #[default]
+    BottomRight,
+}
+
+impl ToastPosition {
+    fn to_classes(self) -> &'static str {
+        match self {
+            ToastPosition::TopLeft => "top-0 left-0",
+            ToastPosition::TopCenter => "top-0 left-1/2 -translate-x-1/2",
+            ToastPosition::TopRight => "top-0 right-0",
+            ToastPosition::BottomLeft => "bottom-0 left-0 flex-col-reverse",
+            ToastPosition::BottomCenter => "bottom-0 left-1/2 -translate-x-1/2 flex-col-reverse",
+            ToastPosition::BottomRight => "bottom-0 right-0 flex-col-reverse",
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct ToastProviderProps {
+    #[props(default)]
+    pub position: ToastPosition,
+    /// Toasts on screen at once; the rest wait their turn
+    #[props(default = 3)]
+    pub max_visible: usize,
+    #[props(default = 5000)]
+    pub default_duration_ms: u32,
+    children: Element,
+}
+
+/// Makes [`use_toast`] available below it and renders the toasts in a labelled region.
+/// Each toast is its own live region: `role="status"`, announced politely, or
+/// `role="alert"` for errors, announced immediately.
+#[component]
+pub fn ToastProvider(props: ToastProviderProps) -> Element {
+    let queue = use_signal(Vec::<QueuedToast>::new);
+    let next_id = use_hook(|| CopyValue::new(0));
+    let toaster = use_context_provider(|| Toaster { queue, next_id });
+
+    let position_classes = props.position.to_classes();
+
+    // Keep new toasts above dialogs and popovers that opened after the region.
+    let region_id = use_unique_id("toasts");
+    let front_id = region_id.clone();
+    let count = queue.read().len();
+    use_effect(use_reactive!(|(count)| {
+        if count > 0 {
+            document::eval(&bring_to_front_script(&front_id));
+        }
+    }));
+
+    rsx! {
+        {props.children}
+        Portal {
+            section {
+                id: "{region_id}",
+                class: "fixed z-[60] flex w-full max-w-sm flex-col gap-2 p-4 pointer-events-none {position_classes}",
+                "aria-label": "Notifications",
+                for queued in queue.read().iter().take(props.max_visible) {
+                    ToastItem {
+                        key: "{queued.id}",
+                        id: queued.id,
+                        toast: queued.toast.clone(),
+                        duration_ms: queued.toast.duration_ms.unwrap_or(props.default_duration_ms),
+                        toaster,
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Granularity of the auto-dismiss countdown.
+const TICK_MS: u32 = 100;
+
+#[component]
+fn ToastItem(id: ToastId, toast: Toast, duration_ms: u32, toaster: Toaster) -> Element {
+    let mut toaster = toaster;
+    let theme = use_theme().toast;
+    let mut remaining = use_signal(|| duration_ms);
+    let mut hovered = use_signal(|| false);
+    let mut focused = use_signal(|| false);
+
+    // Counts down only while visible and neither hovered nor focused.
+    use_future(move || async move {
+        if duration_ms == 0 {
+            return;
+        }
+        loop {
+            sleep(TICK_MS).await;
+            if hovered() || focused() {
+                continue;
+            }
+            let left = remaining().saturating_sub(TICK_MS);
+            remaining.set(left);
+            if left == 0 {
+                toaster.dismiss(id);
+                break;
+            }
+        }
+    });
+
+    let action = toast.action.clone();
+
+    rsx! {
+        div {
+            class: theme.toast,
+            role: if toast.kind == ToastKind::Error { "alert" } else { "status" },
+            onmouseenter: move |_| hovered.set(true),
+            onmouseleave: move |_| hovered.set(false),
+            onfocusin: move |_| focused.set(true),
+            onfocusout: move |_| focused.set(false),
+            svg {
+                class: "h-5 w-5 shrink-0 {toast.kind.icon_classes(&theme)}",
+                xmlns: "http://www.w3.org/2000/svg",
+                fill: "none",
+                view_box: "0 0 24 24",
+                stroke: "currentColor",
+                stroke_width: "1.5",
+                "aria-hidden": "true",
+                path { stroke_linecap: "round", stroke_linejoin: "round", d: toast.kind.icon_path() }
+            }
+            div {
+                class: "flex-1 min-w-0",
+                p { class: theme.title, "{toast.title}" }
+                if let Some(description) = &toast.description {
+                    p { class: theme.description, "{description}" }
+                }
+                if let Some(action) = action {
+                    button {
+                        r#type: "button",
+                        class: theme.action,
+                        onclick: move |_| {
+                            (action.on_action)();
+                            toaster.dismiss(id);
+                        },
+                        "{action.label}"
+                    }
+                }
+            }
+            button {
+                r#type: "button",
+                class: theme.close,
+                "aria-label": "Dismiss notification",
+                onclick: move |_| toaster.dismiss(id),
+                svg {
+                    class: "h-4 w-4",
+                    xmlns: "http://www.w3.org/2000/svg",
+                    fill: "none",
+                    view_box: "0 0 24 24",
+                    stroke: "currentColor",
+                    stroke_width: "2",
+                    "aria-hidden": "true",
+                    path { stroke_linecap: "round", stroke_linejoin: "round", d: "M6 18 18 6M6 6l12 12" }
+                }
+            }
+        }
+    }
+}
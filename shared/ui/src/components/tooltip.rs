@@ -0,0 +1,209 @@
+use dioxus::prelude::*;
+
+use crate::{Placement, Popover, merge_classes, sleep, use_theme, use_unique_id};
+
+const DEFAULT_OPEN_DELAY_MS: u32 = 700;
+const DEFAULT_CLOSE_DELAY_MS: u32 = 100;
+const DEFAULT_SKIP_DELAY_MS: u32 = 300;
+
+/// Id of the enclosing [`Tooltip`]'s description.
+#[derive(Clone, PartialEq)]
+struct TooltipDescription(String);
+
+/// Id of the enclosing `Tooltip`'s description, for the trigger's `aria-describedby`.
+/// `Button` and controls inside a `Field` add it themselves; custom triggers use this.
+pub fn use_tooltip_description() -> Option<String> {
+    try_use_context::<TooltipDescription>().map(|description| description.0)
+}
+
+/// Shared state of the tooltips in one [`TooltipGroup`]: which one is open and whether
+/// one closed a moment ago, in which case the next opens without waiting.
+#[derive(Clone, Copy, PartialEq)]
+struct TooltipGroupState {
+    open: Signal<Option<String>>,
+    warm: Signal<bool>,
+    warm_generation: Signal<u32>,
+    open_delay_ms: u32,
+    close_delay_ms: u32,
+    skip_delay_ms: u32,
+}
+
+impl TooltipGroupState {
+    fn open(&mut self, id: &str) {
+        self.open.set(Some(id.to_string()));
+    }
+
+    fn close(&mut self, id: &str) {
+        if self.open.peek().as_deref() != Some(id) {
+            return;
+        }
+        self.open.set(None);
+        self.warm.set(true);
+        self.warm_generation += 1;
+        let generation = *self.warm_generation.peek();
+        let mut state = *self;
+        spawn(async move {
+            sleep(state.skip_delay_ms).await;
+            if *state.warm_generation.peek() == generation {
+                state.warm.set(false);
+            }
+        });
+    }
+}
+
+fn use_tooltip_group_state(
+    open_delay_ms: u32,
+    close_delay_ms: u32,
+    skip_delay_ms: u32,
+) -> TooltipGroupState {
+    TooltipGroupState {
+        open: use_signal(|| None),
+        warm: use_signal(|| false),
+        warm_generation: use_signal(|| 0),
+        open_delay_ms,
+        close_delay_ms,
+        skip_delay_ms,
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct TooltipGroupProps {
+    #[props(default = DEFAULT_OPEN_DELAY_MS)]
+    pub open_delay_ms: u32,
+    #[props(default = DEFAULT_CLOSE_DELAY_MS)]
+    pub close_delay_ms: u32,
+    /// After a tooltip closes, others in the group open instantly for this long
+    #[props(default = DEFAULT_SKIP_DELAY_MS)]
+    pub skip_delay_ms: u32,
+    children: Element,
+}
+
+/// Groups adjacent tooltips, e.g. of a toolbar: only one is open at a time and moving
+/// from one trigger to the next shows the next tooltip without the open delay.
+#[component]
+pub fn TooltipGroup(props: TooltipGroupProps) -> Element {
+    let mut state = use_tooltip_group_state(
+        props.open_delay_ms,
+        props.close_delay_ms,
+        props.skip_delay_ms,
+    );
+    state.open_delay_ms = props.open_delay_ms;
+    state.close_delay_ms = props.close_delay_ms;
+    state.skip_delay_ms = props.skip_delay_ms;
+    let mut context = use_context_provider(|| Signal::new(state));
+    use_effect(use_reactive!(|(state)| context.set(state)));
+
+    rsx! {
+        {props.children}
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct TooltipProps {
+    /// Text shown in the tooltip and announced as the trigger's description
+    pub content: String,
+    #[props(default = Placement::TOP)]
+    pub placement: Placement,
+    /// Hover delay before opening; defaults to the group's
+    #[props(default)]
+    pub open_delay_ms: Option<u32>,
+    #[props(default)]
+    pub close_delay_ms: Option<u32>,
+    #[props(default = false)]
+    pub disabled: bool,
+    #[props(default)]
+    pub class: Option<String>,
+    /// The trigger, usually a single button
+    children: Element,
+}
+
+/// Short description of its trigger, shown on hover after a delay and immediately on
+/// keyboard focus; Escape hides it. The text is also linked to the trigger through
+/// `aria-describedby` (see [`use_tooltip_description`]), including for disabled
+/// buttons, which still show the tooltip on hover.
+#[component]
+pub fn Tooltip(props: TooltipProps) -> Element {
+    let theme = use_theme().popover;
+    let id = use_unique_id("tooltip");
+    let description_id = format!("{id}-description");
+    use_context_provider(|| TooltipDescription(description_id.clone()));
+
+    let local = use_tooltip_group_state(
+        DEFAULT_OPEN_DELAY_MS,
+        DEFAULT_CLOSE_DELAY_MS,
+        DEFAULT_SKIP_DELAY_MS,
+    );
+    let mut group = try_use_context::<Signal<TooltipGroupState>>()
+        .map(|state| state())
+        .unwrap_or(local);
+    let open_delay_ms = props.open_delay_ms.unwrap_or(group.open_delay_ms);
+    let close_delay_ms = props.close_delay_ms.unwrap_or(group.close_delay_ms);
+
+    // Bumped on every enter/leave so a pending delayed open or close can tell it is stale.
+    let mut pending = use_signal(|| 0_u32);
+    let tooltip_id = use_hook(|| CopyValue::new(id.clone()));
+    let disabled = props.disabled;
+
+    let mut show = move |delay_ms: u32| {
+        pending += 1;
+        if disabled {
+            return;
+        }
+        // Another tooltip of the group is open or just closed: switch without waiting.
+        if delay_ms == 0 || group.open.peek().is_some() || *group.warm.peek() {
+            group.open(&tooltip_id.read());
+            return;
+        }
+        let generation = *pending.peek();
+        spawn(async move {
+            sleep(delay_ms).await;
+            if *pending.peek() == generation {
+                group.open(&tooltip_id.read());
+            }
+        });
+    };
+    let mut hide = move |delay_ms: u32| {
+        pending += 1;
+        if delay_ms == 0 {
+            group.close(&tooltip_id.read());
+            return;
+        }
+        let generation = *pending.peek();
+        spawn(async move {
+            sleep(delay_ms).await;
+            if *pending.peek() == generation {
+                group.close(&tooltip_id.read());
+            }
+        });
+    };
+
+    let open = !props.disabled && group.open.read().as_deref() == Some(id.as_str());
+    let class_string = merge_classes(&[theme.tooltip, props.class.as_deref().unwrap_or("")]);
+
+    rsx! {
+        Popover {
+            open,
+            onclose: move |_| hide(0),
+            placement: props.placement,
+            offset: 6.0,
+            close_on_outside_click: false,
+            unstyled: true,
+            class: class_string,
+            anchor: rsx! {
+                span {
+                    // Disabled buttons swallow pointer events; let the wrapper see them instead.
+                    class: "inline-flex [&_button:disabled]:pointer-events-none",
+                    onmouseenter: move |_| show(open_delay_ms),
+                    onmouseleave: move |_| hide(close_delay_ms),
+                    onfocusin: move |_| show(0),
+                    onfocusout: move |_| hide(0),
+                    onpointerdown: move |_| hide(0),
+                    {props.children}
+                    span { id: "{description_id}", class: "sr-only", "{props.content}" }
+                }
+            },
+            // Announced through the trigger's description instead.
+            div { role: "tooltip", "aria-hidden": "true", "{props.content}" }
+        }
+    }
+}
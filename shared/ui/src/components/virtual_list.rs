@@ -0,0 +1,467 @@
+use std::collections::HashMap;
+use std::ops::Range;
+use std::rc::Rc;
+
+use dioxus::document;
+use dioxus::prelude::*;
+
+use crate::{merge_classes, sleep, use_unique_id};
+
+/// How tall the rows of a virtualized list are.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RowHeight {
+    /// Every row is exactly this many pixels tall
+    Fixed(f64),
+    /// Rows are measured once rendered; rows not rendered yet count as this tall
+    Estimated(f64),
+}
+
+/// Where a row ends up after [`Virtualizer::scroll_to_index`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScrollAlign {
+    Start,
+    Center,
+    End,
+    /// Scroll as little as possible to bring the row into view
+    #[default]
+    Nearest,
+}
+
+/// Row positions of a virtualized list: where each row starts and which rows a scroll
+/// position shows. Fixed heights are computed directly; variable heights keep running
+/// offsets built from the measured heights and the estimate for the rest.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RowOffsets {
+    count: usize,
+    fixed: Option<f64>,
+    /// With variable heights, `offsets[i]` is the top of row `i` and `offsets[count]`
+    /// the total height
+    offsets: Vec<f64>,
+}
+
+impl RowOffsets {
+    pub fn new(count: usize, height: RowHeight, measured: &HashMap<usize, f64>) -> Self {
+        match height {
+            RowHeight::Fixed(height) => Self {
+                count,
+                fixed: Some(height.max(1.0)),
+                offsets: Vec::new(),
+            },
+            RowHeight::Estimated(estimate) => {
+                let mut offsets = Vec::with_capacity(count + 1);
+                let mut top = 0.0;
+                offsets.push(top);
+                for index in 0..count {
+                    top += measured.get(&index).copied().unwrap_or(estimate);
+                    offsets.push(top);
+                }
+                Self {
+                    count,
+                    fixed: None,
+                    offsets,
+                }
+            }
+        }
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Top of row `index`; `offset(count)` is the total height.
+    pub fn offset(&self, index: usize) -> f64 {
+        let index = index.min(self.count);
+        match self.fixed {
+            Some(height) => index as f64 * height,
+            None => self.offsets[index],
+        }
+    }
+
+    pub fn total_height(&self) -> f64 {
+        self.offset(self.count)
+    }
+
+    /// Index of the row at `y`, clamped to the last row.
+    fn index_at(&self, y: f64) -> usize {
+        let index = match self.fixed {
+            Some(height) => (y.max(0.0) / height).floor() as usize,
+            None => self.offsets[1..].partition_point(|&bottom| bottom <= y),
+        };
+        index.min(self.count.saturating_sub(1))
+    }
+
+    /// Rows inside the viewport plus `overscan` rows on either side.
+    pub fn visible_range(
+        &self,
+        scroll_top: f64,
+        viewport_height: f64,
+        overscan: usize,
+    ) -> Range<usize> {
+        if self.count == 0 {
+            return 0..0;
+        }
+        let first = self.index_at(scroll_top);
+        let last = self.index_at(scroll_top + viewport_height.max(0.0));
+        first.saturating_sub(overscan)..(last + 1 + overscan).min(self.count)
+    }
+
+    /// Scroll position that shows row `index` as `align` asks, kept within the list.
+    pub fn scroll_target(
+        &self,
+        index: usize,
+        align: ScrollAlign,
+        scroll_top: f64,
+        viewport_height: f64,
+    ) -> f64 {
+        let top = self.offset(index);
+        let bottom = self.offset(index + 1);
+        let target = match align {
+            ScrollAlign::Start => top,
+            ScrollAlign::End => bottom - viewport_height,
+            ScrollAlign::Center => top - (viewport_height - (bottom - top)) / 2.0,
+            ScrollAlign::Nearest if top < scroll_top => top,
+            ScrollAlign::Nearest if bottom > scroll_top + viewport_height => {
+                bottom - viewport_height
+            }
+            ScrollAlign::Nearest => scroll_top,
+        };
+        target.min(self.total_height() - viewport_height).max(0.0)
+    }
+}
+
+fn scroll_script(container_id: &str, top: f64) -> String {
+    format!("const el = document.getElementById({container_id:?}); if (el) el.scrollTop = {top};")
+}
+
+/// Scroll state of a virtualized list, created with [`use_virtualizer`].
+///
+/// [`VirtualList`] wires it up itself. A custom scroll container (such as the one around a
+/// `DataTable`) calls `attach`, `handle_scroll` and `handle_resize` from its events and
+/// `measure` from each rendered row, then renders `range()` with `padding()` as spacers.
+#[derive(Clone, Copy, PartialEq)]
+pub struct Virtualizer {
+    offsets: Memo<RowOffsets>,
+    range: Memo<Range<usize>>,
+    measured: Signal<HashMap<usize, f64>>,
+    scroll_top: Signal<f64>,
+    viewport_height: Signal<f64>,
+    container: Signal<Option<Rc<MountedData>>>,
+    container_id: CopyValue<String>,
+}
+
+impl Virtualizer {
+    /// Rows to render.
+    pub fn range(&self) -> Range<usize> {
+        self.range.read().clone()
+    }
+
+    pub fn count(&self) -> usize {
+        self.offsets.read().count()
+    }
+
+    pub fn total_height(&self) -> f64 {
+        self.offsets.read().total_height()
+    }
+
+    /// Space to leave above and below the rendered rows.
+    pub fn padding(&self) -> (f64, f64) {
+        let offsets = self.offsets.read();
+        let range = self.range();
+        (
+            offsets.offset(range.start),
+            offsets.total_height() - offsets.offset(range.end),
+        )
+    }
+
+    /// Id for the scroll container.
+    pub fn container_id(&self) -> String {
+        self.container_id.read().clone()
+    }
+
+    /// Scroll the container so row `index` is in view. With estimated heights the target
+    /// is corrected once the rows around it have been measured.
+    pub fn scroll_to_index(&self, index: usize, align: ScrollAlign) {
+        let mut this = *self;
+        spawn(async move {
+            let mut last = None;
+            // Scrolling renders new rows whose measurements can move the target; settle it.
+            for _ in 0..3 {
+                let target = this.offsets.peek().scroll_target(
+                    index,
+                    align,
+                    *this.scroll_top.peek(),
+                    *this.viewport_height.peek(),
+                );
+                if last.is_some_and(|last: f64| (last - target).abs() < 1.0) {
+                    break;
+                }
+                last = Some(target);
+                this.scroll_top.set(target);
+                document::eval(&scroll_script(&this.container_id.read(), target));
+                sleep(50).await;
+            }
+        });
+    }
+
+    /// Call from the scroll container's `onmounted`.
+    pub fn attach(&mut self, evt: MountedEvent) {
+        self.container.set(Some(evt.data()));
+    }
+
+    /// Call from the scroll container's `onscroll`.
+    pub fn handle_scroll(&self) {
+        let Some(container) = self.container.peek().clone() else {
+            return;
+        };
+        let mut scroll_top = self.scroll_top;
+        spawn(async move {
+            if let Ok(offset) = container.get_scroll_offset().await {
+                if *scroll_top.peek() != offset.y {
+                    scroll_top.set(offset.y);
+                }
+            }
+        });
+    }
+
+    /// Call from the scroll container's `onresize`.
+    pub fn handle_resize(&mut self, evt: ResizeEvent) {
+        if let Ok(size) = evt.get_border_box_size() {
+            if *self.viewport_height.peek() != size.height {
+                self.viewport_height.set(size.height);
+            }
+        }
+    }
+
+    /// Call from the `onresize` of rendered row `index`; only used with estimated heights.
+    pub fn measure(&mut self, index: usize, evt: ResizeEvent) {
+        if self.offsets.peek().fixed.is_some() {
+            return;
+        }
+        let Ok(size) = evt.get_border_box_size() else {
+            return;
+        };
+        // Ignore sub-pixel jitter so measuring cannot loop with re-rendering.
+        let changed = self
+            .measured
+            .peek()
+            .get(&index)
+            .map_or(true, |known| (known - size.height).abs() >= 0.5);
+        if changed {
+            self.measured.write().insert(index, size.height);
+        }
+    }
+
+    /// Forget the measured heights, e.g. after the rows were sorted, inserted into or
+    /// removed, when the cached heights would belong to other rows. Rendered rows are
+    /// measured again.
+    pub fn reset_measurements(&mut self) {
+        if !self.measured.peek().is_empty() {
+            self.measured.write().clear();
+        }
+    }
+}
+
+/// Track which of `count` rows a scroll container shows. Measured heights are cached by
+/// row index, so they stay valid while rows are appended (e.g. by infinite loading); call
+/// [`Virtualizer::reset_measurements`] when rows change order.
+pub fn use_virtualizer(count: usize, row_height: RowHeight, overscan: usize) -> Virtualizer {
+    let container_id = use_unique_id("virtual-list");
+    let container_id = use_hook(|| CopyValue::new(container_id));
+    let measured = use_signal(HashMap::new);
+    let scroll_top = use_signal(|| 0.0);
+    let viewport_height = use_signal(|| 0.0);
+    let container = use_signal(|| None);
+    let offsets = use_memo(use_reactive!(|(count, row_height)| {
+        RowOffsets::new(count, row_height, &measured.read())
+    }));
+    let range = use_memo(use_reactive!(|(overscan)| {
+        offsets
+            .read()
+            .visible_range(scroll_top(), viewport_height(), overscan)
+    }));
+
+    Virtualizer {
+        offsets,
+        range,
+        measured,
+        scroll_top,
+        viewport_height,
+        container,
+        container_id,
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct VirtualListProps {
+    /// From [`use_virtualizer`], which also takes the row count and heights
+    pub virtualizer: Virtualizer,
+    /// Renders the row at an index
+    pub render_item: Callback<usize, Element>,
+    /// CSS height of the scrolling viewport
+    #[props(default = "24rem".to_string())]
+    pub height: String,
+    /// Called when the user scrolls near the end, to append more rows
+    #[props(default)]
+    pub onloadmore: Option<EventHandler<()>>,
+    /// Whether `onloadmore` can still add rows
+    #[props(default = false)]
+    pub has_more: bool,
+    /// A load is in flight; shows `loading_text` after the last row
+    #[props(default = false)]
+    pub loading: bool,
+    #[props(default = "Loading...".to_string())]
+    pub loading_text: String,
+    /// Call `onloadmore` once this many rows or fewer are left below the viewport
+    #[props(default = 10)]
+    pub load_more_threshold: usize,
+    #[props(default)]
+    pub aria_label: Option<String>,
+    #[props(default)]
+    pub class: Option<String>,
+}
+
+/// A scrolling list that only renders the rows in view plus some overscan, so lists of
+/// tens of thousands of rows stay fast.
+///
+/// Rows are exposed as a `list` with `aria-setsize` / `aria-posinset`, so screen readers
+/// still announce the full length.
+#[component]
+pub fn VirtualList(props: VirtualListProps) -> Element {
+    let mut virtualizer = props.virtualizer;
+    // Row count at the last `onloadmore`, so each batch is requested once.
+    let mut requested_at = use_signal(|| None::<usize>);
+
+    let has_more = props.has_more;
+    let loading = props.loading;
+    let threshold = props.load_more_threshold;
+    let onloadmore = props.onloadmore;
+    use_effect(use_reactive!(|(
+        has_more,
+        loading,
+        threshold,
+        onloadmore,
+    )| {
+        let count = virtualizer.count();
+        let near_end = virtualizer.range().end + threshold >= count;
+        if !has_more || loading || !near_end || *requested_at.peek() == Some(count) {
+            return;
+        }
+        if let Some(handler) = onloadmore {
+            requested_at.set(Some(count));
+            handler.call(());
+        }
+    }));
+
+    let count = virtualizer.count();
+    let range = virtualizer.range();
+    let (before, _) = virtualizer.padding();
+    let total = virtualizer.total_height();
+    let class_string = merge_classes(&[
+        "relative overflow-auto",
+        props.class.as_deref().unwrap_or(""),
+    ]);
+
+    rsx! {
+        div {
+            id: virtualizer.container_id(),
+            class: "{class_string}",
+            style: "height: {props.height}; overflow-anchor: none;",
+            role: "list",
+            tabindex: "0",
+            "aria-label": props.aria_label.clone(),
+            "aria-busy": props.loading.then_some("true"),
+            onmounted: move |evt| virtualizer.attach(evt),
+            onscroll: move |_| virtualizer.handle_scroll(),
+            onresize: move |evt| virtualizer.handle_resize(evt),
+            div {
+                style: "position: relative; height: {total}px;",
+                div {
+                    style: "position: absolute; top: 0; left: 0; right: 0; transform: translateY({before}px);",
+                    for index in range {
+                        div {
+                            key: "{index}",
+                            role: "listitem",
+                            "aria-setsize": "{count}",
+                            "aria-posinset": "{index + 1}",
+                            onresize: move |evt| virtualizer.measure(index, evt),
+                            {props.render_item.call(index)}
+                        }
+                    }
+                }
+            }
+            if props.loading {
+                div {
+                    class: "px-4 py-3 text-center text-sm text-gray-500 dark:text-gray-400",
+                    role: "status",
+                    "{props.loading_text}"
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixed_heights() {
+        let rows = RowOffsets::new(1000, RowHeight::Fixed(20.0), &HashMap::new());
+        assert_eq!(rows.total_height(), 20_000.0);
+        assert_eq!(rows.offset(10), 200.0);
+        assert_eq!(rows.visible_range(0.0, 100.0, 0), 0..6);
+        assert_eq!(rows.visible_range(205.0, 100.0, 2), 8..18);
+        // Overscan stops at the ends.
+        assert_eq!(rows.visible_range(19_950.0, 100.0, 5), 992..1000);
+        assert_eq!(
+            RowOffsets::new(0, RowHeight::Fixed(20.0), &HashMap::new())
+                .visible_range(0.0, 100.0, 3),
+            0..0
+        );
+    }
+
+    #[test]
+    fn test_measured_heights_replace_estimates() {
+        let measured = HashMap::from([(0, 100.0), (2, 10.0)]);
+        let rows = RowOffsets::new(5, RowHeight::Estimated(40.0), &measured);
+        assert_eq!(rows.offset(1), 100.0);
+        assert_eq!(rows.offset(3), 150.0);
+        assert_eq!(rows.total_height(), 230.0);
+        assert_eq!(rows.visible_range(0.0, 50.0, 0), 0..1);
+        assert_eq!(rows.visible_range(100.0, 60.0, 0), 1..4);
+        assert_eq!(rows.visible_range(500.0, 60.0, 1), 3..5);
+    }
+
+    #[test]
+    fn test_scroll_targets() {
+        let rows = RowOffsets::new(100, RowHeight::Fixed(20.0), &HashMap::new());
+        assert_eq!(
+            rows.scroll_target(10, ScrollAlign::Start, 0.0, 100.0),
+            200.0
+        );
+        assert_eq!(rows.scroll_target(10, ScrollAlign::End, 0.0, 100.0), 120.0);
+        assert_eq!(
+            rows.scroll_target(10, ScrollAlign::Center, 0.0, 100.0),
+            160.0
+        );
+        // Already in view: stay put.
+        assert_eq!(
+            rows.scroll_target(3, ScrollAlign::Nearest, 40.0, 100.0),
+            40.0
+        );
+        assert_eq!(
+            rows.scroll_target(1, ScrollAlign::Nearest, 40.0, 100.0),
+            20.0
+        );
+        assert_eq!(
+            rows.scroll_target(10, ScrollAlign::Nearest, 40.0, 100.0),
+            120.0
+        );
+        // Clamped to the scrollable range.
+        assert_eq!(rows.scroll_target(0, ScrollAlign::Center, 0.0, 100.0), 0.0);
+        assert_eq!(
+            rows.scroll_target(99, ScrollAlign::Start, 0.0, 100.0),
+            1900.0
+        );
+    }
+}
@@ -0,0 +1,459 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+use shared_models::ValidationError;
+use shared_models::api::ApiError;
+
+/// Current value of every form field, keyed by field name.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FormValues(BTreeMap<String, String>);
+
+impl FormValues {
+    /// The field's value, or `""` for an unknown field.
+    pub fn get(&self, name: &str) -> &str {
+        self.0.get(name).map(String::as_str).unwrap_or("")
+    }
+
+    pub fn set(&mut self, name: &str, value: impl Into<String>) {
+        self.0.insert(name.to_string(), value.into());
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+}
+
+impl<'a> FromIterator<(&'a str, &'a str)> for FormValues {
+    fn from_iter<I: IntoIterator<Item = (&'a str, &'a str)>>(iter: I) -> Self {
+        Self(
+            iter.into_iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+        )
+    }
+}
+
+/// Result of running the synchronous validators.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FormErrors {
+    pub fields: BTreeMap<String, String>,
+    /// Errors that do not concern a single field
+    pub form: Option<String>,
+}
+
+impl FormErrors {
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty() && self.form.is_none()
+    }
+
+    /// Record a model validation error such as the one from `User::new` under the field
+    /// it names, or for the whole form when it names none. Earlier errors win.
+    pub fn add_validation(&mut self, error: impl Into<ValidationError>) {
+        let ValidationError { field, message } = error.into();
+        match field {
+            Some(field) => {
+                self.fields.entry(field).or_insert(message);
+            }
+            None => {
+                self.form.get_or_insert(message);
+            }
+        }
+    }
+}
+
+type FormValidator = Rc<dyn Fn(&FormValues) -> Result<(), ValidationError>>;
+type FieldValidator = Rc<dyn Fn(&str) -> Result<(), String>>;
+type AsyncFieldValidator = Rc<dyn Fn(String) -> Pin<Box<dyn Future<Output = Result<(), String>>>>>;
+
+/// Fields and validators for [`use_form`].
+#[derive(Clone, Default)]
+pub struct FormOptions {
+    initial: FormValues,
+    validators: Vec<FormValidator>,
+    field_validators: Vec<(String, FieldValidator)>,
+    async_validators: Vec<(String, AsyncFieldValidator)>,
+}
+
+impl FormOptions {
+    /// Declare the form's fields with their initial values.
+    pub fn new<'a>(fields: impl IntoIterator<Item = (&'a str, &'a str)>) -> Self {
+        Self {
+            initial: fields.into_iter().collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Validate the whole form, e.g. by calling a model constructor. Errors go to the
+    /// field a [`ValidationError`] names, like [`FormErrors::add_validation`]; plain
+    /// strings are errors of the whole form.
+    pub fn validator<E: Into<ValidationError>>(
+        mut self,
+        validator: impl Fn(&FormValues) -> Result<(), E> + 'static,
+    ) -> Self {
+        self.validators
+            .push(Rc::new(move |values| validator(values).map_err(Into::into)));
+        self
+    }
+
+    /// Validate a single field. Runs before the form validators.
+    pub fn field_validator(
+        mut self,
+        field: &str,
+        validator: impl Fn(&str) -> Result<(), String> + 'static,
+    ) -> Self {
+        self.field_validators
+            .push((field.to_string(), Rc::new(validator)));
+        self
+    }
+
+    /// Validate a field asynchronously, e.g. against the server. Runs when the field is
+    /// left and on submit, and only once its synchronous validation passes.
+    pub fn async_validator<F, Fut>(mut self, field: &str, validator: F) -> Self
+    where
+        F: Fn(String) -> Fut + 'static,
+        Fut: Future<Output = Result<(), String>> + 'static,
+    {
+        self.async_validators.push((
+            field.to_string(),
+            Rc::new(move |value| Box::pin(validator(value))),
+        ));
+        self
+    }
+
+    /// Run the synchronous validators.
+    pub fn validate(&self, values: &FormValues) -> FormErrors {
+        let mut errors = FormErrors::default();
+        for (field, validator) in &self.field_validators {
+            if errors.fields.contains_key(field) {
+                continue;
+            }
+            if let Err(message) = validator(values.get(field)) {
+                errors.fields.insert(field.clone(), message);
+            }
+        }
+        for validator in &self.validators {
+            if let Err(error) = validator(values) {
+                errors.add_validation(error);
+            }
+        }
+        errors
+    }
+}
+
+/// Form state created by [`use_form`]. Errors only show once a field has been left
+/// (touched) or a submit was attempted.
+#[derive(Clone, Copy, PartialEq)]
+pub struct Form {
+    options: CopyValue<Rc<FormOptions>>,
+    initial: Signal<FormValues>,
+    values: Signal<FormValues>,
+    touched: Signal<BTreeSet<String>>,
+    errors: Memo<FormErrors>,
+    /// Async validation and server errors; cleared when the field changes
+    external_errors: Signal<BTreeMap<String, String>>,
+    form_error: Signal<Option<String>>,
+    validating: Signal<BTreeSet<String>>,
+    generations: CopyValue<BTreeMap<String, u64>>,
+    submitting: Signal<bool>,
+    submit_attempted: Signal<bool>,
+}
+
+/// Create form state. `options` runs once, on the first render.
+pub fn use_form(options: impl FnOnce() -> FormOptions) -> Form {
+    let options = use_hook(|| CopyValue::new(Rc::new(options())));
+    let initial = use_signal(|| options.read().initial.clone());
+    let values = use_signal(|| options.read().initial.clone());
+    let errors = use_memo(move || options.read().validate(&values.read()));
+
+    Form {
+        options,
+        initial,
+        values,
+        touched: use_signal(BTreeSet::new),
+        errors,
+        external_errors: use_signal(BTreeMap::new),
+        form_error: use_signal(|| None),
+        validating: use_signal(BTreeSet::new),
+        generations: use_hook(|| CopyValue::new(BTreeMap::new())),
+        submitting: use_signal(|| false),
+        submit_attempted: use_signal(|| false),
+    }
+}
+
+impl Form {
+    pub fn value(&self, name: &str) -> String {
+        self.values.read().get(name).to_string()
+    }
+
+    pub fn values(&self) -> FormValues {
+        self.values.read().clone()
+    }
+
+    pub fn set_value(&mut self, name: &str, value: impl Into<String>) {
+        self.values.write().set(name, value);
+        self.external_errors.write().remove(name);
+        self.form_error.set(None);
+        self.cancel_async(name);
+    }
+
+    /// Mark the field as visited, revealing its errors, and run its async validators.
+    pub fn touch(&mut self, name: &str) {
+        if !self.touched.peek().contains(name) {
+            self.touched.write().insert(name.to_string());
+        }
+        let form = *self;
+        let name = name.to_string();
+        spawn(async move {
+            form.run_async_validators(name).await;
+        });
+    }
+
+    pub fn is_touched(&self, name: &str) -> bool {
+        self.touched.read().contains(name)
+    }
+
+    /// Whether the field differs from its initial value.
+    pub fn is_field_dirty(&self, name: &str) -> bool {
+        self.values.read().get(name) != self.initial.read().get(name)
+    }
+
+    pub fn is_dirty(&self) -> bool {
+        *self.values.read() != *self.initial.read()
+    }
+
+    /// The field's error, once it should be shown.
+    pub fn error(&self, name: &str) -> Option<String> {
+        if !self.is_touched(name) && !(self.submit_attempted)() {
+            return None;
+        }
+        self.errors
+            .read()
+            .fields
+            .get(name)
+            .or(self.external_errors.read().get(name))
+            .cloned()
+    }
+
+    /// Error for the form as a whole, from a validator or a failed submit.
+    pub fn form_error(&self) -> Option<String> {
+        if let Some(error) = self.form_error.read().clone() {
+            return Some(error);
+        }
+        if (self.submit_attempted)() {
+            return self.errors.read().form.clone();
+        }
+        None
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.errors.read().is_empty() && self.external_errors.read().is_empty()
+    }
+
+    /// Whether an async validator is running for the field.
+    pub fn is_validating(&self, name: &str) -> bool {
+        self.validating.read().contains(name)
+    }
+
+    pub fn is_submitting(&self) -> bool {
+        (self.submitting)()
+    }
+
+    /// Validate everything and, if valid, call `handler` with the values. Ignored while
+    /// a submit is in flight. Errors returned by `handler` are shown on their fields.
+    pub fn submit<F, Fut>(&mut self, handler: F)
+    where
+        F: FnOnce(FormValues) -> Fut + 'static,
+        Fut: Future<Output = Result<(), ApiError>> + 'static,
+    {
+        if *self.submitting.peek() {
+            return;
+        }
+        self.submit_attempted.set(true);
+        self.form_error.set(None);
+        if !self.errors.peek().is_empty() {
+            return;
+        }
+
+        self.submitting.set(true);
+        let mut form = *self;
+        spawn(async move {
+            let fields: BTreeSet<String> = form
+                .options
+                .peek()
+                .async_validators
+                .iter()
+                .map(|(field, _)| field.clone())
+                .collect();
+            let mut valid = true;
+            for field in fields {
+                valid &= form.run_async_validators(field).await;
+            }
+            if valid {
+                let values = form.values.peek().clone();
+                if let Err(error) = handler(values).await {
+                    form.set_error(error);
+                }
+            }
+            form.submitting.set(false);
+        });
+    }
+
+    /// Show an error returned by the server: field errors on their fields, anything
+    /// else as the form error.
+    pub fn set_error(&mut self, error: ApiError) {
+        let mut external = self.external_errors.write();
+        for field_error in &error.field_errors {
+            external.insert(field_error.field.clone(), field_error.message.clone());
+        }
+        if error.field_errors.is_empty() {
+            self.form_error.set(Some(error.message));
+        }
+    }
+
+    /// Back to the initial values with no touched fields or errors.
+    pub fn reset(&mut self) {
+        let initial = self.initial.peek().clone();
+        self.values.set(initial);
+        self.touched.write().clear();
+        self.external_errors.write().clear();
+        self.form_error.set(None);
+        self.submit_attempted.set(false);
+        let fields: Vec<String> = self.generations.peek().keys().cloned().collect();
+        for field in fields {
+            self.cancel_async(&field);
+        }
+    }
+
+    /// Make the current values the new baseline for `is_dirty` and `reset`.
+    pub fn commit(&mut self) {
+        let values = self.values.peek().clone();
+        self.initial.set(values);
+    }
+
+    /// Invalidate any in-flight async validation of the field.
+    fn cancel_async(&mut self, name: &str) {
+        *self
+            .generations
+            .write()
+            .entry(name.to_string())
+            .or_default() += 1;
+        if self.validating.peek().contains(name) {
+            self.validating.write().remove(name);
+        }
+    }
+
+    /// Returns whether the field passed; a result for an outdated value counts as failed.
+    async fn run_async_validators(mut self, name: String) -> bool {
+        let validators: Vec<AsyncFieldValidator> = self
+            .options
+            .peek()
+            .async_validators
+            .iter()
+            .filter(|(field, _)| *field == name)
+            .map(|(_, validator)| validator.clone())
+            .collect();
+        if validators.is_empty() {
+            return true;
+        }
+        if self.errors.peek().fields.contains_key(&name) {
+            return false;
+        }
+
+        self.cancel_async(&name);
+        let generation = self.generations.peek().get(&name).copied();
+        self.validating.write().insert(name.clone());
+
+        let value = self.values.peek().get(&name).to_string();
+        let mut result = Ok(());
+        for validator in validators {
+            result = validator(value.clone()).await;
+            if result.is_err() {
+                break;
+            }
+        }
+
+        if self.generations.peek().get(&name).copied() != generation {
+            return false;
+        }
+        self.validating.write().remove(&name);
+        match result {
+            Ok(()) => true,
+            Err(message) => {
+                self.external_errors.write().insert(name, message);
+                false
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared_models::User;
+
+    fn user_form() -> FormOptions {
+        FormOptions::new([("name", ""), ("email", "")])
+            .field_validator("name", |name| {
+                if name.len() > 20 {
+                    Err("Name is too long".to_string())
+                } else {
+                    Ok(())
+                }
+            })
+            .validator(|values| {
+                User::new(
+                    0,
+                    values.get("name").to_string(),
+                    values.get("email").to_string(),
+                )
+                .map(|_| ())
+            })
+    }
+
+    #[test]
+    fn test_model_errors_map_to_fields() {
+        let options = user_form();
+        let errors = options.validate(&options.initial);
+        assert_eq!(
+            errors.fields.get("name").map(String::as_str),
+            Some("Name cannot be empty")
+        );
+        assert!(errors.form.is_none());
+
+        let values: FormValues = [("name", "Jane"), ("email", "jane")].into_iter().collect();
+        let errors = options.validate(&values);
+        assert_eq!(
+            errors.fields.get("email").map(String::as_str),
+            Some("Invalid email format")
+        );
+        assert!(!errors.fields.contains_key("name"));
+
+        let values: FormValues = [("name", "Jane"), ("email", "jane@example.com")]
+            .into_iter()
+            .collect();
+        assert!(options.validate(&values).is_empty());
+    }
+
+    #[test]
+    fn test_field_validators_run_first() {
+        let values: FormValues = [("name", "A name that is far too long"), ("email", "")]
+            .into_iter()
+            .collect();
+        let errors = user_form().validate(&values);
+        assert_eq!(
+            errors.fields.get("name").map(String::as_str),
+            Some("Name is too long")
+        );
+    }
+
+    #[test]
+    fn test_unmapped_errors_go_to_form() {
+        let mut errors = FormErrors::default();
+        errors.add_validation("Something else went wrong");
+        assert!(errors.fields.is_empty());
+        assert_eq!(errors.form.as_deref(), Some("Something else went wrong"));
+    }
+}
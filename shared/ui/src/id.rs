@@ -0,0 +1,11 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use dioxus::prelude::*;
+
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// An id such as `field-3`, unique within the page and stable for the lifetime of
+/// the calling component. Used to link labels, hints and radio groups.
+pub fn use_unique_id(prefix: &str) -> String {
+    use_hook(|| format!("{}-{}", prefix, NEXT_ID.fetch_add(1, Ordering::Relaxed)))
+}
@@ -1,3 +1,20 @@
+pub mod class_merge;
+pub mod class_variants;
+pub mod color_scheme;
 pub mod components;
+pub mod form;
+pub mod id;
+pub mod mask;
+pub mod placement;
+pub mod theme;
+pub mod timer;
 
+pub use class_merge::*;
+pub use color_scheme::*;
 pub use components::*;
+pub use form::*;
+pub use id::*;
+pub use mask::*;
+pub use placement::*;
+pub use theme::*;
+pub use timer::*;
@@ -0,0 +1,363 @@
+//! Input masks: reformat what the user typed and keep the caret next to the same
+//! character it was next to before.
+
+use shared_models::{Currency, NumberFormat};
+
+/// How a `MaskedInput` formats its value.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Mask {
+    /// `9` is a digit, `a` a letter, `*` a letter or digit and `\` escapes the next
+    /// character; anything else is a literal inserted as the user types, e.g.
+    /// `(999) 999-9999` or `99/99/9999`.
+    Pattern(String),
+    /// A decimal number with grouping and decimal separators of a locale, and at most
+    /// `scale` fraction digits.
+    Number {
+        format: NumberFormat,
+        scale: u8,
+        allow_negative: bool,
+    },
+}
+
+/// Result of applying a [`Mask`].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MaskedValue {
+    /// What the input shows, e.g. `(555) 123-4567` or `1.234,5`
+    pub formatted: String,
+    /// The typed characters without literals, e.g. `5551234567`; numbers use `.` as
+    /// decimal separator and no grouping (`1234.5`)
+    pub raw: String,
+    /// Caret position in `formatted`, in UTF-16 code units like `selectionStart`
+    pub cursor: usize,
+    /// Every pattern slot is filled (numbers: not empty)
+    pub complete: bool,
+}
+
+/// An output character; `Some(before_cursor)` marks characters the user typed.
+type Out = (char, Option<bool>);
+
+impl Mask {
+    pub fn pattern(pattern: impl Into<String>) -> Self {
+        Mask::Pattern(pattern.into())
+    }
+
+    pub fn number(locale: &str, scale: u8) -> Self {
+        Mask::Number {
+            format: NumberFormat::for_locale(locale),
+            scale,
+            allow_negative: false,
+        }
+    }
+
+    /// An amount with as many fraction digits as `currency` has; parse the raw value
+    /// with `Money::parse`.
+    pub fn currency(locale: &str, currency: Currency) -> Self {
+        Self::number(locale, currency.minor_units())
+    }
+
+    pub fn allow_negative(self) -> Self {
+        match self {
+            Mask::Number { format, scale, .. } => Mask::Number {
+                format,
+                scale,
+                allow_negative: true,
+            },
+            pattern => pattern,
+        }
+    }
+
+    /// Value for the `inputmode` attribute, to bring up a fitting on-screen keyboard.
+    pub fn input_mode(&self) -> &'static str {
+        match self {
+            Mask::Pattern(pattern) if pattern_accepts_letters(pattern) => "text",
+            Mask::Pattern(_) => "numeric",
+            Mask::Number { .. } => "decimal",
+        }
+    }
+
+    /// Reformat `input` as typed, with the caret at `cursor` (UTF-16 code units).
+    pub fn apply(&self, input: &str, cursor: usize) -> MaskedValue {
+        let mut offset = 0;
+        let chars: Vec<(char, bool)> = input
+            .chars()
+            .map(|c| {
+                let before = offset < cursor;
+                offset += c.len_utf16();
+                (c, before)
+            })
+            .collect();
+
+        let (out, raw, complete) = match self {
+            Mask::Pattern(pattern) => apply_pattern(pattern, &chars),
+            Mask::Number {
+                format,
+                scale,
+                allow_negative,
+            } => apply_number(format, *scale, *allow_negative, &chars),
+        };
+
+        let typed_before = out.iter().rposition(|(_, typed)| *typed == Some(true));
+        let cursor = match typed_before {
+            Some(index) => out[..=index].iter().map(|(c, _)| c.len_utf16()).sum(),
+            None => 0,
+        };
+        MaskedValue {
+            formatted: out.iter().map(|(c, _)| *c).collect(),
+            raw,
+            cursor,
+            complete,
+        }
+    }
+
+    /// Format a raw value, e.g. one loaded from the server.
+    pub fn format(&self, raw: &str) -> MaskedValue {
+        match self {
+            Mask::Pattern(_) => self.apply(raw, usize::MAX),
+            Mask::Number { format, .. } => {
+                let localized: String = raw
+                    .chars()
+                    .map(|c| if c == '.' { format.decimal } else { c })
+                    .collect();
+                self.apply(&localized, usize::MAX)
+            }
+        }
+    }
+}
+
+enum Slot {
+    Digit,
+    Letter,
+    Any,
+    Literal(char),
+}
+
+impl Slot {
+    fn accepts(&self, c: char) -> bool {
+        match self {
+            Slot::Digit => c.is_ascii_digit(),
+            Slot::Letter => c.is_alphabetic(),
+            Slot::Any => c.is_alphanumeric(),
+            Slot::Literal(_) => false,
+        }
+    }
+}
+
+fn parse_pattern(pattern: &str) -> Vec<Slot> {
+    let mut slots = Vec::new();
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        slots.push(match c {
+            '9' => Slot::Digit,
+            'a' => Slot::Letter,
+            '*' => Slot::Any,
+            '\\' => Slot::Literal(chars.next().unwrap_or('\\')),
+            other => Slot::Literal(other),
+        });
+    }
+    slots
+}
+
+fn pattern_accepts_letters(pattern: &str) -> bool {
+    parse_pattern(pattern)
+        .iter()
+        .any(|slot| matches!(slot, Slot::Letter | Slot::Any))
+}
+
+fn apply_pattern(pattern: &str, input: &[(char, bool)]) -> (Vec<Out>, String, bool) {
+    let slots = parse_pattern(pattern);
+    let literals: Vec<char> = slots
+        .iter()
+        .filter_map(|slot| match slot {
+            Slot::Literal(c) => Some(*c),
+            _ => None,
+        })
+        .collect();
+    // Literals already in the input (from a previous pass or pasted) are re-inserted
+    // by the pattern, so only alphanumerics count as typed.
+    let mut typed = input
+        .iter()
+        .filter(|(c, _)| c.is_alphanumeric() || !literals.contains(c))
+        .peekable();
+
+    let mut out = Vec::new();
+    let mut raw = String::new();
+    let mut filled = 0;
+    let mut slot_count = 0;
+    for slot in &slots {
+        if let Slot::Literal(literal) = slot {
+            // Literals only appear once something follows them.
+            if typed.peek().is_some() {
+                out.push((*literal, None));
+            }
+            continue;
+        }
+        slot_count += 1;
+        // Skip characters this slot cannot take, e.g. a letter where a digit goes.
+        let next = loop {
+            match typed.next() {
+                Some(&(c, before)) if slot.accepts(c) => break Some((c, before)),
+                Some(_) => continue,
+                None => break None,
+            }
+        };
+        let Some((c, before)) = next else { break };
+        out.push((c, Some(before)));
+        raw.push(c);
+        filled += 1;
+    }
+    // Drop literals that ended up trailing because nothing valid followed them.
+    while matches!(out.last(), Some((_, None))) {
+        out.pop();
+    }
+    (out, raw, filled == slot_count)
+}
+
+fn apply_number(
+    format: &NumberFormat,
+    scale: u8,
+    allow_negative: bool,
+    input: &[(char, bool)],
+) -> (Vec<Out>, String, bool) {
+    let mut negative: Option<bool> = None;
+    let mut int: Vec<(char, bool)> = Vec::new();
+    let mut frac: Option<(bool, Vec<(char, bool)>)> = None;
+
+    for &(c, before) in input {
+        match (c, &mut frac) {
+            ('-', None) if allow_negative && int.is_empty() && negative.is_none() => {
+                negative = Some(before)
+            }
+            (c, None) if c.is_ascii_digit() => int.push((c, before)),
+            // Extra fraction digits are dropped.
+            (c, Some((_, digits))) if c.is_ascii_digit() && digits.len() < usize::from(scale) => {
+                digits.push((c, before))
+            }
+            (c, None) if c == format.decimal && scale > 0 => frac = Some((before, Vec::new())),
+            _ => {}
+        }
+    }
+    while int.len() > 1 && int[0].0 == '0' {
+        int.remove(0);
+    }
+
+    let mut out = Vec::new();
+    let mut raw = String::new();
+    if let Some(before) = negative {
+        out.push(('-', Some(before)));
+        raw.push('-');
+    }
+    for (i, &(c, before)) in int.iter().enumerate() {
+        if let Some(group) = format.group {
+            if i > 0 && (int.len() - i) % 3 == 0 {
+                out.push((group, None));
+            }
+        }
+        out.push((c, Some(before)));
+        raw.push(c);
+    }
+    if let Some((before, digits)) = &frac {
+        out.push((format.decimal, Some(*before)));
+        if int.is_empty() {
+            raw.push('0');
+        }
+        if !digits.is_empty() {
+            raw.push('.');
+        }
+        for &(c, before) in digits {
+            out.push((c, Some(before)));
+            raw.push(c);
+        }
+    }
+    let complete = !int.is_empty() || frac.as_ref().is_some_and(|(_, d)| !d.is_empty());
+    (out, raw, complete)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn typed(mask: &Mask, input: &str) -> MaskedValue {
+        mask.apply(input, input.encode_utf16().count())
+    }
+
+    #[test]
+    fn test_pattern_inserts_literals_while_typing() {
+        let phone = Mask::pattern("(999) 999-9999");
+        assert_eq!(typed(&phone, "5").formatted, "(5");
+        assert_eq!(typed(&phone, "5551").formatted, "(555) 1");
+        assert_eq!(typed(&phone, "(555) 1234").formatted, "(555) 123-4");
+
+        let full = typed(&phone, "555-123-4567 ext");
+        assert_eq!(full.formatted, "(555) 123-4567");
+        assert_eq!(full.raw, "5551234567");
+        assert!(full.complete);
+        assert!(!typed(&phone, "555").complete);
+    }
+
+    #[test]
+    fn test_pattern_skips_invalid_characters() {
+        let plate = Mask::pattern("aa-999");
+        assert_eq!(typed(&plate, "ab1x23").formatted, "ab-123");
+        assert_eq!(typed(&plate, "1ab").formatted, "ab");
+        assert_eq!(Mask::pattern(r"\9\a-99").format("12").formatted, "9a-12");
+    }
+
+    #[test]
+    fn test_pattern_cursor_preserved() {
+        let date = Mask::pattern("99/99/9999");
+        // Typed "1" between "12" and "3" of "12/3": caret lands after the inserted digit.
+        let value = date.apply("121/3", 3);
+        assert_eq!(value.formatted, "12/13");
+        assert_eq!(value.cursor, 4);
+
+        // Caret at the start stays at the start.
+        assert_eq!(date.apply("12/34", 0).cursor, 0);
+    }
+
+    #[test]
+    fn test_number_grouping() {
+        let en = Mask::number("en-US", 2);
+        let value = typed(&en, "1234567.891");
+        assert_eq!(value.formatted, "1,234,567.89");
+        assert_eq!(value.raw, "1234567.89");
+
+        let de = Mask::number("de-DE", 2);
+        let value = typed(&de, "1.2345,5");
+        assert_eq!(value.formatted, "12.345,5");
+        assert_eq!(value.raw, "12345.5");
+
+        assert_eq!(typed(&de, "007").formatted, "7");
+        assert_eq!(typed(&de, ",5").raw, "0.5");
+        assert_eq!(typed(&en, "-5").formatted, "5");
+        assert_eq!(typed(&en.clone().allow_negative(), "-5").raw, "-5");
+        assert_eq!(typed(&Mask::number("en-US", 0), "1.5").formatted, "15");
+    }
+
+    #[test]
+    fn test_number_cursor_across_group_separators() {
+        let en = Mask::number("en-US", 0);
+        // "123" -> typed "4" at the end: caret stays after "4" despite the new comma.
+        let value = en.apply("1234", 4);
+        assert_eq!(value.formatted, "1,234");
+        assert_eq!(value.cursor, 5);
+
+        // Deleting the comma's neighbour in "1,234" leaves the caret in place.
+        let value = en.apply("1,34", 1);
+        assert_eq!(value.formatted, "134");
+        assert_eq!(value.cursor, 1);
+    }
+
+    #[test]
+    fn test_format_raw_value() {
+        let de = Mask::currency("de-DE", Currency::EUR);
+        assert_eq!(de.format("1234.5").formatted, "1.234,5");
+        let value = de.format("1234.56");
+        assert_eq!(value.raw, "1234.56");
+        assert_eq!(
+            shared_models::Money::parse(&value.raw, Currency::EUR)
+                .unwrap()
+                .minor(),
+            123456
+        );
+    }
+}
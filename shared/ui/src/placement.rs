@@ -0,0 +1,353 @@
+//! Where to put a floating element (popover, menu, tooltip) next to its anchor.
+//!
+//! Pure geometry in viewport coordinates, so it can be tested without a browser:
+//! place the element on the preferred side, flip to the opposite side when that has
+//! more room, then shift it along the anchor to keep it inside the viewport.
+
+/// A rectangle in CSS pixels, relative to the viewport like `getBoundingClientRect`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Rect {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Rect {
+    pub fn new(x: f64, y: f64, width: f64, height: f64) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    pub fn right(&self) -> f64 {
+        self.x + self.width
+    }
+
+    pub fn bottom(&self) -> f64 {
+        self.y + self.height
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Side {
+    Top,
+    Right,
+    Bottom,
+    Left,
+}
+
+impl Side {
+    pub fn opposite(self) -> Side {
+        match self {
+            Side::Top => Side::Bottom,
+            Side::Right => Side::Left,
+            Side::Bottom => Side::Top,
+            Side::Left => Side::Right,
+        }
+    }
+
+    fn is_vertical(self) -> bool {
+        matches!(self, Side::Top | Side::Bottom)
+    }
+
+    /// Value for a `data-side` attribute, e.g. to pick an animation direction.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Side::Top => "top",
+            Side::Right => "right",
+            Side::Bottom => "bottom",
+            Side::Left => "left",
+        }
+    }
+}
+
+/// Alignment along the anchor edge: `Start` lines up the left (or top) edges.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Align {
+    Start,
+    Center,
+    End,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Placement {
+    pub side: Side,
+    pub align: Align,
+}
+
+impl Placement {
+    pub const TOP: Placement = Placement::new(Side::Top, Align::Center);
+    pub const TOP_START: Placement = Placement::new(Side::Top, Align::Start);
+    pub const TOP_END: Placement = Placement::new(Side::Top, Align::End);
+    pub const RIGHT: Placement = Placement::new(Side::Right, Align::Center);
+    pub const RIGHT_START: Placement = Placement::new(Side::Right, Align::Start);
+    pub const RIGHT_END: Placement = Placement::new(Side::Right, Align::End);
+    pub const BOTTOM: Placement = Placement::new(Side::Bottom, Align::Center);
+    pub const BOTTOM_START: Placement = Placement::new(Side::Bottom, Align::Start);
+    pub const BOTTOM_END: Placement = Placement::new(Side::Bottom, Align::End);
+    pub const LEFT: Placement = Placement::new(Side::Left, Align::Center);
+    pub const LEFT_START: Placement = Placement::new(Side::Left, Align::Start);
+    pub const LEFT_END: Placement = Placement::new(Side::Left, Align::End);
+
+    pub const fn new(side: Side, align: Align) -> Self {
+        Self { side, align }
+    }
+}
+
+impl Default for Placement {
+    fn default() -> Self {
+        Placement::BOTTOM_START
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlacementOptions {
+    pub placement: Placement,
+    /// Gap between the anchor and the floating element
+    pub offset: f64,
+    /// Minimum distance kept from the viewport edges
+    pub padding: f64,
+    /// Move to the opposite side when it has more room
+    pub flip: bool,
+    /// Slide along the anchor edge to stay inside the viewport
+    pub shift: bool,
+}
+
+impl Default for PlacementOptions {
+    fn default() -> Self {
+        Self {
+            placement: Placement::default(),
+            offset: 4.0,
+            padding: 8.0,
+            flip: true,
+            shift: true,
+        }
+    }
+}
+
+/// Where the floating element goes, in viewport coordinates (for `position: fixed`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Position {
+    pub x: f64,
+    pub y: f64,
+    /// The placement actually used, which differs from the requested one after a flip
+    pub placement: Placement,
+}
+
+/// Position a `floating_width` by `floating_height` element next to `anchor`.
+pub fn compute_position(
+    anchor: Rect,
+    floating_width: f64,
+    floating_height: f64,
+    viewport: Rect,
+    options: &PlacementOptions,
+) -> Position {
+    let size = (floating_width, floating_height);
+    let mut placement = options.placement;
+    let (mut x, mut y) = coords(anchor, size, placement, options.offset);
+
+    if options.flip {
+        let overflow = main_axis_overflow(x, y, size, placement.side, viewport, options.padding);
+        if overflow > 0.0 {
+            let flipped = Placement::new(placement.side.opposite(), placement.align);
+            let (fx, fy) = coords(anchor, size, flipped, options.offset);
+            let flipped_overflow =
+                main_axis_overflow(fx, fy, size, flipped.side, viewport, options.padding);
+            if flipped_overflow < overflow {
+                placement = flipped;
+                (x, y) = (fx, fy);
+            }
+        }
+    }
+
+    if options.shift {
+        if placement.side.is_vertical() {
+            x = clamp_into(
+                x,
+                floating_width,
+                viewport.x,
+                viewport.right(),
+                options.padding,
+            );
+        } else {
+            y = clamp_into(
+                y,
+                floating_height,
+                viewport.y,
+                viewport.bottom(),
+                options.padding,
+            );
+        }
+    }
+
+    Position { x, y, placement }
+}
+
+fn coords(
+    anchor: Rect,
+    (width, height): (f64, f64),
+    placement: Placement,
+    offset: f64,
+) -> (f64, f64) {
+    let align = |start: f64, anchor_len: f64, len: f64| match placement.align {
+        Align::Start => start,
+        Align::Center => start + (anchor_len - len) / 2.0,
+        Align::End => start + anchor_len - len,
+    };
+    match placement.side {
+        Side::Top => (
+            align(anchor.x, anchor.width, width),
+            anchor.y - height - offset,
+        ),
+        Side::Bottom => (
+            align(anchor.x, anchor.width, width),
+            anchor.bottom() + offset,
+        ),
+        Side::Left => (
+            anchor.x - width - offset,
+            align(anchor.y, anchor.height, height),
+        ),
+        Side::Right => (
+            anchor.right() + offset,
+            align(anchor.y, anchor.height, height),
+        ),
+    }
+}
+
+/// How far the element sticks out of the viewport on the side it is placed on.
+fn main_axis_overflow(
+    x: f64,
+    y: f64,
+    (width, height): (f64, f64),
+    side: Side,
+    viewport: Rect,
+    padding: f64,
+) -> f64 {
+    match side {
+        Side::Top => viewport.y + padding - y,
+        Side::Bottom => y + height - (viewport.bottom() - padding),
+        Side::Left => viewport.x + padding - x,
+        Side::Right => x + width - (viewport.right() - padding),
+    }
+}
+
+/// Keep `[start, start + len]` inside `[min, max]` minus padding, preferring the start
+/// edge when the element is larger than the room available.
+fn clamp_into(start: f64, len: f64, min: f64, max: f64, padding: f64) -> f64 {
+    let low = min + padding;
+    let high = max - padding - len;
+    start.min(high).max(low)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VIEWPORT: Rect = Rect {
+        x: 0.0,
+        y: 0.0,
+        width: 800.0,
+        height: 600.0,
+    };
+
+    fn place(anchor: Rect, width: f64, height: f64, placement: Placement) -> Position {
+        let options = PlacementOptions {
+            placement,
+            ..PlacementOptions::default()
+        };
+        compute_position(anchor, width, height, VIEWPORT, &options)
+    }
+
+    #[test]
+    fn test_sides_and_alignment() {
+        let anchor = Rect::new(100.0, 100.0, 80.0, 20.0);
+
+        let below = place(anchor, 120.0, 50.0, Placement::BOTTOM_START);
+        assert_eq!((below.x, below.y), (100.0, 124.0));
+        assert_eq!(below.placement, Placement::BOTTOM_START);
+
+        let centered = place(anchor, 120.0, 50.0, Placement::BOTTOM);
+        assert_eq!(centered.x, 80.0);
+
+        let end = place(anchor, 120.0, 50.0, Placement::TOP_END);
+        assert_eq!((end.x, end.y), (60.0, 46.0));
+
+        let right = place(anchor, 60.0, 40.0, Placement::RIGHT);
+        assert_eq!((right.x, right.y), (184.0, 90.0));
+
+        let left = place(
+            Rect::new(300.0, 100.0, 80.0, 20.0),
+            60.0,
+            40.0,
+            Placement::LEFT_START,
+        );
+        assert_eq!((left.x, left.y), (236.0, 100.0));
+    }
+
+    #[test]
+    fn test_flips_when_opposite_side_has_more_room() {
+        // Near the bottom edge: no room below, plenty above.
+        let anchor = Rect::new(100.0, 560.0, 80.0, 20.0);
+        let position = place(anchor, 120.0, 100.0, Placement::BOTTOM_START);
+        assert_eq!(position.placement, Placement::TOP_START);
+        assert_eq!(position.y, 456.0);
+
+        // Near the right edge a right-side tooltip goes left.
+        let anchor = Rect::new(760.0, 100.0, 30.0, 20.0);
+        let position = place(anchor, 100.0, 30.0, Placement::RIGHT);
+        assert_eq!(position.placement.side, Side::Left);
+    }
+
+    #[test]
+    fn test_keeps_side_when_flipping_does_not_help() {
+        // Taller than the viewport: the preferred side overflows less.
+        let anchor = Rect::new(100.0, 100.0, 80.0, 20.0);
+        let position = place(anchor, 120.0, 700.0, Placement::BOTTOM_START);
+        assert_eq!(position.placement, Placement::BOTTOM_START);
+
+        let options = PlacementOptions {
+            flip: false,
+            ..PlacementOptions::default()
+        };
+        let anchor = Rect::new(100.0, 560.0, 80.0, 20.0);
+        let position = compute_position(anchor, 120.0, 100.0, VIEWPORT, &options);
+        assert_eq!(position.placement.side, Side::Bottom);
+    }
+
+    #[test]
+    fn test_shifts_into_viewport() {
+        // Anchor at the right edge, menu wider than the room to its right.
+        let anchor = Rect::new(740.0, 10.0, 50.0, 20.0);
+        let position = place(anchor, 200.0, 100.0, Placement::BOTTOM_START);
+        assert_eq!(position.x, 592.0);
+
+        // Anchor at the left edge with centered alignment.
+        let anchor = Rect::new(0.0, 10.0, 20.0, 20.0);
+        let position = place(anchor, 100.0, 30.0, Placement::BOTTOM);
+        assert_eq!(position.x, 8.0);
+
+        // Wider than the viewport: stick to the start edge.
+        let position = place(anchor, 1000.0, 30.0, Placement::BOTTOM);
+        assert_eq!(position.x, 8.0);
+
+        let options = PlacementOptions {
+            shift: false,
+            ..PlacementOptions::default()
+        };
+        let position = compute_position(
+            anchor,
+            100.0,
+            30.0,
+            VIEWPORT,
+            &PlacementOptions {
+                placement: Placement::BOTTOM,
+                ..options
+            },
+        );
+        assert_eq!(position.x, -40.0);
+    }
+}
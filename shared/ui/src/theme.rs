@@ -0,0 +1,453 @@
+use dioxus::prelude::*;
+
+use crate::color_scheme::use_color_scheme_provider;
+use crate::{DEFAULT_COLOR_SCHEME_STORAGE_KEY, ThemePreference};
+
+/// Class tokens for `Button`. Sizes are structural and stay in the component.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ButtonTheme {
+    pub base: &'static str,
+    pub primary: &'static str,
+    pub secondary: &'static str,
+    pub outline: &'static str,
+    pub destructive: &'static str,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CardTheme {
+    pub root: &'static str,
+    pub header: &'static str,
+    pub content: &'static str,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InputTheme {
+    pub input: &'static str,
+    /// Added to `input` while the value is invalid
+    pub input_invalid: &'static str,
+    pub label: &'static str,
+    pub required_marker: &'static str,
+    /// `Field` hint text below the control
+    pub hint: &'static str,
+    /// `Field` error message below the control
+    pub error: &'static str,
+    pub checkbox: &'static str,
+    pub radio: &'static str,
+    /// `RadioGroup` option description below its label
+    pub radio_description: &'static str,
+    /// Label text next to a checkbox, radio or switch
+    pub choice_label: &'static str,
+    /// `Switch` track; styled on `aria-checked`
+    pub switch_track: &'static str,
+    /// `Switch` thumb; moves with `group-aria-checked`
+    pub switch_thumb: &'static str,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DialogTheme {
+    pub backdrop: &'static str,
+    pub panel: &'static str,
+    pub title: &'static str,
+    pub description: &'static str,
+    pub footer: &'static str,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PopoverTheme {
+    pub panel: &'static str,
+    pub tooltip: &'static str,
+}
+
+/// `Combobox` control and its listbox of options.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ComboboxTheme {
+    /// Added to the input classes on the box holding the chips and the text input
+    pub control: &'static str,
+    pub input: &'static str,
+    pub chip: &'static str,
+    pub chip_remove: &'static str,
+    pub listbox: &'static str,
+    /// Highlighted through `data-active` while it is the active descendant
+    pub option: &'static str,
+    pub option_description: &'static str,
+    /// Loading, empty and error messages in the listbox
+    pub status: &'static str,
+}
+
+/// `Tabs` tab list, tabs and panels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TabsTheme {
+    pub list: &'static str,
+    pub list_vertical: &'static str,
+    /// The selected tab carries `aria-selected="true"`
+    pub tab: &'static str,
+    pub tab_vertical: &'static str,
+    pub panel: &'static str,
+}
+
+/// `Accordion` sections; `Collapsible` reuses the trigger and icon inside a card.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AccordionTheme {
+    pub root: &'static str,
+    /// Header button, with `aria-expanded` while open
+    pub trigger: &'static str,
+    /// Chevron, turned while open
+    pub icon: &'static str,
+    pub panel: &'static str,
+}
+
+/// `DataTable` container, header, rows and pagination footer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DataTableTheme {
+    /// Scroll container; the header sticks to its top
+    pub container: &'static str,
+    pub table: &'static str,
+    pub header_cell: &'static str,
+    pub sort_button: &'static str,
+    /// Selected rows carry `aria-selected="true"`
+    pub row: &'static str,
+    pub cell: &'static str,
+    /// Loading and empty messages spanning all columns
+    pub status: &'static str,
+    pub footer: &'static str,
+}
+
+/// `DropdownMenu` and `ContextMenu` content, shown inside a popover panel.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MenuTheme {
+    pub menu: &'static str,
+    /// Highlighted through `focus:` as keyboard and pointer move focus between items
+    pub item: &'static str,
+    /// Added to `item` for destructive actions
+    pub destructive: &'static str,
+    pub separator: &'static str,
+    /// Non-interactive group heading
+    pub label: &'static str,
+    pub shortcut: &'static str,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ToastTheme {
+    pub toast: &'static str,
+    /// Icon colors per kind
+    pub success: &'static str,
+    pub error: &'static str,
+    pub warning: &'static str,
+    pub info: &'static str,
+    pub title: &'static str,
+    pub description: &'static str,
+    pub action: &'static str,
+    pub close: &'static str,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LayoutTheme {
+    pub layout: &'static str,
+    pub header: &'static str,
+    pub header_title: &'static str,
+    pub main: &'static str,
+    pub footer: &'static str,
+    /// `AppShell` sidebar; an off-canvas drawer below the `lg` breakpoint
+    pub sidebar: &'static str,
+    pub sidebar_backdrop: &'static str,
+    /// Hamburger and collapse buttons
+    pub menu_button: &'static str,
+    pub nav_item: &'static str,
+    pub nav_icon: &'static str,
+    /// Indents the items inside a `NavGroup`
+    pub nav_group: &'static str,
+}
+
+/// Design tokens for every shared-ui component.
+///
+/// Tokens are complete Tailwind class lists so the Tailwind scanner sees them in
+/// source. A brand theme can also define CSS custom properties in `css_variables`
+/// and reference them from its tokens, e.g. `bg-[var(--brand-primary)]`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Theme {
+    pub name: &'static str,
+    pub button: ButtonTheme,
+    pub card: CardTheme,
+    pub input: InputTheme,
+    pub dialog: DialogTheme,
+    pub popover: PopoverTheme,
+    pub menu: MenuTheme,
+    pub combobox: ComboboxTheme,
+    pub data_table: DataTableTheme,
+    pub tabs: TabsTheme,
+    pub accordion: AccordionTheme,
+    pub toast: ToastTheme,
+    pub layout: LayoutTheme,
+    pub css_variables: &'static [(&'static str, &'static str)],
+}
+
+impl Theme {
+    /// The blue/gray look shared-ui has always had.
+    pub const DEFAULT: Theme = Theme {
+        name: "default",
+        button: ButtonTheme {
+            base: "inline-flex items-center justify-center gap-2 font-medium rounded-lg transition-colors duration-200 focus:outline-none focus:ring-2 focus:ring-opacity-50 disabled:opacity-50 disabled:cursor-not-allowed aria-busy:cursor-wait",
+            primary: "bg-blue-600 hover:bg-blue-700 text-white focus:ring-blue-500",
+            secondary: "bg-gray-200 hover:bg-gray-300 text-gray-900 focus:ring-gray-400",
+            outline: "border-2 border-blue-600 text-blue-600 hover:bg-blue-600 hover:text-white focus:ring-blue-500",
+            destructive: "bg-red-600 hover:bg-red-700 text-white focus:ring-red-500",
+        },
+        card: CardTheme {
+            root: "bg-white dark:bg-gray-800 shadow-lg border border-gray-200 dark:border-gray-700 rounded-lg overflow-hidden",
+            header: "px-6 py-4 border-b border-gray-200 dark:border-gray-700",
+            content: "px-6 py-4",
+        },
+        input: InputTheme {
+            input: "w-full px-3 py-2 border border-gray-300 dark:border-gray-600 rounded-lg focus:outline-none focus:ring-2 focus:ring-blue-500 focus:border-transparent bg-white dark:bg-gray-800 text-gray-900 dark:text-gray-100 disabled:opacity-50 disabled:cursor-not-allowed",
+            input_invalid: "border-red-500 dark:border-red-500 focus:ring-red-500",
+            label: "block text-sm font-medium text-gray-700 dark:text-gray-300 mb-1",
+            required_marker: "text-red-500 ml-1",
+            hint: "mt-1 text-sm text-gray-500 dark:text-gray-400",
+            error: "mt-1 text-sm text-red-600 dark:text-red-400",
+            checkbox: "h-4 w-4 shrink-0 rounded border-gray-300 dark:border-gray-600 accent-blue-600 focus:outline-none focus:ring-2 focus:ring-blue-500 focus:ring-offset-2 dark:focus:ring-offset-gray-900 disabled:opacity-50 disabled:cursor-not-allowed",
+            radio: "h-4 w-4 shrink-0 rounded-full border-gray-300 dark:border-gray-600 accent-blue-600 focus:outline-none focus:ring-2 focus:ring-blue-500 focus:ring-offset-2 dark:focus:ring-offset-gray-900 disabled:opacity-50 disabled:cursor-not-allowed",
+            radio_description: "text-xs text-gray-500 dark:text-gray-400",
+            choice_label: "inline-flex items-center gap-2 text-sm text-gray-700 dark:text-gray-300",
+            switch_track: "group relative inline-flex h-6 w-11 shrink-0 items-center rounded-full bg-gray-200 dark:bg-gray-700 aria-checked:bg-blue-600 transition-colors duration-200 focus:outline-none focus:ring-2 focus:ring-blue-500 focus:ring-offset-2 dark:focus:ring-offset-gray-900 disabled:opacity-50 disabled:cursor-not-allowed",
+            switch_thumb: "pointer-events-none inline-block h-5 w-5 translate-x-0.5 rounded-full bg-white shadow transition-transform duration-200 group-aria-checked:translate-x-[1.375rem]",
+        },
+        dialog: DialogTheme {
+            backdrop: "fixed inset-0 bg-gray-900/50 dark:bg-black/70 animate-fade-in",
+            panel: "relative w-full max-h-[calc(100vh-2rem)] overflow-y-auto bg-white dark:bg-gray-800 rounded-lg shadow-xl border border-gray-200 dark:border-gray-700 p-6 focus:outline-none animate-slide-up",
+            title: "text-lg font-semibold text-gray-900 dark:text-white",
+            description: "mt-2 text-sm text-gray-600 dark:text-gray-300",
+            footer: "mt-6 flex flex-col-reverse gap-2 sm:flex-row sm:justify-end",
+        },
+        popover: PopoverTheme {
+            panel: "z-50 rounded-lg border border-gray-200 dark:border-gray-700 bg-white dark:bg-gray-800 text-gray-900 dark:text-gray-100 shadow-lg focus:outline-none",
+            tooltip: "z-50 max-w-xs rounded-md bg-gray-900 dark:bg-gray-700 px-2 py-1 text-xs text-white shadow pointer-events-none",
+        },
+        menu: MenuTheme {
+            menu: "min-w-[10rem] py-1 focus:outline-none",
+            item: "flex w-full cursor-default select-none items-center gap-2 px-3 py-1.5 text-sm text-gray-700 dark:text-gray-200 outline-none focus:bg-gray-100 dark:focus:bg-gray-700 aria-disabled:opacity-50 aria-disabled:cursor-not-allowed",
+            destructive: "text-red-600 dark:text-red-400 focus:bg-red-50 dark:focus:bg-red-900/30",
+            separator: "my-1 h-px bg-gray-200 dark:bg-gray-700",
+            label: "px-3 py-1.5 text-xs font-semibold uppercase tracking-wide text-gray-500 dark:text-gray-400",
+            shortcut: "ml-auto pl-4 text-xs text-gray-400 dark:text-gray-500",
+        },
+        combobox: ComboboxTheme {
+            control: "flex flex-wrap items-center gap-1 cursor-text focus-within:ring-2 focus-within:ring-blue-500 focus-within:border-transparent aria-disabled:opacity-50 aria-disabled:cursor-not-allowed",
+            input: "min-w-[6rem] flex-1 border-0 bg-transparent p-0 text-inherit placeholder:text-gray-400 focus:outline-none focus:ring-0 disabled:cursor-not-allowed",
+            chip: "inline-flex items-center gap-1 rounded-md bg-blue-100 dark:bg-blue-900 px-2 py-0.5 text-xs font-medium text-blue-800 dark:text-blue-200",
+            chip_remove: "rounded text-blue-600 dark:text-blue-300 hover:bg-blue-200 dark:hover:bg-blue-800 focus:outline-none focus:ring-2 focus:ring-blue-500",
+            listbox: "max-h-60 overflow-y-auto py-1",
+            option: "flex cursor-pointer select-none items-center gap-2 px-3 py-1.5 text-sm text-gray-700 dark:text-gray-200 data-[active=true]:bg-gray-100 dark:data-[active=true]:bg-gray-700",
+            option_description: "block text-xs text-gray-500 dark:text-gray-400",
+            status: "px-3 py-2 text-sm text-gray-500 dark:text-gray-400",
+        },
+        data_table: DataTableTheme {
+            container: "relative overflow-auto rounded-lg border border-gray-200 dark:border-gray-700 bg-white dark:bg-gray-800",
+            table: "min-w-full table-fixed border-separate border-spacing-0 text-sm",
+            header_cell: "sticky top-0 z-10 border-b border-gray-200 dark:border-gray-700 bg-gray-50 dark:bg-gray-900 px-4 py-3 text-left text-xs font-semibold uppercase tracking-wide text-gray-600 dark:text-gray-300",
+            sort_button: "inline-flex items-center gap-1 rounded uppercase tracking-wide hover:text-gray-900 dark:hover:text-white focus:outline-none focus:ring-2 focus:ring-blue-500",
+            row: "data-[selected=true]:bg-blue-50 dark:data-[selected=true]:bg-blue-900/40 hover:bg-gray-50 dark:hover:bg-gray-700/50",
+            cell: "border-b border-gray-100 dark:border-gray-700 px-4 py-3 text-gray-700 dark:text-gray-200",
+            status: "px-4 py-10 text-center text-sm text-gray-500 dark:text-gray-400",
+            footer: "flex flex-wrap items-center justify-between gap-2 px-1 py-3 text-sm text-gray-600 dark:text-gray-300",
+        },
+        tabs: TabsTheme {
+            list: "flex gap-1 border-b border-gray-200 dark:border-gray-700",
+            list_vertical: "flex flex-col gap-1 border-r border-gray-200 dark:border-gray-700",
+            tab: "-mb-px border-b-2 border-transparent px-4 py-2 text-sm font-medium text-gray-600 dark:text-gray-400 hover:text-gray-900 dark:hover:text-white aria-selected:border-blue-600 aria-selected:text-blue-600 dark:aria-selected:border-blue-400 dark:aria-selected:text-blue-400 focus:outline-none focus-visible:ring-2 focus-visible:ring-blue-500 disabled:opacity-50 disabled:cursor-not-allowed",
+            tab_vertical: "-mr-px border-r-2 border-transparent px-4 py-2 text-left text-sm font-medium text-gray-600 dark:text-gray-400 hover:text-gray-900 dark:hover:text-white aria-selected:border-blue-600 aria-selected:text-blue-600 dark:aria-selected:border-blue-400 dark:aria-selected:text-blue-400 focus:outline-none focus-visible:ring-2 focus-visible:ring-blue-500 disabled:opacity-50 disabled:cursor-not-allowed",
+            panel: "py-4 text-gray-700 dark:text-gray-300 focus:outline-none focus-visible:ring-2 focus-visible:ring-blue-500 rounded",
+        },
+        accordion: AccordionTheme {
+            root: "bg-white dark:bg-gray-800 shadow-lg border border-gray-200 dark:border-gray-700 rounded-lg overflow-hidden divide-y divide-gray-200 dark:divide-gray-700",
+            trigger: "flex w-full items-center justify-between gap-4 px-6 py-4 text-left font-medium text-gray-900 dark:text-white hover:bg-gray-50 dark:hover:bg-gray-700/50 focus:outline-none focus-visible:ring-2 focus-visible:ring-inset focus-visible:ring-blue-500 disabled:opacity-50 disabled:cursor-not-allowed",
+            icon: "h-5 w-5 shrink-0 text-gray-500 dark:text-gray-400 transition-transform",
+            panel: "px-6 pb-4 text-gray-600 dark:text-gray-300",
+        },
+        toast: ToastTheme {
+            toast: "pointer-events-auto flex w-full items-start gap-3 rounded-lg border border-gray-200 dark:border-gray-700 bg-white dark:bg-gray-800 p-4 shadow-lg animate-slide-up",
+            success: "text-green-600 dark:text-green-400",
+            error: "text-red-600 dark:text-red-400",
+            warning: "text-yellow-600 dark:text-yellow-400",
+            info: "text-blue-600 dark:text-blue-400",
+            title: "text-sm font-medium text-gray-900 dark:text-white",
+            description: "mt-1 text-sm text-gray-600 dark:text-gray-300",
+            action: "mt-2 text-sm font-medium text-blue-600 dark:text-blue-400 hover:underline focus:outline-none focus:ring-2 focus:ring-blue-500 rounded",
+            close: "shrink-0 rounded p-1 text-gray-400 hover:text-gray-600 dark:hover:text-gray-200 focus:outline-none focus:ring-2 focus:ring-blue-500",
+        },
+        layout: LayoutTheme {
+            layout: "min-h-screen bg-gray-50 dark:bg-gray-900 flex flex-col",
+            header: "bg-white dark:bg-gray-800 shadow-sm border-b border-gray-200 dark:border-gray-700",
+            header_title: "text-2xl font-bold text-gray-900 dark:text-white",
+            main: "flex-1 max-w-7xl mx-auto px-4 sm:px-6 lg:px-8 py-8",
+            footer: "bg-white dark:bg-gray-800 border-t border-gray-200 dark:border-gray-700 text-sm text-gray-500 dark:text-gray-400",
+            sidebar: "fixed inset-y-0 left-0 z-40 w-64 flex flex-col bg-white dark:bg-gray-800 border-r border-gray-200 dark:border-gray-700 transition-transform duration-200 lg:static lg:z-auto lg:translate-x-0 lg:transition-[width]",
+            sidebar_backdrop: "fixed inset-0 z-30 bg-black/50 lg:hidden",
+            menu_button: "inline-flex items-center justify-center rounded-lg p-2 text-gray-500 dark:text-gray-400 hover:bg-gray-100 dark:hover:bg-gray-700 hover:text-gray-900 dark:hover:text-white focus:outline-none focus-visible:ring-2 focus-visible:ring-blue-500",
+            nav_item: "flex w-full items-center gap-3 rounded-lg px-3 py-2 text-left text-sm font-medium text-gray-700 dark:text-gray-300 hover:bg-gray-100 dark:hover:bg-gray-700 aria-[current=page]:bg-blue-50 aria-[current=page]:text-blue-700 dark:aria-[current=page]:bg-blue-900/40 dark:aria-[current=page]:text-blue-300 focus:outline-none focus-visible:ring-2 focus-visible:ring-blue-500",
+            nav_icon: "flex h-5 w-5 shrink-0 items-center justify-center",
+            nav_group: "mt-1 space-y-1 pl-4",
+        },
+        css_variables: &[],
+    };
+
+    /// Example brand theme driven by CSS variables: emerald accents on slate surfaces.
+    pub const EMERALD: Theme = Theme {
+        name: "emerald",
+        button: ButtonTheme {
+            primary: "bg-[var(--brand-primary)] hover:bg-[var(--brand-primary-hover)] text-white focus:ring-[var(--brand-primary)]",
+            secondary: "bg-slate-200 hover:bg-slate-300 text-slate-900 focus:ring-slate-400",
+            outline: "border-2 border-[var(--brand-primary)] text-[var(--brand-primary)] hover:bg-[var(--brand-primary)] hover:text-white focus:ring-[var(--brand-primary)]",
+            ..Theme::DEFAULT.button
+        },
+        card: CardTheme {
+            root: "bg-white dark:bg-slate-800 shadow-md border border-slate-200 dark:border-slate-700 rounded-xl overflow-hidden",
+            header: "px-6 py-4 border-b border-slate-200 dark:border-slate-700",
+            ..Theme::DEFAULT.card
+        },
+        input: InputTheme {
+            input: "w-full px-3 py-2 border border-slate-300 dark:border-slate-600 rounded-xl focus:outline-none focus:ring-2 focus:ring-[var(--brand-primary)] focus:border-transparent bg-white dark:bg-slate-800 text-slate-900 dark:text-slate-100 disabled:opacity-50 disabled:cursor-not-allowed",
+            label: "block text-sm font-medium text-slate-700 dark:text-slate-300 mb-1",
+            hint: "mt-1 text-sm text-slate-500 dark:text-slate-400",
+            checkbox: "h-4 w-4 shrink-0 rounded border-slate-300 dark:border-slate-600 accent-[var(--brand-primary)] focus:outline-none focus:ring-2 focus:ring-[var(--brand-primary)] focus:ring-offset-2 dark:focus:ring-offset-slate-900 disabled:opacity-50 disabled:cursor-not-allowed",
+            radio: "h-4 w-4 shrink-0 rounded-full border-slate-300 dark:border-slate-600 accent-[var(--brand-primary)] focus:outline-none focus:ring-2 focus:ring-[var(--brand-primary)] focus:ring-offset-2 dark:focus:ring-offset-slate-900 disabled:opacity-50 disabled:cursor-not-allowed",
+            radio_description: "text-xs text-slate-500 dark:text-slate-400",
+            choice_label: "inline-flex items-center gap-2 text-sm text-slate-700 dark:text-slate-300",
+            switch_track: "group relative inline-flex h-6 w-11 shrink-0 items-center rounded-full bg-slate-200 dark:bg-slate-700 aria-checked:bg-[var(--brand-primary)] transition-colors duration-200 focus:outline-none focus:ring-2 focus:ring-[var(--brand-primary)] focus:ring-offset-2 dark:focus:ring-offset-slate-900 disabled:opacity-50 disabled:cursor-not-allowed",
+            ..Theme::DEFAULT.input
+        },
+        dialog: DialogTheme {
+            backdrop: "fixed inset-0 bg-slate-900/50 dark:bg-black/70 animate-fade-in",
+            panel: "relative w-full max-h-[calc(100vh-2rem)] overflow-y-auto bg-white dark:bg-slate-800 rounded-xl shadow-xl border border-slate-200 dark:border-slate-700 p-6 focus:outline-none animate-slide-up",
+            title: "text-lg font-semibold text-slate-900 dark:text-white",
+            description: "mt-2 text-sm text-slate-600 dark:text-slate-300",
+            ..Theme::DEFAULT.dialog
+        },
+        popover: PopoverTheme {
+            panel: "z-50 rounded-xl border border-slate-200 dark:border-slate-700 bg-white dark:bg-slate-800 text-slate-900 dark:text-slate-100 shadow-lg focus:outline-none",
+            tooltip: "z-50 max-w-xs rounded-md bg-slate-900 dark:bg-slate-700 px-2 py-1 text-xs text-white shadow pointer-events-none",
+        },
+        menu: MenuTheme {
+            item: "flex w-full cursor-default select-none items-center gap-2 px-3 py-1.5 text-sm text-slate-700 dark:text-slate-200 outline-none focus:bg-slate-100 dark:focus:bg-slate-700 aria-disabled:opacity-50 aria-disabled:cursor-not-allowed",
+            separator: "my-1 h-px bg-slate-200 dark:bg-slate-700",
+            label: "px-3 py-1.5 text-xs font-semibold uppercase tracking-wide text-slate-500 dark:text-slate-400",
+            shortcut: "ml-auto pl-4 text-xs text-slate-400 dark:text-slate-500",
+            ..Theme::DEFAULT.menu
+        },
+        combobox: ComboboxTheme {
+            control: "flex flex-wrap items-center gap-1 cursor-text focus-within:ring-2 focus-within:ring-[var(--brand-primary)] focus-within:border-transparent aria-disabled:opacity-50 aria-disabled:cursor-not-allowed",
+            chip: "inline-flex items-center gap-1 rounded-md bg-emerald-100 dark:bg-emerald-900 px-2 py-0.5 text-xs font-medium text-emerald-800 dark:text-emerald-200",
+            chip_remove: "rounded text-emerald-600 dark:text-emerald-300 hover:bg-emerald-200 dark:hover:bg-emerald-800 focus:outline-none focus:ring-2 focus:ring-[var(--brand-primary)]",
+            option: "flex cursor-pointer select-none items-center gap-2 px-3 py-1.5 text-sm text-slate-700 dark:text-slate-200 data-[active=true]:bg-slate-100 dark:data-[active=true]:bg-slate-700",
+            option_description: "block text-xs text-slate-500 dark:text-slate-400",
+            status: "px-3 py-2 text-sm text-slate-500 dark:text-slate-400",
+            ..Theme::DEFAULT.combobox
+        },
+        data_table: DataTableTheme {
+            container: "relative overflow-auto rounded-xl border border-slate-200 dark:border-slate-700 bg-white dark:bg-slate-800",
+            header_cell: "sticky top-0 z-10 border-b border-slate-200 dark:border-slate-700 bg-slate-50 dark:bg-slate-900 px-4 py-3 text-left text-xs font-semibold uppercase tracking-wide text-slate-600 dark:text-slate-300",
+            sort_button: "inline-flex items-center gap-1 rounded uppercase tracking-wide hover:text-slate-900 dark:hover:text-white focus:outline-none focus:ring-2 focus:ring-[var(--brand-primary)]",
+            row: "data-[selected=true]:bg-emerald-50 dark:data-[selected=true]:bg-emerald-900/40 hover:bg-slate-50 dark:hover:bg-slate-700/50",
+            cell: "border-b border-slate-100 dark:border-slate-700 px-4 py-3 text-slate-700 dark:text-slate-200",
+            status: "px-4 py-10 text-center text-sm text-slate-500 dark:text-slate-400",
+            footer: "flex flex-wrap items-center justify-between gap-2 px-1 py-3 text-sm text-slate-600 dark:text-slate-300",
+            ..Theme::DEFAULT.data_table
+        },
+        tabs: TabsTheme {
+            list: "flex gap-1 border-b border-slate-200 dark:border-slate-700",
+            list_vertical: "flex flex-col gap-1 border-r border-slate-200 dark:border-slate-700",
+            tab: "-mb-px border-b-2 border-transparent px-4 py-2 text-sm font-medium text-slate-600 dark:text-slate-400 hover:text-slate-900 dark:hover:text-white aria-selected:border-[var(--brand-primary)] aria-selected:text-[var(--brand-primary)] focus:outline-none focus-visible:ring-2 focus-visible:ring-[var(--brand-primary)] disabled:opacity-50 disabled:cursor-not-allowed",
+            tab_vertical: "-mr-px border-r-2 border-transparent px-4 py-2 text-left text-sm font-medium text-slate-600 dark:text-slate-400 hover:text-slate-900 dark:hover:text-white aria-selected:border-[var(--brand-primary)] aria-selected:text-[var(--brand-primary)] focus:outline-none focus-visible:ring-2 focus-visible:ring-[var(--brand-primary)] disabled:opacity-50 disabled:cursor-not-allowed",
+            panel: "py-4 text-slate-700 dark:text-slate-300 focus:outline-none focus-visible:ring-2 focus-visible:ring-[var(--brand-primary)] rounded",
+        },
+        accordion: AccordionTheme {
+            root: "bg-white dark:bg-slate-800 shadow-md border border-slate-200 dark:border-slate-700 rounded-xl overflow-hidden divide-y divide-slate-200 dark:divide-slate-700",
+            trigger: "flex w-full items-center justify-between gap-4 px-6 py-4 text-left font-medium text-slate-900 dark:text-white hover:bg-slate-50 dark:hover:bg-slate-700/50 focus:outline-none focus-visible:ring-2 focus-visible:ring-inset focus-visible:ring-[var(--brand-primary)] disabled:opacity-50 disabled:cursor-not-allowed",
+            icon: "h-5 w-5 shrink-0 text-slate-500 dark:text-slate-400 transition-transform",
+            panel: "px-6 pb-4 text-slate-600 dark:text-slate-300",
+        },
+        toast: ToastTheme {
+            toast: "pointer-events-auto flex w-full items-start gap-3 rounded-xl border border-slate-200 dark:border-slate-700 bg-white dark:bg-slate-800 p-4 shadow-lg animate-slide-up",
+            info: "text-[var(--brand-primary)]",
+            title: "text-sm font-medium text-slate-900 dark:text-white",
+            description: "mt-1 text-sm text-slate-600 dark:text-slate-300",
+            action: "mt-2 text-sm font-medium text-[var(--brand-primary)] hover:underline focus:outline-none focus:ring-2 focus:ring-[var(--brand-primary)] rounded",
+            ..Theme::DEFAULT.toast
+        },
+        layout: LayoutTheme {
+            layout: "min-h-screen bg-slate-50 dark:bg-slate-900 flex flex-col",
+            header: "bg-white dark:bg-slate-800 shadow-sm border-b border-slate-200 dark:border-slate-700",
+            header_title: "text-2xl font-bold text-slate-900 dark:text-white",
+            footer: "bg-white dark:bg-slate-800 border-t border-slate-200 dark:border-slate-700 text-sm text-slate-500 dark:text-slate-400",
+            sidebar: "fixed inset-y-0 left-0 z-40 w-64 flex flex-col bg-white dark:bg-slate-800 border-r border-slate-200 dark:border-slate-700 transition-transform duration-200 lg:static lg:z-auto lg:translate-x-0 lg:transition-[width]",
+            menu_button: "inline-flex items-center justify-center rounded-lg p-2 text-slate-500 dark:text-slate-400 hover:bg-slate-100 dark:hover:bg-slate-700 hover:text-slate-900 dark:hover:text-white focus:outline-none focus-visible:ring-2 focus-visible:ring-[var(--brand-primary)]",
+            nav_item: "flex w-full items-center gap-3 rounded-lg px-3 py-2 text-left text-sm font-medium text-slate-700 dark:text-slate-300 hover:bg-slate-100 dark:hover:bg-slate-700 aria-[current=page]:bg-slate-100 aria-[current=page]:text-[var(--brand-primary)] dark:aria-[current=page]:bg-slate-700 focus:outline-none focus-visible:ring-2 focus-visible:ring-[var(--brand-primary)]",
+            ..Theme::DEFAULT.layout
+        },
+        css_variables: &[
+            ("--brand-primary", "#059669"),
+            ("--brand-primary-hover", "#047857"),
+        ],
+    };
+
+    /// Inline `style` value declaring this theme's CSS variables.
+    pub fn css_variables_style(&self) -> String {
+        self.css_variables
+            .iter()
+            .map(|(name, value)| format!("{}: {};", name, value))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme::DEFAULT
+    }
+}
+
+/// The theme provided by the nearest `ThemeProvider`, or [`Theme::DEFAULT`] without one.
+pub fn use_theme() -> Theme {
+    match try_use_context::<Signal<Theme>>() {
+        Some(theme) => *theme.read(),
+        None => Theme::DEFAULT,
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct ThemeProviderProps {
+    #[props(default)]
+    pub theme: Theme,
+    /// Color scheme used until a stored preference is loaded
+    #[props(default)]
+    pub default_scheme: ThemePreference,
+    /// localStorage key holding the user's color scheme choice
+    #[props(default = DEFAULT_COLOR_SCHEME_STORAGE_KEY)]
+    pub storage_key: &'static str,
+    children: Element,
+}
+
+/// Makes `theme` and the color scheme (see [`use_color_scheme`](crate::use_color_scheme)) available to every
+/// component below it, and declares the theme's CSS variables.
+#[component]
+pub fn ThemeProvider(props: ThemeProviderProps) -> Element {
+    use_color_scheme_provider(props.default_scheme, props.storage_key);
+
+    let mut theme = use_context_provider(|| Signal::new(props.theme));
+    let current = props.theme;
+    use_effect(use_reactive!(|(current)| {
+        if *theme.peek() != current {
+            theme.set(current);
+        }
+    }));
+
+    let style = props.theme.css_variables_style();
+
+    rsx! {
+        div {
+            class: "contents",
+            "data-theme": props.theme.name,
+            style: "{style}",
+            {props.children}
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use dioxus::document;
+
+/// Wait for `ms` milliseconds using the browser's `setTimeout`.
+pub async fn sleep(ms: u32) {
+    let _ = document::eval(&format!(
+        "await new Promise((resolve) => setTimeout(resolve, {ms})); return null;"
+    ))
+    .await;
+}
@@ -21,21 +21,791 @@ fn test_button_component_compilation() {
 fn test_button_props() {
     // Test that ButtonProps can be created with different configurations
     let _props1 = ButtonProps {
-        text: "Click me".to_string(),
+        text: Some("Click me".to_string()),
         onclick: None,
         variant: ButtonVariant::Primary,
         size: ButtonSize::Medium,
         disabled: false,
+        loading: false,
+        leading_icon: None,
+        trailing_icon: None,
+        aria_label: None,
+        button_type: ButtonType::Button,
+        form: None,
+        href: None,
+        to: None,
+        target: None,
         class: None,
+        children: VNode::empty(),
     };
 
     // Test with default values
     let _props2 = ButtonProps {
-        text: "Another button".to_string(),
+        text: Some("Another button".to_string()),
         onclick: None,
         variant: ButtonVariant::Secondary,
         size: ButtonSize::Small,
         disabled: false,
+        loading: true,
+        leading_icon: None,
+        trailing_icon: None,
+        aria_label: None,
+        button_type: ButtonType::Submit,
+        form: Some("profile-form".to_string()),
+        href: None,
+        to: None,
+        target: None,
         class: Some("custom-class".to_string()),
+        children: VNode::empty(),
     };
 }
+
+#[test]
+fn test_button_slots_compilation() {
+    #[allow(dead_code)]
+    fn test_app() -> Element {
+        rsx! {
+            Button {
+                loading: true,
+                leading_icon: rsx! { span { "+" } },
+                trailing_icon: rsx! { span { "→" } },
+                strong { "Save" }
+                " changes"
+            }
+            IconButton {
+                aria_label: "Close",
+                variant: ButtonVariant::Outline,
+                span { "×" }
+            }
+            Spinner { size: SpinnerSize::Small }
+        }
+    }
+}
+
+#[test]
+fn test_button_form_and_link_compilation() {
+    #[allow(dead_code)]
+    fn test_app() -> Element {
+        rsx! {
+            form {
+                id: "signup",
+                Button { text: "Cancel", button_type: ButtonType::Reset }
+            }
+            Button { text: "Sign up", button_type: ButtonType::Submit, form: "signup" }
+            Button {
+                text: "Documentation",
+                variant: ButtonVariant::Outline,
+                href: "https://dioxuslabs.com",
+                target: "_blank",
+            }
+            Router::<Route> {}
+        }
+    }
+
+    #[derive(Routable, Clone, PartialEq)]
+    enum Route {
+        #[route("/")]
+        Home {},
+        #[route("/settings")]
+        Settings {},
+    }
+
+    #[component]
+    fn Home() -> Element {
+        rsx! {
+            Button { text: "Settings", to: Route::Settings {}.to_string() }
+            IconButton { aria_label: "Settings", to: "/settings", disabled: true, "⚙" }
+        }
+    }
+
+    #[component]
+    fn Settings() -> Element {
+        rsx! {
+            Button { text: "Back", variant: ButtonVariant::Outline, to: Route::Home {}.to_string(), loading: true }
+        }
+    }
+}
+
+#[test]
+fn test_theme_provider_compilation() {
+    #[allow(dead_code)]
+    fn test_app() -> Element {
+        rsx! {
+            ThemeProvider {
+                theme: Theme::EMERALD,
+                Card {
+                    CardContent { Button { text: "Branded" } }
+                }
+            }
+        }
+    }
+
+    assert_eq!(Theme::default(), Theme::DEFAULT);
+    assert_eq!(Theme::DEFAULT.css_variables_style(), "");
+    assert_eq!(
+        Theme::EMERALD.css_variables_style(),
+        "--brand-primary: #059669; --brand-primary-hover: #047857;"
+    );
+}
+
+#[test]
+fn test_color_scheme_compilation() {
+    #[allow(dead_code)]
+    fn toggle() -> Element {
+        let mut scheme = use_color_scheme();
+        rsx! {
+            Button {
+                text: if scheme.is_dark() { "Light" } else { "Dark" },
+                onclick: move |_| scheme.set(ThemePreference::System)
+            }
+        }
+    }
+
+    #[allow(dead_code)]
+    fn test_app() -> Element {
+        rsx! {
+            ThemeProvider {
+                default_scheme: ThemePreference::Dark,
+                storage_key: "my-app-scheme",
+                {toggle()}
+            }
+        }
+    }
+
+    let script = color_scheme_init_script("my-app-scheme", ThemePreference::Dark);
+    assert!(script.contains(r#"localStorage.getItem("my-app-scheme")"#));
+}
+
+#[test]
+fn test_class_recipes() {
+    #[allow(dead_code)]
+    fn test_app() -> Element {
+        rsx! {
+            Card {
+                variant: CardVariant::Outlined,
+                CardContent {
+                    Input { size: InputSize::Small, placeholder: "Search" }
+                }
+            }
+        }
+    }
+
+    let theme = Theme::DEFAULT.button;
+    let icon_only = ButtonClasses {
+        size: ButtonSize::Small,
+        shape: ButtonShape::IconOnly,
+        ..Default::default()
+    }
+    .classes(&theme, "");
+    assert!(icon_only.ends_with("text-sm p-1.5"));
+    assert!(!icon_only.contains("px-3"));
+
+    let outlined = CardClasses {
+        variant: CardVariant::Outlined,
+    }
+    .classes(&Theme::DEFAULT.card, "");
+    assert!(outlined.contains("shadow-none"));
+    assert!(!outlined.contains("shadow-lg"));
+}
+
+#[test]
+fn test_form_controls_compilation() {
+    #[allow(dead_code)]
+    fn test_app() -> Element {
+        let mut accepted = use_signal(|| false);
+        let mut plan = use_signal(|| Some("pro".to_string()));
+
+        rsx! {
+            Textarea { placeholder: "Bio", max_rows: 8, name: "bio" }
+            Select {
+                placeholder: "Choose a country",
+                options: vec![SelectOption::new("other", "Other")],
+                groups: vec![
+                    SelectGroup::new("Europe", vec![
+                        SelectOption::new("de", "Germany"),
+                        SelectOption::new("fr", "France").disabled(),
+                    ]),
+                ],
+                required: true,
+                onchange: |_value: String| {}
+            }
+            Checkbox {
+                label: "Accept terms",
+                checked: accepted(),
+                indeterminate: false,
+                onchange: move |checked| accepted.set(checked)
+            }
+            RadioGroup {
+                legend: "Plan",
+                value: plan(),
+                orientation: RadioOrientation::Horizontal,
+                options: vec![
+                    RadioOption::new("free", "Free"),
+                    RadioOption::new("pro", "Pro").description("For teams"),
+                ],
+                onchange: move |value| plan.set(Some(value))
+            }
+            Switch {
+                label: "Notifications",
+                checked: accepted(),
+                name: "notifications",
+                onchange: move |checked| accepted.set(checked)
+            }
+        }
+    }
+}
+
+#[test]
+fn test_field_compilation() {
+    #[allow(dead_code)]
+    fn test_app() -> Element {
+        let mut email = use_signal(String::new);
+        let error = (!email().contains('@')).then(|| "Invalid email format".to_string());
+
+        rsx! {
+            Field {
+                label: "Email",
+                hint: "We never share it",
+                error,
+                required: true,
+                Input {
+                    input_type: InputType::Email,
+                    value: email(),
+                    oninput: move |evt: FormEvent| email.set(evt.value())
+                }
+            }
+            Field {
+                label: "Bio",
+                id: "bio",
+                Textarea { name: "bio" }
+            }
+            Field {
+                hint: "Required to continue",
+                error: "Please accept the terms",
+                required: true,
+                Checkbox { label: "Accept terms" }
+            }
+            Field {
+                hint: "Sent at most once a day",
+                Switch { label: "Email digests" }
+            }
+            Field {
+                error: "Pick a plan",
+                RadioGroup { legend: "Plan", options: vec![RadioOption::new("free", "Free")] }
+            }
+            Input { invalid: true }
+            Checkbox { invalid: true }
+        }
+    }
+
+    let field = FieldContext {
+        id: "field-1".to_string(),
+        hint_id: Some("field-1-hint".to_string()),
+        error_id: Some("field-1-error".to_string()),
+        required: true,
+        invalid: true,
+    };
+    assert_eq!(
+        field.describedby().as_deref(),
+        Some("field-1-hint field-1-error")
+    );
+    let field = FieldContext {
+        hint_id: None,
+        error_id: None,
+        ..field
+    };
+    assert_eq!(field.describedby(), None);
+}
+
+#[test]
+fn test_use_form_compilation() {
+    #[allow(dead_code)]
+    fn test_app() -> Element {
+        let mut form = use_form(|| {
+            FormOptions::new([("name", ""), ("email", "")])
+                .field_validator("name", |name| {
+                    if name.len() > 50 {
+                        Err("Name is too long".to_string())
+                    } else {
+                        Ok(())
+                    }
+                })
+                .validator(|values| {
+                    shared_models::User::new(
+                        0,
+                        values.get("name").to_string(),
+                        values.get("email").to_string(),
+                    )
+                    .map(|_| ())
+                })
+                .async_validator("email", |_email| async { Ok(()) })
+        });
+
+        rsx! {
+            form {
+                onsubmit: move |evt| {
+                    evt.prevent_default();
+                    form.submit(|_values| async { Ok(()) });
+                },
+                Field {
+                    label: "Name",
+                    error: form.error("name"),
+                    Input {
+                        value: form.value("name"),
+                        oninput: move |evt: FormEvent| form.set_value("name", evt.value()),
+                        onblur: move |_| form.touch("name")
+                    }
+                }
+                Button {
+                    text: "Save",
+                    button_type: ButtonType::Submit,
+                    loading: form.is_submitting()
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_masked_input_compilation() {
+    #[allow(dead_code)]
+    fn test_app() -> Element {
+        let mut phone = use_signal(String::new);
+        let mut amount = use_signal(|| "1234.5".to_string());
+
+        rsx! {
+            Field {
+                label: "Phone",
+                MaskedInput {
+                    mask: Mask::pattern("(999) 999-9999"),
+                    value: phone(),
+                    placeholder: "(555) 123-4567",
+                    onchange: move |value: MaskedValue| phone.set(value.raw)
+                }
+            }
+            MaskedInput {
+                mask: Mask::currency("de-DE", shared_models::Currency::EUR),
+                value: amount(),
+                name: "amount",
+                onchange: move |value: MaskedValue| amount.set(value.raw)
+            }
+        }
+    }
+
+    let date = Mask::pattern("99/99/9999").format("24122026");
+    assert_eq!(date.formatted, "24/12/2026");
+    assert!(date.complete);
+    assert_eq!(Mask::number("fr-FR", 2).input_mode(), "decimal");
+}
+
+#[test]
+fn test_dialog_compilation() {
+    #[allow(dead_code)]
+    fn test_app() -> Element {
+        let mut editing = use_signal(|| false);
+        let mut confirming = use_signal(|| false);
+
+        rsx! {
+            Button { text: "Edit", onclick: move |_| editing.set(true) }
+            Dialog {
+                open: editing(),
+                title: "Edit profile",
+                description: "Changes are saved immediately.",
+                size: DialogSize::Large,
+                close_on_backdrop: false,
+                onclose: move |_| editing.set(false),
+                footer: rsx! {
+                    Button { text: "Delete account", variant: ButtonVariant::Destructive, onclick: move |_| confirming.set(true) }
+                    Button { text: "Done", onclick: move |_| editing.set(false) }
+                },
+                Field { label: "Name", Input {} }
+            }
+            ConfirmDialog {
+                open: confirming(),
+                title: "Delete account?",
+                confirm_text: "Delete",
+                onconfirm: move |_| confirming.set(false),
+                oncancel: move |_| confirming.set(false)
+            }
+            Portal { div { "Rendered at the end of body" } }
+        }
+    }
+}
+
+#[test]
+fn test_toast_compilation() {
+    #[allow(dead_code)]
+    fn test_app() -> Element {
+        rsx! {
+            ToastProvider {
+                position: ToastPosition::TopRight,
+                max_visible: 5,
+                default_duration_ms: 3000,
+                SaveButton {}
+            }
+        }
+    }
+
+    #[allow(dead_code)]
+    #[component]
+    fn SaveButton() -> Element {
+        let mut toaster = use_toast();
+
+        rsx! {
+            Button {
+                text: "Save",
+                onclick: move |_| {
+                    toaster.success("Saved");
+                    let id = toaster.show(
+                        Toast::error("Upload failed")
+                            .description("The server did not respond.")
+                            .sticky()
+                            .action("Retry", || {}),
+                    );
+                    toaster.dismiss(id);
+                }
+            }
+        }
+    }
+
+    let toast = Toast::warning("Low disk space").duration_ms(8000);
+    assert_eq!(toast.kind, ToastKind::Warning);
+    assert_eq!(toast.duration_ms, Some(8000));
+    assert_eq!(Toast::info("Hi").sticky().duration_ms, Some(0));
+}
+
+#[test]
+fn test_popover_compilation() {
+    #[allow(dead_code)]
+    fn test_app() -> Element {
+        let mut open = use_signal(|| false);
+
+        rsx! {
+            Popover {
+                open: open(),
+                onclose: move |_| open.set(false),
+                placement: Placement::BOTTOM_END,
+                offset: 8.0,
+                role: "dialog",
+                class: "p-4 w-64",
+                anchor: rsx! {
+                    Button {
+                        text: "Filters",
+                        variant: ButtonVariant::Outline,
+                        onclick: move |_| open.toggle()
+                    }
+                },
+                p { "Filter options" }
+            }
+        }
+    }
+
+    let position = compute_position(
+        Rect::new(10.0, 10.0, 40.0, 20.0),
+        100.0,
+        50.0,
+        Rect::new(0.0, 0.0, 320.0, 480.0),
+        &PlacementOptions::default(),
+    );
+    assert_eq!(position.placement, Placement::BOTTOM_START);
+    assert_eq!((position.x, position.y), (10.0, 34.0));
+}
+
+#[test]
+fn test_menu_compilation() {
+    #[allow(dead_code)]
+    fn test_app() -> Element {
+        let mut wrap = use_signal(|| true);
+        let items = vec![
+            MenuItem::label("Edit"),
+            MenuItem::new("copy", "Copy").shortcut("Ctrl+C"),
+            MenuItem::new("paste", "Paste").disabled(),
+            MenuItem::checkbox("wrap", "Word wrap", wrap()),
+            MenuItem::submenu("Share", vec![MenuItem::new("email", "Email")]),
+            MenuItem::separator(),
+            MenuItem::new("delete", "Delete").destructive(),
+        ];
+
+        rsx! {
+            DropdownMenu {
+                items: items.clone(),
+                variant: ButtonVariant::Outline,
+                placement: Placement::BOTTOM_END,
+                onselect: move |id: String| {
+                    if id == "wrap" {
+                        wrap.toggle();
+                    }
+                },
+                "Edit"
+            }
+            DropdownMenu {
+                items: items.clone(),
+                icon_label: "More actions",
+                "⋯"
+            }
+            ContextMenu {
+                items,
+                aria_label: "Editor actions",
+                div { class: "h-40", "Right-click here" }
+            }
+        }
+    }
+
+    let share = MenuItem::submenu("Share", vec![MenuItem::new("email", "Email")]);
+    assert_eq!(share.id, "Share");
+    assert!(matches!(share.kind, MenuItemKind::Submenu(ref items) if items.len() == 1));
+}
+
+#[test]
+fn test_tooltip_compilation() {
+    #[allow(dead_code)]
+    fn test_app() -> Element {
+        rsx! {
+            TooltipGroup {
+                open_delay_ms: 500,
+                skip_delay_ms: 200,
+                Tooltip {
+                    content: "Bold",
+                    IconButton { aria_label: "Bold", "B" }
+                }
+                Tooltip {
+                    content: "Available on the Pro plan",
+                    placement: Placement::BOTTOM,
+                    Button { text: "Export", disabled: true }
+                }
+            }
+            Tooltip {
+                content: "Copied!",
+                open_delay_ms: 0,
+                close_delay_ms: 0,
+                disabled: true,
+                CustomTrigger {}
+            }
+            Tooltip {
+                content: "Letters and digits only",
+                Field { label: "Username", hint: "Shown on your profile", Input {} }
+            }
+        }
+    }
+
+    #[allow(dead_code)]
+    #[component]
+    fn CustomTrigger() -> Element {
+        rsx! {
+            span { tabindex: "0", "aria-describedby": use_tooltip_description(), "Hover me" }
+        }
+    }
+}
+
+#[test]
+fn test_combobox_compilation() {
+    use shared_models::User;
+
+    #[allow(dead_code)]
+    fn test_app() -> Element {
+        let loader = use_hook(|| {
+            ComboboxLoader::new(|query: String| async move {
+                let user = User::new(1, query, "ada@example.com".into())?;
+                Ok(vec![user])
+            })
+        });
+
+        rsx! {
+            Field {
+                label: "Assignees",
+                Combobox::<User> {
+                    loader,
+                    debounce_ms: 100,
+                    multiple: true,
+                    selected: Vec::new(),
+                    onchange: |_: Vec<User>| {},
+                    name: "assignees",
+                    placeholder: "Search people..."
+                }
+            }
+            Combobox::<SelectOption> {
+                options: vec![SelectOption::new("rust", "Rust")],
+                oncreate: |_: String| {},
+                empty_text: "No tags",
+                size: InputSize::Small,
+                disabled: true
+            }
+        }
+    }
+
+    let users = vec![User::new(1, "Ada".into(), "ada@example.com".into()).unwrap()];
+    assert_eq!(filter_options(&users, "ADA@", 10).len(), 1);
+    assert_eq!(users[0].key(), "1");
+}
+
+#[test]
+fn test_data_table_compilation() {
+    use shared_models::User;
+
+    #[allow(dead_code)]
+    fn test_app() -> Element {
+        let columns = use_hook(|| {
+            let mut columns = user_columns();
+            columns.push(Column::new("actions", "", |user: &User| {
+                rsx! { Button { text: "Edit", aria_label: "Edit {user.name}" } }
+            }));
+            columns
+        });
+
+        rsx! {
+            DataTable::<User> {
+                rows: Vec::new(),
+                columns: columns.clone(),
+                default_sort: SortState::descending("id"),
+                per_page: 50,
+                selectable: true,
+                selected: vec!["1".to_string()],
+                onselectionchange: |_: Vec<String>| {},
+                onrowclick: |_: User| {},
+                empty_text: "No users yet",
+                max_height: "20rem",
+                caption: "Users"
+            }
+            DataTable::<User> {
+                rows: Vec::new(),
+                columns: vec![Column::text("name", "Name", |user: &User| user.name.clone()).sortable().width("50%")],
+                server_side: true,
+                total: 1234,
+                onquerychange: |_: TableQuery| {},
+                loading: true
+            }
+        }
+    }
+
+    assert_eq!(next_sort(None, "id"), Some(SortState::ascending("id")));
+    assert!(user_columns().iter().all(Column::is_sortable));
+}
+
+#[test]
+fn test_virtual_list_compilation() {
+    use shared_models::User;
+
+    #[allow(dead_code)]
+    fn test_app() -> Element {
+        let list = use_virtualizer(50_000, RowHeight::Fixed(32.0), 5);
+        let users = use_signal(Vec::<User>::new);
+        let table_rows = use_virtualizer(users.read().len(), RowHeight::Estimated(48.0), 10);
+        let columns = use_hook(user_columns);
+
+        rsx! {
+            Button {
+                text: "Top",
+                onclick: move |_| list.scroll_to_index(0, ScrollAlign::Start)
+            }
+            VirtualList {
+                virtualizer: list,
+                height: "400px",
+                aria_label: "Rows",
+                has_more: true,
+                loading: false,
+                load_more_threshold: 20,
+                onloadmore: |_| {},
+                render_item: |index: usize| rsx! { div { "Row {index}" } }
+            }
+            DataTable::<User> {
+                rows: users(),
+                columns: columns.clone(),
+                per_page: 0,
+                max_height: "30rem",
+                virtualizer: table_rows
+            }
+        }
+    }
+
+    let rows = RowOffsets::new(10, RowHeight::Fixed(10.0), &Default::default());
+    assert_eq!(rows.visible_range(0.0, 25.0, 1), 0..4);
+}
+
+#[test]
+fn test_tabs_and_accordion_compilation() {
+    #[allow(dead_code)]
+    fn test_app() -> Element {
+        let mut open = use_signal(Vec::<String>::new);
+
+        rsx! {
+            Tabs {
+                default_value: "one",
+                activation: TabsActivation::Manual,
+                orientation: TabsOrientation::Vertical,
+                lazy: true,
+                onchange: |_: String| {},
+                TabList {
+                    aria_label: "Sections",
+                    Tab { value: "one", "One" }
+                    Tab { value: "two", disabled: true, "Two" }
+                }
+                TabPanel { value: "one", "First" }
+                TabPanel { value: "two", class: "p-0", "Second" }
+            }
+            Accordion {
+                value: open(),
+                onchange: move |values| open.set(values),
+                multiple: true,
+                AccordionItem { value: "a", title: "A", "Alpha" }
+                AccordionItem { value: "b", title: "B", disabled: true, "Beta" }
+            }
+            Accordion {
+                default_value: vec!["a".to_string()],
+                collapsible: false,
+                AccordionItem { value: "a", title: "A", "Alpha" }
+            }
+            Collapsible {
+                title: "Advanced",
+                default_open: true,
+                variant: CardVariant::Outlined,
+                onchange: |_: bool| {},
+                "Content"
+            }
+            Collapsible { title: "Controlled", open: false, "Hidden" }
+        }
+    }
+}
+
+#[test]
+fn test_app_shell_compilation() {
+    #[allow(dead_code)]
+    fn test_app() -> Element {
+        rsx! {
+            AppShell {
+                current_path: "/users/42",
+                default_collapsed: true,
+                storage_key: "admin-sidebar",
+                header: rsx! { Header { title: "Admin" } },
+                sidebar: rsx! {
+                    Sidebar {
+                        aria_label: "Admin",
+                        NavItem { href: "/", label: "Home", exact: true }
+                        NavGroup {
+                            label: "People",
+                            icon: rsx! { span { "P" } },
+                            default_open: true,
+                            NavItem { href: "/users", label: "Users", icon: rsx! { span { "U" } } }
+                            NavGroup {
+                                label: "Archive",
+                                NavItem { href: "/users/archive", label: "Archived", class: "italic" }
+                            }
+                        }
+                    }
+                },
+                footer: rsx! { Footer { class: "mt-8", "© Example" } },
+                Main { "Content" }
+            }
+        }
+    }
+
+    #[allow(dead_code)]
+    fn test_sidebar_state() -> Element {
+        let mut sidebar = use_sidebar();
+        rsx! {
+            button {
+                onclick: move |_| sidebar.toggle_collapsed(),
+                "{sidebar.collapsed()} {sidebar.mobile_open()} {sidebar.location()}"
+            }
+        }
+    }
+}